@@ -1,3 +1,5 @@
+#include <inttypes.h>
+#include <stdbool.h>
 #include <stdint.h>
 #include <stdio.h>
 #include <stdlib.h>
@@ -42,6 +44,27 @@ const char* float_string(double value) {
     return buffer;
 }
 
+const char* integer_string(int64_t value) {
+    char* buffer = malloc(32);
+    if (buffer) {
+        snprintf(buffer, 32, "%" PRId64, value);
+    }
+    return buffer;
+}
+
+const char* boolean_string(bool value) {
+    return value ? "true" : "false";
+}
+
+const char* character_string(char value) {
+    char* buffer = malloc(2);
+    if (buffer) {
+        buffer[0] = value;
+        buffer[1] = '\0';
+    }
+    return buffer;
+}
+
 const char* pointer_string(uint8_t* pointer) {
     return (const char*)pointer;
 }
@@ -1,4 +1,6 @@
+#include <inttypes.h>
 #include <stdint.h>
+#include <stdio.h>
 #include <string.h>
 #include <ctype.h>
 #include <stdlib.h>
@@ -32,6 +34,60 @@ const char* string_substring(const char* string, uint64_t start, uint64_t end) {
     return result;
 }
 
+const char* string_concatenate(const char* left, const char* right) {
+    uint64_t first = strlen(left);
+    uint64_t second = strlen(right);
+    char* result = malloc(first + second + 1);
+    memcpy(result, left, first);
+    memcpy(result + first, right, second);
+    result[first + second] = '\0';
+    return result;
+}
+
+char* format_start(void) {
+    char* buffer = malloc(1);
+    if (buffer) {
+        buffer[0] = '\0';
+    }
+    return buffer;
+}
+
+char* format_string(char* buffer, const char* value) {
+    if (!buffer) {
+        return NULL;
+    }
+    uint64_t first = strlen(buffer);
+    uint64_t second = strlen(value);
+    char* result = realloc(buffer, first + second + 1);
+    if (!result) {
+        free(buffer);
+        return NULL;
+    }
+    memcpy(result + first, value, second + 1);
+    return result;
+}
+
+char* format_integer(char* buffer, int64_t value) {
+    char digits[32];
+    snprintf(digits, sizeof digits, "%" PRId64, value);
+    return format_string(buffer, digits);
+}
+
+char* format_float(char* buffer, double value) {
+    char digits[64];
+    snprintf(digits, sizeof digits, "%f", value);
+    return format_string(buffer, digits);
+}
+
+char* format_boolean(char* buffer, bool value) {
+    return format_string(buffer, value ? "true" : "false");
+}
+
+char* format_character(char* buffer, char value) {
+    char character[2] = { value, '\0' };
+    return format_string(buffer, character);
+}
+
 double parse_float(const char* string) {
     return strtod(string, NULL);
 }
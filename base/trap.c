@@ -0,0 +1,9 @@
+#include <stdio.h>
+#include <stdlib.h>
+
+void trap_report(const char* kind, const char* location) {
+    fflush(stdout);
+    fprintf(stderr, "error: %s at %s\n", kind, location);
+    fflush(stderr);
+    abort();
+}
@@ -0,0 +1,59 @@
+#include <stdint.h>
+
+typedef struct {
+    int32_t left;
+    int32_t right;
+} Pair;
+
+typedef struct {
+    int64_t start;
+    int64_t end;
+} Range;
+
+typedef struct {
+    double x;
+    double y;
+} Point;
+
+typedef struct {
+    int64_t count;
+    double total;
+} Tally;
+
+typedef struct {
+    double left;
+    double top;
+    double right;
+    double bottom;
+} Rect;
+
+Pair swap(Pair pair) {
+    Pair result = { pair.right, pair.left };
+    return result;
+}
+
+Range shift(Range range, int64_t offset) {
+    range.start += offset;
+    range.end += offset;
+    return range;
+}
+
+Point scale(Point point, double factor) {
+    point.x *= factor;
+    point.y *= factor;
+    return point;
+}
+
+Tally tally(Tally tally, double value) {
+    tally.count += 1;
+    tally.total += value;
+    return tally;
+}
+
+Rect grow(Rect rect, double margin) {
+    rect.left -= margin;
+    rect.top -= margin;
+    rect.right += margin;
+    rect.bottom += margin;
+    return rect;
+}
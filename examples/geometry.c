@@ -0,0 +1,41 @@
+#include <stdint.h>
+
+typedef struct {
+    double x;
+    double y;
+} Point;
+
+typedef struct {
+    int64_t count;
+    double total;
+} Tally;
+
+typedef struct {
+    double left;
+    double top;
+    double right;
+    double bottom;
+} Rect;
+
+Point midpoint(Point a, Point b) {
+    Point result = { (a.x + b.x) / 2.0, (a.y + b.y) / 2.0 };
+    return result;
+}
+
+Tally record(Tally tally, double value) {
+    tally.count += 1;
+    tally.total += value;
+    return tally;
+}
+
+Rect expand(Rect rect, double margin) {
+    rect.left -= margin;
+    rect.top -= margin;
+    rect.right += margin;
+    rect.bottom += margin;
+    return rect;
+}
+
+double area(Rect rect) {
+    return (rect.right - rect.left) * (rect.bottom - rect.top);
+}
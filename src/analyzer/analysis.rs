@@ -50,6 +50,12 @@ pub enum AnalysisKind<'analysis> {
 
     Negate(Box<Analysis<'analysis>>),
     SizeOf(Type<'analysis>),
+    Cast(Box<Analysis<'analysis>>, Type<'analysis>),
+    Wrap(Box<Analysis<'analysis>>),
+    Nothing,
+    Success(Box<Analysis<'analysis>>),
+    Failure(Box<Analysis<'analysis>>),
+    Propagate(Box<Analysis<'analysis>>),
 
     Add(Box<Analysis<'analysis>>, Box<Analysis<'analysis>>),
     Subtract(Box<Analysis<'analysis>>, Box<Analysis<'analysis>>),
@@ -79,6 +85,7 @@ pub enum AnalysisKind<'analysis> {
     Index(Index<Box<Analysis<'analysis>>, Analysis<'analysis>>),
     Invoke(Invoke<Box<Analysis<'analysis>>, Analysis<'analysis>>),
     Call(Target<'analysis>, Vec<Analysis<'analysis>>),
+    Assembly(Str<'analysis>, Str<'analysis>, Vec<Analysis<'analysis>>),
 
     Block(Vec<Analysis<'analysis>>),
     Conditional(
@@ -86,10 +93,15 @@ pub enum AnalysisKind<'analysis> {
         Box<Analysis<'analysis>>,
         Option<Box<Analysis<'analysis>>>,
     ),
-    While(Box<Analysis<'analysis>>, Box<Analysis<'analysis>>),
+    While(
+        Box<Analysis<'analysis>>,
+        Box<Analysis<'analysis>>,
+        Option<Str<'analysis>>,
+    ),
     Return(Option<Box<Analysis<'analysis>>>),
-    Break(Option<Box<Analysis<'analysis>>>),
-    Continue(Option<Box<Analysis<'analysis>>>),
+    Break(Option<Str<'analysis>>, Option<Box<Analysis<'analysis>>>),
+    Continue(Option<Str<'analysis>>, Option<Box<Analysis<'analysis>>>),
+    Defer(Box<Analysis<'analysis>>),
 
     Usage(Str<'analysis>),
     Symbol(Target<'analysis>),
@@ -97,12 +109,19 @@ pub enum AnalysisKind<'analysis> {
     Slot(Box<Analysis<'analysis>>, Scale),
     Constructor(Aggregate<Str<'analysis>, Analysis<'analysis>>),
     Pack(Target<'analysis>, Vec<(Scale, Analysis<'analysis>)>),
+    Pattern(Vec<(Scale, Analysis<'analysis>)>),
     Assign(Str<'analysis>, Box<Analysis<'analysis>>),
     Write(Target<'analysis>, Box<Analysis<'analysis>>),
     Store(Box<Analysis<'analysis>>, Box<Analysis<'analysis>>),
     Binding(Binding<Box<Analysis<'analysis>>, Box<Analysis<'analysis>>, Type<'analysis>>),
     Structure(Aggregate<Str<'analysis>, Analysis<'analysis>>),
     Union(Aggregate<Str<'analysis>, Analysis<'analysis>>),
+    Enumeration(Aggregate<Str<'analysis>, Analysis<'analysis>>),
+    Variant(Target<'analysis>, Scale, Box<Analysis<'analysis>>),
+    Match(
+        Box<Analysis<'analysis>>,
+        Vec<(Option<Scale>, Vec<Str<'analysis>>, Analysis<'analysis>)>,
+    ),
     Composite(Aggregate<Target<'analysis>, Analysis<'analysis>>),
     Function(
         Function<
@@ -112,5 +131,14 @@ pub enum AnalysisKind<'analysis> {
             Option<Type<'analysis>>,
         >,
     ),
+    Closure(
+        Function<
+            Str<'analysis>,
+            Analysis<'analysis>,
+            Option<Box<Analysis<'analysis>>>,
+            Option<Type<'analysis>>,
+        >,
+        Vec<Analysis<'analysis>>,
+    ),
     Module(Str<'analysis>, Vec<Analysis<'analysis>>),
 }
@@ -1,15 +1,16 @@
 use crate::{
-    analyzer::{Analysis, AnalysisKind, AnalyzeError},
-    data::{Aggregate, Binding, Function, Identity, Str},
+    analyzer::{Analysis, AnalysisKind, AnalyzeError, ErrorKind, Target},
+    data::{memory::take, Aggregate, Binding, Function, Identity, Scale, Str},
     internal::{Artifact, RecordKind, Session, SessionError},
     parser::{Element, Symbol, SymbolKind},
-    resolver::Resolver,
+    resolver::{Resolver, Type, TypeKind},
 };
 
 pub struct Analyzer<'analyzer> {
     pub input: Vec<Element<'analyzer>>,
     pub output: Vec<Analysis<'analyzer>>,
     pub errors: Vec<AnalyzeError<'analyzer>>,
+    pub constants: Vec<Analysis<'analyzer>>,
 }
 
 impl<'analyzer> Analyzer<'analyzer> {
@@ -18,25 +19,63 @@ impl<'analyzer> Analyzer<'analyzer> {
             input,
             output: Vec::new(),
             errors: Vec::new(),
+            constants: Vec::new(),
         }
     }
 
     pub fn analyze(&mut self, resolver: &mut Resolver<'analyzer>) {
         for element in self.input.iter_mut() {
+            if element.kind.try_unwrap_symbolize().is_some_and(|symbol| {
+                symbol.is_generic()
+                    || symbol.is_variadic()
+                    || symbol.kind.is_interface()
+                    || symbol.kind.is_import()
+                    || symbol.kind.is_alias()
+            }) {
+                continue;
+            }
+
+            if let Some(implementation) = element
+                .kind
+                .try_unwrap_symbolize()
+                .and_then(|symbol| symbol.kind.try_unwrap_implementation())
+            {
+                for method in &implementation.members {
+                    match method.analyze(resolver) {
+                        Ok(analysis) => self.output.push(analysis),
+                        Err(error) => self.errors.push(error),
+                    }
+                }
+
+                continue;
+            }
+
             match element.analyze(resolver) {
                 Ok(analysis) => self.output.push(analysis),
                 Err(error) => self.errors.push(error),
             }
         }
+
+        let output = take(&mut self.output);
+        self.output = output
+            .into_iter()
+            .map(|analysis| self.settle(analysis))
+            .collect();
     }
 
     pub fn execute(session: &mut Session<'analyzer>, keys: &[Identity]) {
+        let mut constants = Vec::new();
+
         for &key in keys {
-            Self::process(session, key);
+            Self::process(session, key, &mut constants);
         }
     }
 
-    fn process(session: &mut Session<'analyzer>, key: Identity) {
+    fn process(
+        session: &mut Session<'analyzer>,
+        key: Identity,
+        constants: &mut Vec<Analysis<'analyzer>>,
+    ) {
         let (kind, elements) = {
             let record = session.records.get(&key).unwrap();
             let elements = if let Some(Artifact::Elements(elements)) = record.fetch(2) {
@@ -52,7 +91,9 @@ impl<'analyzer> Analyzer<'analyzer> {
         }
 
         let mut analyzer = Analyzer::new(elements.unwrap_or_default());
+        analyzer.constants = take(constants);
         analyzer.analyze(&mut session.resolver);
+        *constants = take(&mut analyzer.constants);
 
         analyzer.output.shrink_to_fit();
 
@@ -90,7 +131,64 @@ impl<'symbol> Analyzable<'symbol> for Symbol<'symbol> {
                     .map(|value| value.analyze(resolver))
                     .transpose()?;
 
-                let head = binding.target.analyze(resolver)?;
+                let head = match binding.target.destructure() {
+                    Some(binders) => {
+                        let span = binding.target.span;
+                        let typing = resolver.reify(&binding.target.typing);
+
+                        let fields = match &typing.kind {
+                            TypeKind::Tuple { members } => members
+                                .iter()
+                                .map(|member| (None, member.clone()))
+                                .collect::<Vec<_>>(),
+                            TypeKind::Structure(structure) => structure
+                                .members
+                                .iter()
+                                .map(|member| match &member.kind {
+                                    TypeKind::Binding(field) => (
+                                        Some(field.target),
+                                        field
+                                            .value
+                                            .as_deref()
+                                            .or(field.annotation.as_deref())
+                                            .cloned()
+                                            .unwrap_or_else(|| Type::from(TypeKind::Unknown)),
+                                    ),
+                                    _ => (None, member.clone()),
+                                })
+                                .collect(),
+                            _ => return Err(AnalyzeError::new(ErrorKind::InvalidType, span)),
+                        };
+
+                        let mut slots = Vec::with_capacity(binders.len());
+
+                        for (position, (field, binder)) in binders.into_iter().enumerate() {
+                            if binder.is_empty() || binder.as_str() == Some("_") {
+                                continue;
+                            }
+
+                            let slot = match field {
+                                Some(field) => {
+                                    fields.iter().position(|(name, _)| *name == Some(field))
+                                }
+                                None => (position < fields.len()).then_some(position),
+                            }
+                            .ok_or_else(|| AnalyzeError::new(ErrorKind::InvalidTarget, span))?;
+
+                            slots.push((
+                                slot as Scale,
+                                Analysis::new(
+                                    AnalysisKind::Symbol(Target::new(Identity::default(), binder)),
+                                    span,
+                                    resolver.reify(&fields[slot].1),
+                                ),
+                            ));
+                        }
+
+                        Analysis::new(AnalysisKind::Pattern(slots), span, typing)
+                    }
+                    None => binding.target.analyze(resolver)?,
+                };
 
                 let binding = Binding::new(
                     Box::new(head),
@@ -125,6 +223,18 @@ impl<'symbol> Analyzable<'symbol> for Symbol<'symbol> {
                     members,
                 ))
             }
+            SymbolKind::Enumeration(enumeration) => {
+                let members = enumeration
+                    .members
+                    .iter()
+                    .map(|member| member.analyze(resolver))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                AnalysisKind::Enumeration(Aggregate::new(
+                    Str::from(enumeration.target.target().unwrap_or_default().to_string()),
+                    members,
+                ))
+            }
             SymbolKind::Function(function) => {
                 let members = function
                     .members
@@ -140,7 +250,8 @@ impl<'symbol> Analyzable<'symbol> for Symbol<'symbol> {
                     .map(Box::new);
 
                 let output = function.output.clone().map(|output| output.typing);
-                let function = Function::new(
+                let constant = function.constant;
+                let mut function = Function::new(
                     Str::from(function.target.target().unwrap_or_default().to_string()),
                     members,
                     body,
@@ -149,12 +260,56 @@ impl<'symbol> Analyzable<'symbol> for Symbol<'symbol> {
                     function.entry,
                     function.variadic,
                 );
+                function.constant = constant;
+                if resolver.owners.contains_key(&self.identity) {
+                    function.visibility = self.visibility;
+                }
+                function.attributes = self
+                    .attributes
+                    .iter()
+                    .filter_map(Element::attribute)
+                    .collect();
+
+                if self.is_closure() {
+                    let captures = resolver
+                        .captured(self)
+                        .into_iter()
+                        .map(|symbol| {
+                            Analysis::new(
+                                AnalysisKind::Symbol(Target::new(
+                                    symbol.identity,
+                                    symbol.target().unwrap_or_default(),
+                                )),
+                                symbol.span,
+                                resolver.reify(&symbol.typing),
+                            )
+                        })
+                        .collect();
+
+                    return Ok(Analysis::new(
+                        AnalysisKind::Closure(function, captures),
+                        self.span,
+                        resolver.reify(&self.typing),
+                    ));
+                }
 
                 AnalysisKind::Function(function)
             }
+            SymbolKind::Implementation(implementation) => AnalysisKind::Module(
+                implementation.target.target().unwrap_or_default(),
+                implementation
+                    .members
+                    .iter()
+                    .map(|member| member.analyze(resolver))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            SymbolKind::Interface(interface) => {
+                AnalysisKind::Module(interface.target.target().unwrap_or_default(), Vec::new())
+            }
             SymbolKind::Module(module) => {
                 AnalysisKind::Module(module.target.target().unwrap_or_default(), Vec::new())
             }
+            SymbolKind::Alias(_) | SymbolKind::Import(_) => AnalysisKind::Block(Vec::new()),
         };
 
         Ok(Analysis::new(kind, self.span, self.typing.clone()))
@@ -0,0 +1,388 @@
+use crate::{
+    analyzer::{Analysis, AnalysisKind, AnalyzeError, Analyzer, ErrorKind},
+    data::{BindingKind, Str},
+};
+
+#[cfg(feature = "interpreter")]
+use crate::{
+    analyzer::Analyzable,
+    data::{Float, Integer},
+    emitter::{Engine, Value},
+    parser::Element,
+    resolver::{Resolver, Type, TypeKind},
+    tracker::Span,
+};
+
+impl<'analyzer> Analyzer<'analyzer> {
+    pub fn settle(&mut self, analysis: Analysis<'analyzer>) -> Analysis<'analyzer> {
+        match &analysis.kind {
+            AnalysisKind::Function(function) if function.constant => {
+                let mut locals = vec![function.target];
+
+                for member in &function.members {
+                    if let AnalysisKind::Binding(binding) = &member.kind {
+                        Self::declare(&binding.target, &mut locals);
+                    }
+                }
+
+                if let Some(body) = &function.body {
+                    if let Err(error) = self.evaluable(body, &mut locals) {
+                        self.errors.push(error);
+                        return analysis;
+                    }
+                }
+
+                self.constants.push(analysis.clone());
+                analysis
+            }
+            AnalysisKind::Binding(binding)
+                if binding.kind == BindingKind::Static && binding.value.is_some() =>
+            {
+                match self.evaluate(&analysis) {
+                    Ok(analysis) => {
+                        self.constants.push(analysis.clone());
+                        analysis
+                    }
+                    Err(error) => {
+                        self.errors.push(error);
+                        analysis
+                    }
+                }
+            }
+            _ => analysis,
+        }
+    }
+
+    fn known(&self, name: Str<'analyzer>) -> bool {
+        self.constants.iter().any(|constant| match &constant.kind {
+            AnalysisKind::Function(function) => function.target == name,
+            AnalysisKind::Binding(binding) => {
+                let mut names = Vec::new();
+                Self::declare(&binding.target, &mut names);
+                names.contains(&name)
+            }
+            _ => false,
+        })
+    }
+
+    fn declare(target: &Analysis<'analyzer>, locals: &mut Vec<Str<'analyzer>>) {
+        match &target.kind {
+            AnalysisKind::Usage(name) => locals.push(*name),
+            AnalysisKind::Symbol(target) => locals.push(target.name),
+            AnalysisKind::Pattern(binders) => {
+                for (_, binder) in binders {
+                    Self::declare(binder, locals);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn root(target: &Analysis<'analyzer>) -> Option<Str<'analyzer>> {
+        match &target.kind {
+            AnalysisKind::Usage(name) => Some(*name),
+            AnalysisKind::Symbol(target) => Some(target.name),
+            AnalysisKind::Slot(base, _) => Self::root(base),
+            AnalysisKind::Index(index) => Self::root(&index.target),
+            _ => None,
+        }
+    }
+
+    fn evaluable(
+        &self,
+        analysis: &Analysis<'analyzer>,
+        locals: &mut Vec<Str<'analyzer>>,
+    ) -> Result<(), AnalyzeError<'analyzer>> {
+        let reachable = |name: &Str<'analyzer>, locals: &Vec<Str<'analyzer>>| {
+            if locals.contains(name) || self.known(*name) {
+                Ok(())
+            } else {
+                Err(AnalyzeError::new(ErrorKind::NonConstant, analysis.span))
+            }
+        };
+
+        match &analysis.kind {
+            AnalysisKind::Integer { .. }
+            | AnalysisKind::Float { .. }
+            | AnalysisKind::Boolean { .. }
+            | AnalysisKind::String { .. }
+            | AnalysisKind::Character { .. }
+            | AnalysisKind::SizeOf(_)
+            | AnalysisKind::Nothing
+            | AnalysisKind::Pattern(_) => Ok(()),
+
+            AnalysisKind::Negate(operand)
+            | AnalysisKind::LogicalNot(operand)
+            | AnalysisKind::BitwiseNot(operand)
+            | AnalysisKind::AddressOf(operand)
+            | AnalysisKind::Dereference(operand)
+            | AnalysisKind::Access(operand, _)
+            | AnalysisKind::Slot(operand, _)
+            | AnalysisKind::Variant(_, _, operand)
+            | AnalysisKind::Cast(operand, _)
+            | AnalysisKind::Wrap(operand)
+            | AnalysisKind::Success(operand)
+            | AnalysisKind::Failure(operand)
+            | AnalysisKind::Propagate(operand)
+            | AnalysisKind::Defer(operand) => self.evaluable(operand, locals),
+
+            AnalysisKind::Add(left, right)
+            | AnalysisKind::Subtract(left, right)
+            | AnalysisKind::Multiply(left, right)
+            | AnalysisKind::Divide(left, right)
+            | AnalysisKind::Modulus(left, right)
+            | AnalysisKind::LogicalAnd(left, right)
+            | AnalysisKind::LogicalOr(left, right)
+            | AnalysisKind::LogicalXOr(left, right)
+            | AnalysisKind::BitwiseAnd(left, right)
+            | AnalysisKind::BitwiseOr(left, right)
+            | AnalysisKind::BitwiseXOr(left, right)
+            | AnalysisKind::ShiftLeft(left, right)
+            | AnalysisKind::ShiftRight(left, right)
+            | AnalysisKind::Equal(left, right)
+            | AnalysisKind::NotEqual(left, right)
+            | AnalysisKind::Less(left, right)
+            | AnalysisKind::LessOrEqual(left, right)
+            | AnalysisKind::Greater(left, right)
+            | AnalysisKind::GreaterOrEqual(left, right)
+            | AnalysisKind::While(left, right, _) => {
+                self.evaluable(left, locals)?;
+                self.evaluable(right, locals)
+            }
+
+            AnalysisKind::Array(members)
+            | AnalysisKind::Tuple(members)
+            | AnalysisKind::Block(members) => members
+                .iter()
+                .try_for_each(|member| self.evaluable(member, locals)),
+
+            AnalysisKind::Index(index) => {
+                self.evaluable(&index.target, locals)?;
+                index
+                    .members
+                    .iter()
+                    .try_for_each(|member| self.evaluable(member, locals))
+            }
+            AnalysisKind::Invoke(invoke) => {
+                self.evaluable(&invoke.target, locals)?;
+                invoke
+                    .members
+                    .iter()
+                    .try_for_each(|member| self.evaluable(member, locals))
+            }
+            AnalysisKind::Call(target, members) => {
+                reachable(&target.name, locals)?;
+                members
+                    .iter()
+                    .try_for_each(|member| self.evaluable(member, locals))
+            }
+
+            AnalysisKind::Conditional(condition, then, otherwise) => {
+                self.evaluable(condition, locals)?;
+                self.evaluable(then, locals)?;
+                otherwise
+                    .iter()
+                    .try_for_each(|otherwise| self.evaluable(otherwise, locals))
+            }
+            AnalysisKind::Return(value)
+            | AnalysisKind::Break(_, value)
+            | AnalysisKind::Continue(_, value) => value
+                .iter()
+                .try_for_each(|value| self.evaluable(value, locals)),
+
+            AnalysisKind::Usage(name) => reachable(name, locals),
+            AnalysisKind::Symbol(target) => reachable(&target.name, locals),
+            AnalysisKind::Assign(name, value) if locals.contains(name) => {
+                self.evaluable(value, locals)
+            }
+            AnalysisKind::Write(target, value) if locals.contains(&target.name) => {
+                self.evaluable(value, locals)
+            }
+            AnalysisKind::Store(target, value)
+                if Self::root(target).is_some_and(|name| locals.contains(&name)) =>
+            {
+                self.evaluable(target, locals)?;
+                self.evaluable(value, locals)
+            }
+            AnalysisKind::Binding(binding) => {
+                if let Some(value) = &binding.value {
+                    self.evaluable(value, locals)?;
+                }
+
+                Self::declare(&binding.target, locals);
+                Ok(())
+            }
+
+            AnalysisKind::Pack(_, values) => values
+                .iter()
+                .try_for_each(|(_, value)| self.evaluable(value, locals)),
+            AnalysisKind::Constructor(aggregate) => aggregate
+                .members
+                .iter()
+                .try_for_each(|member| self.evaluable(member, locals)),
+            AnalysisKind::Composite(aggregate) => aggregate
+                .members
+                .iter()
+                .try_for_each(|member| self.evaluable(member, locals)),
+            AnalysisKind::Match(scrutinee, arms) => {
+                self.evaluable(scrutinee, locals)?;
+
+                for (_, binders, body) in arms {
+                    locals.extend(binders.iter().copied());
+                    self.evaluable(body, locals)?;
+                }
+
+                Ok(())
+            }
+
+            AnalysisKind::Assign(_, _)
+            | AnalysisKind::Write(_, _)
+            | AnalysisKind::Store(_, _)
+            | AnalysisKind::Assembly(_, _, _)
+            | AnalysisKind::Function(_)
+            | AnalysisKind::Closure(_, _)
+            | AnalysisKind::Structure(_)
+            | AnalysisKind::Union(_)
+            | AnalysisKind::Enumeration(_)
+            | AnalysisKind::Module(_, _) => {
+                Err(AnalyzeError::new(ErrorKind::NonConstant, analysis.span))
+            }
+        }
+    }
+
+    #[cfg(feature = "interpreter")]
+    pub fn fold(
+        resolver: &mut Resolver<'analyzer>,
+        element: &Element<'analyzer>,
+    ) -> Result<Analysis<'analyzer>, AnalyzeError<'analyzer>> {
+        let functions: Vec<_> = resolver
+            .registry
+            .values()
+            .filter(|symbol| {
+                !resolver.constants.contains_key(&symbol.identity)
+                    && !symbol.is_generic()
+                    && !symbol.is_variadic()
+                    && symbol
+                        .kind
+                        .try_unwrap_function()
+                        .is_some_and(|function| function.constant)
+            })
+            .cloned()
+            .collect();
+
+        let mut analyzer = Analyzer::default();
+        analyzer.constants = resolver.constants.values().flatten().cloned().collect();
+
+        for function in functions {
+            let settled = function.analyze(resolver).ok().and_then(|analysis| {
+                let count = analyzer.constants.len();
+                analyzer.settle(analysis);
+                analyzer.constants.get(count).cloned()
+            });
+
+            resolver.constants.insert(function.identity, settled);
+        }
+
+        let mut value = element.analyze(resolver)?;
+        value.typing = resolver.reify(&value.typing);
+
+        analyzer.evaluable(&value, &mut Vec::new())?;
+        analyzer
+            .compute(&value)?
+            .ok_or_else(|| AnalyzeError::new(ErrorKind::NonConstant, value.span))
+    }
+
+    fn evaluate(
+        &self,
+        analysis: &Analysis<'analyzer>,
+    ) -> Result<Analysis<'analyzer>, AnalyzeError<'analyzer>> {
+        let AnalysisKind::Binding(binding) = &analysis.kind else {
+            return Ok(analysis.clone());
+        };
+
+        let Some(value) = &binding.value else {
+            return Ok(analysis.clone());
+        };
+
+        self.evaluable(value, &mut Vec::new())?;
+
+        let Some(literal) = self.compute(value)? else {
+            return Ok(analysis.clone());
+        };
+
+        let mut binding = binding.clone();
+        binding.value = Some(Box::new(literal));
+
+        Ok(Analysis::new(
+            AnalysisKind::Binding(binding),
+            analysis.span,
+            analysis.typing.clone(),
+        ))
+    }
+
+    #[cfg(not(feature = "interpreter"))]
+    fn compute(
+        &self,
+        _: &Analysis<'analyzer>,
+    ) -> Result<Option<Analysis<'analyzer>>, AnalyzeError<'analyzer>> {
+        Ok(None)
+    }
+
+    #[cfg(feature = "interpreter")]
+    fn compute(
+        &self,
+        value: &Analysis<'analyzer>,
+    ) -> Result<Option<Analysis<'analyzer>>, AnalyzeError<'analyzer>> {
+        let mut program = self.constants.clone();
+        program.push(value.clone());
+
+        let result = Engine::new().process(program).map_err(|error| {
+            AnalyzeError::new(ErrorKind::Evaluation(error.kind.to_string()), value.span)
+        })?;
+
+        Ok(Self::literal(result, &value.typing, value.span))
+    }
+
+    #[cfg(feature = "interpreter")]
+    fn literal(
+        value: Value<'analyzer>,
+        typing: &Type<'analyzer>,
+        span: Span,
+    ) -> Option<Analysis<'analyzer>> {
+        let kind = match (value, &typing.kind) {
+            (Value::Integer(value), TypeKind::Integer { size, signed }) => AnalysisKind::Integer {
+                value: value as Integer,
+                size: *size,
+                signed: *signed,
+            },
+            (Value::Float(value), TypeKind::Float { size }) => AnalysisKind::Float {
+                value: Float(value),
+                size: *size,
+            },
+            (Value::Boolean(value), TypeKind::Boolean) => AnalysisKind::Boolean { value },
+            (Value::Character(value), TypeKind::Character) => AnalysisKind::Character { value },
+            (Value::String(value), TypeKind::String) => AnalysisKind::String { value },
+            (Value::Array(values), TypeKind::Array { member, .. }) => AnalysisKind::Array(
+                values
+                    .into_iter()
+                    .map(|value| Self::literal(value, member, span))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            (Value::Tuple(values), TypeKind::Tuple { members })
+                if values.len() == members.len() =>
+            {
+                AnalysisKind::Tuple(
+                    values
+                        .into_iter()
+                        .zip(members.iter())
+                        .map(|(value, member)| Self::literal(value, member, span))
+                        .collect::<Option<Vec<_>>>()?,
+                )
+            }
+            (value, TypeKind::Newtype { member, .. }) => Self::literal(value, member, span)?.kind,
+            _ => return None,
+        };
+
+        Some(Analysis::new(kind, span, typing.clone()))
+    }
+}
@@ -3,12 +3,19 @@ use crate::{
     data::*,
     parser::SymbolKind,
     parser::{Element, ElementKind},
-    resolver::{Resolver, Type, TypeKind},
+    resolver::{next_identity, Resolver, Type, TypeKind},
     scanner::{OperatorKind, PunctuationKind, Token, TokenKind},
+    tracker::Span,
 };
 
-fn target<'a>(id: Option<Identity>, name: Option<Str<'a>>) -> Target<'a> {
-    Target::new(id.unwrap_or_default(), name.unwrap_or_default())
+fn target<'a>(resolver: &Resolver<'a>, id: Option<Identity>, name: Option<Str<'a>>) -> Target<'a> {
+    match id
+        .and_then(|id| resolver.aliases.get(&id))
+        .and_then(|original| resolver.get_symbol(*original))
+    {
+        Some(original) => Target::new(original.identity, original.target().unwrap_or_default()),
+        None => Target::new(id.unwrap_or_default(), name.unwrap_or_default()),
+    }
 }
 
 fn name<'a>(typing: &Type<'a>) -> Option<Str<'a>> {
@@ -56,6 +63,251 @@ fn mutate<'a>(
     }
 }
 
+fn unit<'a>(typing: &Type<'a>, span: Span) -> Analysis<'a> {
+    let (size, signed) = match &typing.kind {
+        TypeKind::Integer { size, signed } => (*size, *signed),
+        _ => (64, true),
+    };
+
+    Analysis::new(
+        AnalysisKind::Integer {
+            value: 1,
+            size,
+            signed,
+        },
+        span,
+        typing.clone(),
+    )
+}
+
+fn call<'a>(
+    resolver: &Resolver<'a>,
+    module: &'static str,
+    name: &'static str,
+    members: Vec<Analysis<'a>>,
+    typing: Type<'a>,
+    span: Span,
+) -> Result<Analysis<'a>, AnalyzeError<'a>> {
+    let symbol = resolver
+        .provided(module, name)
+        .ok_or_else(|| AnalyzeError::new(ErrorKind::Unavailable { module, name }, span))?;
+
+    Ok(Analysis::new(
+        AnalysisKind::Call(
+            Target::new(symbol.identity, symbol.target().unwrap_or_default()),
+            members,
+        ),
+        span,
+        typing,
+    ))
+}
+
+fn parts<'a, 'e>(members: &'e [Element<'a>]) -> Vec<&'e Element<'a>> {
+    let mut parts = Vec::with_capacity(members.len());
+
+    for member in members {
+        match &member.kind {
+            ElementKind::Invoke(inner)
+                if inner.target.target().and_then(|name| name.as_str()) == Some("format") =>
+            {
+                parts.extend(self::parts(&inner.members))
+            }
+            _ => parts.push(member),
+        }
+    }
+
+    parts
+}
+
+fn append<'a>(
+    resolver: &mut Resolver<'a>,
+    buffer: Analysis<'a>,
+    value: Analysis<'a>,
+) -> Result<Analysis<'a>, AnalyzeError<'a>> {
+    let name = match resolver.reify(&value.typing).kind {
+        TypeKind::String => "format_string",
+        TypeKind::Integer { .. } => "format_integer",
+        TypeKind::Float { .. } => "format_float",
+        TypeKind::Boolean => "format_boolean",
+        TypeKind::Character => "format_character",
+        _ => return Err(AnalyzeError::new(ErrorKind::InvalidType, value.span)),
+    };
+
+    let span = value.span;
+
+    call(
+        resolver,
+        "string",
+        name,
+        vec![buffer, value],
+        Type::from(TypeKind::String),
+        span,
+    )
+}
+
+fn display<'a>(
+    resolver: &mut Resolver<'a>,
+    value: Analysis<'a>,
+) -> Result<Analysis<'a>, AnalyzeError<'a>> {
+    let name = match resolver.reify(&value.typing).kind {
+        TypeKind::String => "print_string",
+        TypeKind::Integer { .. } => "print_integer",
+        TypeKind::Float { .. } => "print_float",
+        TypeKind::Boolean => "print_boolean",
+        TypeKind::Character => "print_character",
+        _ => return Err(AnalyzeError::new(ErrorKind::InvalidType, value.span)),
+    };
+
+    let span = value.span;
+
+    call(
+        resolver,
+        "print",
+        name,
+        vec![value],
+        Type::from(TypeKind::Void),
+        span,
+    )
+}
+
+fn iterate<'a>(
+    resolver: &mut Resolver<'a>,
+    binder: &Element<'a>,
+    iterable: Analysis<'a>,
+    body: Analysis<'a>,
+    label: Option<Str<'a>>,
+    yielded: Type<'a>,
+    span: Span,
+) -> Result<AnalysisKind<'a>, AnalyzeError<'a>> {
+    let typing = resolver.reify(&iterable.typing);
+    let identity = next_identity();
+    let collection = Str::from(format!("for.iterable.{}", identity));
+    let index = Str::from(format!("for.index.{}", identity));
+
+    let symbol = |name: Str<'a>, typing: Type<'a>| {
+        Analysis::new(
+            AnalysisKind::Symbol(Target::new(Identity::default(), name)),
+            span,
+            typing,
+        )
+    };
+
+    let bind = |target: Analysis<'a>, value: Analysis<'a>| {
+        let typing = value.typing.clone();
+
+        Analysis::new(
+            AnalysisKind::Binding(Binding::new(
+                Box::new(target),
+                Some(Box::new(value)),
+                typing,
+                BindingKind::Let,
+            )),
+            span,
+            Type::from(TypeKind::Void),
+        )
+    };
+
+    let (counter, start, end) = match &typing.kind {
+        TypeKind::Array { size, .. } => {
+            let counter = Type::from(TypeKind::Integer {
+                size: 64,
+                signed: true,
+            });
+            let bound = |value: Integer| {
+                Analysis::new(
+                    AnalysisKind::Integer {
+                        value,
+                        size: 64,
+                        signed: true,
+                    },
+                    span,
+                    counter.clone(),
+                )
+            };
+
+            (counter.clone(), bound(0), bound(*size as Integer))
+        }
+        TypeKind::Tuple { members } if members.len() == 2 => {
+            let counter = resolver.reify(&members[0]);
+            let bound = |slot: Scale| {
+                Analysis::new(
+                    AnalysisKind::Slot(Box::new(symbol(collection, typing.clone())), slot),
+                    span,
+                    counter.clone(),
+                )
+            };
+
+            (counter.clone(), bound(0), bound(1))
+        }
+        _ => return Err(AnalyzeError::new(ErrorKind::InvalidType, iterable.span)),
+    };
+
+    let current = Analysis::new(
+        AnalysisKind::Subtract(
+            Box::new(symbol(index, counter.clone())),
+            Box::new(unit(&counter, span)),
+        ),
+        span,
+        counter.clone(),
+    );
+
+    let value = match &typing.kind {
+        TypeKind::Array { member, .. } => Analysis::new(
+            AnalysisKind::Index(Index::new(
+                Box::new(symbol(collection, typing.clone())),
+                vec![current],
+            )),
+            span,
+            resolver.reify(member),
+        ),
+        _ => current,
+    };
+
+    let step = Analysis::new(
+        AnalysisKind::Write(
+            Target::new(Identity::default(), index),
+            Box::new(Analysis::new(
+                AnalysisKind::Add(
+                    Box::new(symbol(index, counter.clone())),
+                    Box::new(unit(&counter, span)),
+                ),
+                span,
+                counter.clone(),
+            )),
+        ),
+        span,
+        counter.clone(),
+    );
+
+    let item = Analysis::new(
+        AnalysisKind::Symbol(target(resolver, binder.reference, binder.target())),
+        binder.span,
+        value.typing.clone(),
+    );
+
+    let condition = Analysis::new(
+        AnalysisKind::Less(Box::new(symbol(index, counter.clone())), Box::new(end)),
+        span,
+        Type::from(TypeKind::Boolean),
+    );
+
+    let looping = Analysis::new(
+        AnalysisKind::Block(vec![step, bind(item, value), body]),
+        span,
+        Type::from(TypeKind::Void),
+    );
+
+    Ok(AnalysisKind::Block(vec![
+        bind(symbol(collection, typing.clone()), iterable),
+        bind(symbol(index, counter), start),
+        Analysis::new(
+            AnalysisKind::While(Box::new(condition), Box::new(looping), label),
+            span,
+            yielded,
+        ),
+    ]))
+}
+
 impl<'element> Analyzable<'element> for Element<'element> {
     fn analyze(
         &self,
@@ -91,7 +343,7 @@ impl<'element> Analyzable<'element> for Element<'element> {
                     TokenKind::String(value) => AnalysisKind::String { value: **value },
                     TokenKind::Character(value) => AnalysisKind::Character { value: *value },
                     TokenKind::Identifier(identifier) => {
-                        AnalysisKind::Symbol(target(self.reference, Some(**identifier)))
+                        AnalysisKind::Symbol(target(resolver, self.reference, Some(**identifier)))
                     }
                     _ => unreachable!("unreachable token kind."),
                 };
@@ -154,7 +406,7 @@ impl<'element> Analyzable<'element> for Element<'element> {
 
             ElementKind::Unary(unary) => {
                 if let TokenKind::Operator(operator) = &unary.operator.kind {
-                    let operand = unary.operand.analyze(resolver)?;
+                    let mut operand = unary.operand.analyze(resolver)?;
 
                     let kind = match operator.as_slice() {
                         [OperatorKind::Exclamation] => AnalysisKind::LogicalNot(Box::new(operand)),
@@ -163,6 +415,14 @@ impl<'element> Analyzable<'element> for Element<'element> {
                         [OperatorKind::Minus] => AnalysisKind::Negate(Box::new(operand)),
                         [OperatorKind::Ampersand] => AnalysisKind::AddressOf(Box::new(operand)),
                         [OperatorKind::Star] => AnalysisKind::Dereference(Box::new(operand)),
+                        [OperatorKind::QuestionMark] => {
+                            operand.typing = resolver.reify(&operand.typing);
+                            return Ok(Analysis::new(
+                                AnalysisKind::Propagate(Box::new(operand)),
+                                self.span,
+                                resolver.reify(&typing),
+                            ));
+                        }
                         [OperatorKind::Plus, OperatorKind::Plus] => {
                             let step = Analysis::new(
                                 AnalysisKind::Integer {
@@ -218,6 +478,70 @@ impl<'element> Analyzable<'element> for Element<'element> {
             }
 
             ElementKind::Binary(binary) => {
+                if let Some(label) = Element::label(binary) {
+                    resolver.label = Some(label);
+                    return binary.right.analyze(resolver);
+                }
+
+                if binary
+                    .operator
+                    .kind
+                    .try_unwrap_identifier()
+                    .is_some_and(|identifier| identifier.as_str() == Some("as"))
+                {
+                    let mut value = binary.left.analyze(resolver)?;
+                    value.typing = resolver.reify(&value.typing);
+                    let typing = resolver.reify(&typing);
+
+                    return Ok(Analysis::new(
+                        AnalysisKind::Cast(Box::new(value), typing.clone()),
+                        self.span,
+                        typing,
+                    ));
+                }
+
+                if let Some(method) = self
+                    .reference
+                    .and_then(|reference| resolver.get_symbol(reference))
+                    .filter(|symbol| symbol.kind.is_function())
+                    .filter(|_| {
+                        !matches!(
+                            &binary.operator.kind,
+                            TokenKind::Operator(operator) if operator.as_slice() == [OperatorKind::Dot]
+                        )
+                    })
+                    .cloned()
+                {
+                    let left = binary.left.analyze(resolver)?;
+                    let right = binary.right.analyze(resolver)?;
+                    let name = method.target();
+
+                    let call = Analysis::new(
+                        AnalysisKind::Call(
+                            target(resolver, Some(method.identity), name),
+                            vec![left, right],
+                        ),
+                        self.span,
+                        typing.clone(),
+                    );
+
+                    let negated = matches!(
+                        &binary.operator.kind,
+                        TokenKind::Operator(operator)
+                            if operator.as_slice() == [OperatorKind::Exclamation, OperatorKind::Equal]
+                    ) && !name.is_some_and(|name| name.ends_with("not_equal"));
+
+                    if negated {
+                        return Ok(Analysis::new(
+                            AnalysisKind::LogicalNot(Box::new(call)),
+                            self.span,
+                            typing,
+                        ));
+                    }
+
+                    return Ok(call);
+                }
+
                 let op_kind = if let TokenKind::Operator(operator) = &binary.operator.kind {
                     operator
                 } else {
@@ -232,7 +556,74 @@ impl<'element> Analyzable<'element> for Element<'element> {
                 };
 
                 let kind = match op_kind.as_slice() {
+                    [OperatorKind::Dot, OperatorKind::Dot] => AnalysisKind::Tuple(vec![
+                        binary.left.analyze(resolver)?,
+                        binary.right.analyze(resolver)?,
+                    ]),
+                    [OperatorKind::Dot, OperatorKind::Dot, OperatorKind::Equal] => {
+                        let left = binary.left.analyze(resolver)?;
+                        let right = binary.right.analyze(resolver)?;
+                        let end = Analysis::new(
+                            AnalysisKind::Add(
+                                Box::new(right.clone()),
+                                Box::new(unit(&right.typing, right.span)),
+                            ),
+                            right.span,
+                            right.typing.clone(),
+                        );
+                        AnalysisKind::Tuple(vec![left, end])
+                    }
                     [OperatorKind::Dot] => {
+                        if let Some(variants) = binary
+                            .left
+                            .reference
+                            .and_then(|reference| resolver.get_symbol(reference))
+                            .and_then(|symbol| symbol.kind.try_unwrap_enumeration())
+                            .map(|enumeration| {
+                                enumeration
+                                    .members
+                                    .iter()
+                                    .map(|member| member.target())
+                                    .collect::<Vec<_>>()
+                            })
+                        {
+                            let index = variants
+                                .iter()
+                                .position(|variant| *variant == binary.right.target())
+                                .ok_or_else(|| {
+                                    AnalyzeError::new(
+                                        ErrorKind::InvalidTarget,
+                                        binary.operator.span,
+                                    )
+                                })?;
+
+                            let payload = match &binary.right.kind {
+                                ElementKind::Construct(_) => binary.right.analyze(resolver)?,
+                                _ => Analysis::new(
+                                    AnalysisKind::Pack(
+                                        target(
+                                            resolver,
+                                            binary.right.reference,
+                                            binary.right.target(),
+                                        ),
+                                        Vec::new(),
+                                    ),
+                                    binary.right.span,
+                                    binary.right.typing.clone(),
+                                ),
+                            };
+
+                            return Ok(Analysis::new(
+                                AnalysisKind::Variant(
+                                    target(resolver, binary.left.reference, binary.left.target()),
+                                    index as Scale,
+                                    Box::new(payload),
+                                ),
+                                self.span,
+                                resolver.reify(&typing),
+                            ));
+                        }
+
                         if binary.left.typing.kind.is_module()
                             || binary
                                 .left
@@ -241,6 +632,11 @@ impl<'element> Analyzable<'element> for Element<'element> {
                                 .is_some_and(|symbol| {
                                     !matches!(symbol.kind, SymbolKind::Binding(_))
                                 })
+                            || binary
+                                .right
+                                .reference
+                                .and_then(|reference| resolver.get_symbol(reference))
+                                .is_some_and(|symbol| symbol.kind.is_function())
                         {
                             return binary.right.analyze(resolver);
                         }
@@ -552,25 +948,194 @@ impl<'element> Analyzable<'element> for Element<'element> {
                         AnalysisKind::Conditional(Box::new(condition), Box::new(then), otherwise)
                     }
                     Some("while") => {
-                        let condition = invoke.members[0].analyze(resolver)?;
-                        let body = invoke.members[1].analyze(resolver)?;
-                        AnalysisKind::While(Box::new(condition), Box::new(body))
+                        let ((condition, body), label, _) = resolver.looping(|resolver| {
+                            (
+                                invoke.members[0].analyze(resolver),
+                                invoke.members[1].analyze(resolver),
+                            )
+                        });
+
+                        AnalysisKind::While(Box::new(condition?), Box::new(body?), label)
+                    }
+                    Some("for") => {
+                        let label = resolver.label.take();
+
+                        let ElementKind::Binary(binary) = &invoke.members[0].kind else {
+                            return Err(AnalyzeError::new(
+                                ErrorKind::InvalidTarget,
+                                invoke.members[0].span,
+                            ));
+                        };
+
+                        let iterable = binary.right.analyze(resolver)?;
+
+                        resolver.label = label;
+                        let (body, label, _) =
+                            resolver.looping(|resolver| invoke.members[1].analyze(resolver));
+
+                        iterate(
+                            resolver,
+                            &binary.left,
+                            iterable,
+                            body?,
+                            label,
+                            typing.clone(),
+                            self.span,
+                        )?
+                    }
+                    Some("format") => {
+                        let parts = parts(&invoke.members);
+
+                        if parts.is_empty() {
+                            return Ok(Analysis::new(
+                                AnalysisKind::String {
+                                    value: Str::default(),
+                                },
+                                self.span,
+                                typing,
+                            ));
+                        }
+
+                        let mut result = call(
+                            resolver,
+                            "string",
+                            "format_start",
+                            Vec::new(),
+                            Type::from(TypeKind::String),
+                            self.span,
+                        )?;
+
+                        for part in parts {
+                            let value = part.analyze(resolver)?;
+                            result = append(resolver, result, value)?;
+                        }
+
+                        result.span = self.span;
+                        return Ok(result);
+                    }
+                    Some("println") => {
+                        let parts = parts(&invoke.members);
+                        let mut calls = Vec::with_capacity(parts.len() + 1);
+
+                        for part in parts {
+                            let value = part.analyze(resolver)?;
+                            calls.push(display(resolver, value)?);
+                        }
+
+                        calls.push(call(
+                            resolver,
+                            "print",
+                            "print_newline",
+                            Vec::new(),
+                            Type::from(TypeKind::Void),
+                            self.span,
+                        )?);
+
+                        AnalysisKind::Block(calls)
                     }
                     Some("break") => {
-                        let value = if !invoke.members.is_empty() {
-                            Some(Box::new(invoke.members[0].analyze(resolver)?))
-                        } else {
-                            None
+                        let (label, value) = resolver.escape(&invoke.members);
+                        let value = match value {
+                            Some(index) => Some(Box::new(invoke.members[index].analyze(resolver)?)),
+                            None => None,
                         };
-                        AnalysisKind::Break(value)
+                        AnalysisKind::Break(label, value)
                     }
                     Some("continue") => {
-                        let value = if !invoke.members.is_empty() {
-                            Some(Box::new(invoke.members[0].analyze(resolver)?))
+                        let (label, value) = resolver.escape(&invoke.members);
+                        let value = match value {
+                            Some(index) => Some(Box::new(invoke.members[index].analyze(resolver)?)),
+                            None => None,
+                        };
+                        AnalysisKind::Continue(label, value)
+                    }
+                    Some("match") => {
+                        let mut scrutinee = invoke.members[0].analyze(resolver)?;
+                        scrutinee.typing = resolver.reify(&scrutinee.typing);
+
+                        let variants = resolver
+                            .enumeration(&scrutinee.typing)
+                            .and_then(|owner| {
+                                owner
+                                    .kind
+                                    .try_unwrap_enumeration()
+                                    .map(|enumeration| enumeration.members.clone())
+                            })
+                            .ok_or_else(|| {
+                                AnalyzeError::new(ErrorKind::InvalidType, invoke.members[0].span)
+                            })?;
+
+                        let arms = match &invoke.members[1].kind {
+                            ElementKind::Delimited(delimited) => delimited.members.as_slice(),
+                            _ => &[],
+                        };
+
+                        let mut branches = Vec::with_capacity(arms.len());
+
+                        for arm in arms {
+                            let (Some((name, binders)), ElementKind::Binary(binary)) =
+                                (arm.arm(), &arm.kind)
+                            else {
+                                return Err(AnalyzeError::new(ErrorKind::InvalidTarget, arm.span));
+                            };
+
+                            let index = if name.as_str() == Some("_") {
+                                None
+                            } else {
+                                Some(
+                                    variants
+                                        .iter()
+                                        .position(|variant| variant.target() == Some(name))
+                                        .ok_or_else(|| {
+                                            AnalyzeError::new(
+                                                ErrorKind::InvalidTarget,
+                                                binary.left.span,
+                                            )
+                                        })? as Scale,
+                                )
+                            };
+
+                            branches.push((index, binders, binary.right.analyze(resolver)?));
+                        }
+
+                        return Ok(Analysis::new(
+                            AnalysisKind::Match(Box::new(scrutinee), branches),
+                            self.span,
+                            resolver.reify(&typing),
+                        ));
+                    }
+                    Some("some") => {
+                        let Some(member) = invoke.members.first() else {
+                            return Err(AnalyzeError::new(ErrorKind::InvalidTarget, self.span));
+                        };
+
+                        return Ok(Analysis::new(
+                            AnalysisKind::Wrap(Box::new(member.analyze(resolver)?)),
+                            self.span,
+                            resolver.reify(&typing),
+                        ));
+                    }
+                    Some("none") => {
+                        return Ok(Analysis::new(
+                            AnalysisKind::Nothing,
+                            self.span,
+                            resolver.reify(&typing),
+                        ));
+                    }
+                    Some("ok") | Some("err") => {
+                        let Some(member) = invoke.members.first() else {
+                            return Err(AnalyzeError::new(ErrorKind::InvalidTarget, self.span));
+                        };
+
+                        let value = Box::new(member.analyze(resolver)?);
+
+                        let kind = if invoke.target.target() == Some(Str::from("ok")) {
+                            AnalysisKind::Success(value)
                         } else {
-                            None
+                            AnalysisKind::Failure(value)
                         };
-                        AnalysisKind::Continue(value)
+
+                        return Ok(Analysis::new(kind, self.span, resolver.reify(&typing)));
                     }
                     Some("return") => {
                         let value = if !invoke.members.is_empty() {
@@ -580,8 +1145,72 @@ impl<'element> Analyzable<'element> for Element<'element> {
                         };
                         AnalysisKind::Return(value)
                     }
+                    Some("defer") => {
+                        let Some(body) = invoke.members.first() else {
+                            return Err(AnalyzeError::new(ErrorKind::InvalidTarget, self.span));
+                        };
+
+                        AnalysisKind::Defer(Box::new(body.analyze(resolver)?))
+                    }
+                    Some("asm") => {
+                        let [template, constraints, operands @ ..] = invoke.members.as_slice()
+                        else {
+                            return Err(AnalyzeError::new(ErrorKind::InvalidTarget, self.span));
+                        };
+
+                        let (Some(template), Some(constraints)) =
+                            (template.text(), constraints.text())
+                        else {
+                            return Err(AnalyzeError::new(ErrorKind::InvalidTarget, self.span));
+                        };
+
+                        let operands = operands
+                            .iter()
+                            .map(|operand| operand.analyze(resolver))
+                            .collect::<Result<Vec<_>, _>>()?;
+
+                        return Ok(Analysis::new(
+                            AnalysisKind::Assembly(template, constraints, operands),
+                            self.span,
+                            resolver.reify(&typing),
+                        ));
+                    }
+                    _ if invoke
+                        .target
+                        .reference
+                        .and_then(|reference| resolver.get_symbol(reference))
+                        .is_some_and(|symbol| symbol.kind.is_alias()) =>
+                    {
+                        let Some(member) = invoke.members.first() else {
+                            return Err(AnalyzeError::new(ErrorKind::InvalidTarget, self.span));
+                        };
+
+                        let mut value = member.analyze(resolver)?;
+                        value.span = self.span;
+                        value.typing = resolver.reify(&typing);
+                        return Ok(value);
+                    }
+                    _ if !invoke.target.kind.is_literal()
+                        || invoke
+                            .target
+                            .reference
+                            .and_then(|reference| resolver.get_symbol(reference))
+                            .is_some_and(|symbol| symbol.kind.is_binding()) =>
+                    {
+                        let mut callee = invoke.target.analyze(resolver)?;
+                        callee.typing = resolver.reify(&callee.typing);
+
+                        AnalysisKind::Invoke(Invoke::new(
+                            Box::new(callee),
+                            invoke
+                                .members
+                                .iter()
+                                .map(|member| member.analyze(resolver))
+                                .collect::<Result<Vec<_>, _>>()?,
+                        ))
+                    }
                     _ => AnalysisKind::Call(
-                        target(invoke.target.reference, invoke.target.target()),
+                        target(resolver, invoke.target.reference, invoke.target.target()),
                         invoke
                             .members
                             .iter()
@@ -624,7 +1253,11 @@ impl<'element> Analyzable<'element> for Element<'element> {
 
                 Ok(Analysis::new(
                     AnalysisKind::Pack(
-                        target(construct.target.reference, construct.target.target()),
+                        target(
+                            resolver,
+                            construct.target.reference,
+                            construct.target.target(),
+                        ),
                         values,
                     ),
                     self.span,
@@ -20,6 +20,12 @@ pub enum ErrorKind<'error> {
         name: String,
         expected: String,
     },
+    NonConstant,
+    Evaluation(String),
+    Unavailable {
+        module: &'static str,
+        name: &'static str,
+    },
 }
 
 impl<'error> Display for ErrorKind<'error> {
@@ -59,6 +65,17 @@ impl<'error> Display for ErrorKind<'error> {
             ErrorKind::ContextMismatch { name, expected } => {
                 write!(f, "invalid context for `{}`: expected {}.", name, expected)
             }
+            ErrorKind::NonConstant => write!(f, "cannot evaluate expression at compile time."),
+            ErrorKind::Evaluation(reason) => {
+                write!(f, "compile-time evaluation failed: {}", reason)
+            }
+            ErrorKind::Unavailable { module, name } => {
+                write!(
+                    f,
+                    "`{}.{}` is required here but is not available.",
+                    module, name
+                )
+            }
         }
     }
 }
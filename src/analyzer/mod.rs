@@ -1,5 +1,6 @@
 mod analysis;
 mod analyzer;
+mod constant;
 mod element;
 mod error;
 
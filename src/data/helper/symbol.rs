@@ -17,6 +17,25 @@ pub enum BindingKind {
     Let,
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+#[derive(Debug, Eq)]
+pub struct Alias<Target, Value> {
+    pub target: Target,
+    pub value: Value,
+    pub kind: AliasKind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AliasKind {
+    Transparent,
+    Nominal,
+}
+
 #[derive(Debug, Eq)]
 pub struct Aggregate<Target, Field> {
     pub target: Target,
@@ -37,6 +56,9 @@ pub struct Function<Target, Parameter, Body, Output> {
     pub interface: Interface,
     pub entry: Boolean,
     pub variadic: Boolean,
+    pub constant: Boolean,
+    pub visibility: Visibility,
+    pub attributes: Vec<Attribute<Target>>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -47,6 +69,15 @@ pub enum Interface {
     Compiler,
 }
 
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Attribute<Name> {
+    Inline,
+    Cold,
+    Export,
+    Link(Name),
+    Section(Name),
+}
+
 impl<Target, Value, Type> Binding<Target, Value, Type> {
     #[inline]
     pub fn new(target: Target, value: Option<Value>, annotation: Type, kind: BindingKind) -> Self {
@@ -59,6 +90,17 @@ impl<Target, Value, Type> Binding<Target, Value, Type> {
     }
 }
 
+impl<Target, Value> Alias<Target, Value> {
+    #[inline]
+    pub fn new(target: Target, value: Value, kind: AliasKind) -> Self {
+        Alias {
+            target,
+            value,
+            kind,
+        }
+    }
+}
+
 impl<Target, Field> Aggregate<Target, Field> {
     #[inline]
     pub fn new(target: Target, fields: Vec<Field>) -> Self {
@@ -88,6 +130,9 @@ impl<Target, Parameter, Body, Output> Function<Target, Parameter, Body, Output>
             interface,
             entry,
             variadic,
+            constant: false,
+            visibility: Visibility::Public,
+            attributes: Vec::new(),
         }
     }
 }
@@ -108,6 +153,14 @@ impl<Target: Hash, Value: Hash, Type: Hash> Hash for Binding<Target, Value, Type
     }
 }
 
+impl<Target: Hash, Value: Hash> Hash for Alias<Target, Value> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.target.hash(state);
+        self.value.hash(state);
+        self.kind.hash(state);
+    }
+}
+
 impl<Target: Hash, Field: Hash> Hash for Aggregate<Target, Field> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.target.hash(state);
@@ -143,6 +196,12 @@ impl<Target: PartialEq, Value: PartialEq, Type: PartialEq> PartialEq
     }
 }
 
+impl<Target: PartialEq, Value: PartialEq> PartialEq for Alias<Target, Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target && self.value == other.value && self.kind == other.kind
+    }
+}
+
 impl<Target: PartialEq, Field: PartialEq> PartialEq for Aggregate<Target, Field> {
     fn eq(&self, other: &Self) -> bool {
         self.target == other.target && self.members == other.members
@@ -177,6 +236,12 @@ impl<Target: Clone, Value: Clone, Type: Clone> Clone for Binding<Target, Value,
     }
 }
 
+impl<Target: Clone, Value: Clone> Clone for Alias<Target, Value> {
+    fn clone(&self) -> Self {
+        Alias::new(self.target.clone(), self.value.clone(), self.kind)
+    }
+}
+
 impl<Target: Clone, Field: Clone> Clone for Aggregate<Target, Field> {
     fn clone(&self) -> Self {
         Aggregate::new(self.target.clone(), self.members.clone())
@@ -187,7 +252,7 @@ impl<Target: Clone, Parameter: Clone, Body: Clone, Output: Clone> Clone
     for Function<Target, Parameter, Body, Output>
 {
     fn clone(&self) -> Self {
-        Function::new(
+        let mut function = Function::new(
             self.target.clone(),
             self.members.clone(),
             self.body.clone(),
@@ -195,7 +260,11 @@ impl<Target: Clone, Parameter: Clone, Body: Clone, Output: Clone> Clone
             self.interface.clone(),
             self.entry.clone(),
             self.variadic.clone(),
-        )
+        );
+        function.constant = self.constant;
+        function.visibility = self.visibility;
+        function.attributes = self.attributes.clone();
+        function
     }
 }
 
@@ -215,6 +215,8 @@ impl Dialog {
 
         #[cfg(feature = "interpreter")]
         {
+            engine.machine.checked = session.get_directive(Str::from("Checked")).is_some();
+
             let base_keys = session.all_source_keys();
             for key in &base_keys {
                 if let Some(analyses) = session.records.get(key).and_then(|r| {
@@ -9,6 +9,7 @@ pub enum ErrorKind<'error> {
     InvalidType(Type<'error>),
     UnsupportedFloatWidth(Scale),
     Cast,
+    Address,
     Bitwise(BitwiseError),
     Function(FunctionError),
     Variable(VariableError),
@@ -20,6 +21,8 @@ pub enum ErrorKind<'error> {
     SizeOf,
     Negate,
     Boolean,
+    Overflow,
+    Assembly,
 }
 
 #[derive(Clone, Eq, Debug, PartialEq)]
@@ -89,6 +92,9 @@ pub enum VariableError {
 pub enum ControlFlowError {
     BreakOutsideLoop,
     ContinueOutsideLoop,
+    DeferOutsideBlock,
+    IncompatibleArms,
+    Unmatched,
 }
 
 #[derive(Clone, Debug)]
@@ -155,11 +161,19 @@ impl<'error> Display for ErrorKind<'error> {
             ErrorKind::Normalize => write!(f, "normalization error"),
             ErrorKind::BuilderError(error) => write!(f, "builder error: {}", error),
             ErrorKind::Cast => write!(f, "Unsupported or incompatible cast operation"),
+            ErrorKind::Address => write!(
+                f,
+                "pointers and integers can only be cast into each other by the llvm backend"
+            ),
             ErrorKind::SizeOf => write!(f, "Cannot compute the byte size of the provided type"),
             ErrorKind::Negate => {
                 write!(f, "Operand cannot be negated (must be an Integer or Float)")
             }
             ErrorKind::Boolean => write!(f, "not a Boolean"),
+            ErrorKind::Overflow => write!(f, "integer overflow in checked arithmetic"),
+            ErrorKind::Assembly => {
+                write!(f, "inline assembly is only supported by the llvm backend")
+            }
         }
     }
 }
@@ -332,6 +346,15 @@ impl Display for ControlFlowError {
             ControlFlowError::ContinueOutsideLoop => {
                 write!(f, "continue statement outside of a loop")
             }
+            ControlFlowError::DeferOutsideBlock => {
+                write!(f, "defer statement outside of a block")
+            }
+            ControlFlowError::IncompatibleArms => {
+                write!(f, "match arms produce values of different types")
+            }
+            ControlFlowError::Unmatched => {
+                write!(f, "no match arm matched the scrutinee")
+            }
         }
     }
 }
@@ -0,0 +1,510 @@
+use {
+    crate::{
+        analyzer::Analysis,
+        data::{NonZeroU32, Str},
+        emitter::{
+            inkwell::{GenerateError, Inkwell},
+            ErrorKind,
+        },
+        tracker::Span,
+    },
+    inkwell::{
+        attributes::{Attribute, AttributeLoc},
+        types::{AnyType, BasicMetadataTypeEnum, BasicTypeEnum},
+        values::{BasicMetadataValueEnum, BasicValue, BasicValueEnum, FunctionValue, PointerValue},
+    },
+};
+
+const INTEGERS: usize = 6;
+const VECTORS: usize = 8;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Class {
+    Empty,
+    Integer,
+    Vector,
+    Memory,
+}
+
+#[derive(Clone, Debug)]
+pub enum Passing<'backend> {
+    Direct,
+    Coerced {
+        original: BasicTypeEnum<'backend>,
+        parts: Vec<BasicTypeEnum<'backend>>,
+    },
+    Memory(BasicTypeEnum<'backend>),
+}
+
+#[derive(Clone, Debug)]
+pub struct Convention<'backend> {
+    pub parameters: Vec<Passing<'backend>>,
+    pub output: Option<Passing<'backend>>,
+}
+
+impl<'backend> Passing<'backend> {
+    fn demand(&self) -> (usize, usize) {
+        match self {
+            Passing::Coerced { parts, .. } => {
+                parts.iter().fold((0, 0), |(integers, vectors), part| {
+                    if part.is_int_type() {
+                        (integers + 1, vectors)
+                    } else {
+                        (integers, vectors + 1)
+                    }
+                })
+            }
+            _ => (0, 0),
+        }
+    }
+}
+
+impl<'backend> Convention<'backend> {
+    pub fn trivial(&self) -> bool {
+        self.parameters
+            .iter()
+            .chain(self.output.iter())
+            .all(|passing| matches!(passing, Passing::Direct))
+    }
+
+    pub fn returned(&self) -> bool {
+        matches!(self.output, Some(Passing::Memory(_)))
+    }
+}
+
+impl<'backend> Inkwell<'backend> {
+    fn scatter(&self, layout: BasicTypeEnum<'backend>, offset: u64, classes: &mut [Class]) {
+        let size = self.target.get_abi_size(&layout);
+        let align = self.target.get_abi_alignment(&layout) as u64;
+
+        let class = match layout {
+            BasicTypeEnum::IntType(_) | BasicTypeEnum::PointerType(_) => Class::Integer,
+            BasicTypeEnum::FloatType(float) if float != self.context.f128_type() => Class::Vector,
+            BasicTypeEnum::ArrayType(array) => {
+                let element = array.get_element_type();
+                let stride = self.target.get_abi_size(&element);
+
+                for index in 0..array.len() as u64 {
+                    self.scatter(element, offset + index * stride, classes);
+                }
+
+                return;
+            }
+            BasicTypeEnum::StructType(shape) => {
+                for (index, field) in shape.get_field_types().into_iter().enumerate() {
+                    let cursor = self
+                        .target
+                        .offset_of_element(&shape, index as u32)
+                        .unwrap_or_default();
+
+                    self.scatter(field, offset + cursor, classes);
+                }
+
+                return;
+            }
+            _ => Class::Memory,
+        };
+
+        if size == 0 {
+            return;
+        }
+
+        if offset % align != 0 {
+            classes.iter_mut().for_each(|slot| *slot = Class::Memory);
+            return;
+        }
+
+        for slot in &mut classes[(offset / 8) as usize..=((offset + size - 1) / 8) as usize] {
+            *slot = match (*slot, class) {
+                (Class::Memory, _) | (_, Class::Memory) => Class::Memory,
+                (Class::Integer, _) | (_, Class::Integer) => Class::Integer,
+                _ => Class::Vector,
+            };
+        }
+    }
+
+    fn piece(&self, class: Class, bytes: u64) -> BasicTypeEnum<'backend> {
+        match class {
+            Class::Vector if bytes > 4 => self.context.f64_type().into(),
+            Class::Vector => self.context.f32_type().into(),
+            _ => match bytes {
+                1 => self.context.i8_type().into(),
+                2 => self.context.i16_type().into(),
+                4 => self.context.i32_type().into(),
+                8 => self.context.i64_type().into(),
+                size => self
+                    .context
+                    .custom_width_int_type(NonZeroU32::new(size as u32 * 8).unwrap())
+                    .unwrap()
+                    .into(),
+            },
+        }
+    }
+
+    pub fn classify(&self, layout: BasicTypeEnum<'backend>) -> Passing<'backend> {
+        if !layout.is_struct_type() {
+            return Passing::Direct;
+        }
+
+        let size = self.target.get_abi_size(&layout);
+
+        if size == 0 {
+            return Passing::Direct;
+        }
+
+        if size > 16 {
+            return Passing::Memory(layout);
+        }
+
+        let count = size.div_ceil(8) as usize;
+        let mut classes = vec![Class::Empty; count];
+
+        self.scatter(layout, 0, &mut classes);
+
+        if classes.contains(&Class::Memory) {
+            return Passing::Memory(layout);
+        }
+
+        let parts = classes
+            .iter()
+            .enumerate()
+            .map(|(index, class)| {
+                let bytes = (size - index as u64 * 8).min(8);
+                self.piece(*class, bytes)
+            })
+            .collect();
+
+        Passing::Coerced {
+            original: layout,
+            parts,
+        }
+    }
+
+    pub fn convention(
+        &self,
+        parameters: &[BasicTypeEnum<'backend>],
+        output: Option<BasicTypeEnum<'backend>>,
+    ) -> Convention<'backend> {
+        let output = output.map(|layout| self.classify(layout));
+
+        let mut integers = INTEGERS;
+        let mut vectors = VECTORS;
+
+        if matches!(output, Some(Passing::Memory(_))) {
+            integers -= 1;
+        }
+
+        let parameters = parameters
+            .iter()
+            .map(|layout| {
+                let passing = self.classify(*layout);
+
+                let (integer, vector) = match passing {
+                    Passing::Direct if layout.is_float_type() => (0, 1),
+                    Passing::Direct => (1, 0),
+                    _ => passing.demand(),
+                };
+
+                if integer <= integers && vector <= vectors {
+                    integers -= integer;
+                    vectors -= vector;
+                    passing
+                } else if let Passing::Coerced { original, .. } = passing {
+                    Passing::Memory(original)
+                } else {
+                    passing
+                }
+            })
+            .collect();
+
+        Convention { parameters, output }
+    }
+
+    pub fn lower(
+        &self,
+        parameters: &[BasicTypeEnum<'backend>],
+        output: Option<BasicTypeEnum<'backend>>,
+        convention: &Convention<'backend>,
+    ) -> (
+        Vec<BasicMetadataTypeEnum<'backend>>,
+        Option<BasicTypeEnum<'backend>>,
+    ) {
+        let pointer = self.context.ptr_type(inkwell::AddressSpace::default());
+        let mut lowered: Vec<BasicMetadataTypeEnum> = Vec::with_capacity(parameters.len());
+
+        if convention.returned() {
+            lowered.push(pointer.into());
+        }
+
+        for (layout, passing) in parameters.iter().zip(convention.parameters.iter()) {
+            match passing {
+                Passing::Direct => lowered.push((*layout).into()),
+                Passing::Coerced { parts, .. } => {
+                    lowered.extend(parts.iter().map(|part| BasicMetadataTypeEnum::from(*part)))
+                }
+                Passing::Memory(_) => lowered.push(pointer.into()),
+            }
+        }
+
+        let output = match &convention.output {
+            Some(Passing::Coerced { parts, .. }) => Some(self.merge(parts)),
+            Some(Passing::Memory(_)) => None,
+            _ => output,
+        };
+
+        (lowered, output)
+    }
+
+    fn merge(&self, parts: &[BasicTypeEnum<'backend>]) -> BasicTypeEnum<'backend> {
+        match parts {
+            [part] => *part,
+            _ => self.context.struct_type(parts, false).into(),
+        }
+    }
+
+    fn attributes(&self, convention: &Convention<'backend>) -> Vec<(u32, Attribute)> {
+        let mut attributes = Vec::new();
+        let mut index = 0;
+
+        if let Some(Passing::Memory(layout)) = &convention.output {
+            let kind = Attribute::get_named_enum_kind_id("sret");
+
+            attributes.push((
+                index,
+                self.context
+                    .create_type_attribute(kind, layout.as_any_type_enum()),
+            ));
+
+            index += 1;
+        }
+
+        for passing in &convention.parameters {
+            match passing {
+                Passing::Coerced { parts, .. } => index += parts.len() as u32,
+                Passing::Memory(layout) => {
+                    let kind = Attribute::get_named_enum_kind_id("byval");
+
+                    attributes.push((
+                        index,
+                        self.context
+                            .create_type_attribute(kind, layout.as_any_type_enum()),
+                    ));
+
+                    index += 1;
+                }
+                Passing::Direct => index += 1,
+            }
+        }
+
+        attributes
+    }
+
+    pub fn mark(&self, function: FunctionValue<'backend>, convention: &Convention<'backend>) {
+        for (index, attribute) in self.attributes(convention) {
+            function.add_attribute(AttributeLoc::Param(index), attribute);
+        }
+    }
+
+    fn spill(
+        &mut self,
+        original: BasicTypeEnum<'backend>,
+        parts: &[BasicTypeEnum<'backend>],
+        span: Span,
+    ) -> Result<PointerValue<'backend>, GenerateError<'backend>> {
+        let function = self.parent(span)?;
+        let merged = self.merge(parts);
+
+        let layout = if self.target.get_abi_size(&merged) > self.target.get_abi_size(&original) {
+            merged
+        } else {
+            original
+        };
+
+        let align = self
+            .target
+            .get_abi_alignment(&merged)
+            .max(self.target.get_abi_alignment(&original));
+
+        let slot = self.build_entry(function, layout, Str::from("coerced"));
+
+        if let Some(instruction) = slot.as_instruction_value() {
+            instruction.set_alignment(align).ok();
+        }
+
+        Ok(slot)
+    }
+
+    fn split(
+        &mut self,
+        value: BasicValueEnum<'backend>,
+        original: BasicTypeEnum<'backend>,
+        parts: &[BasicTypeEnum<'backend>],
+        span: Span,
+    ) -> Result<Vec<BasicValueEnum<'backend>>, GenerateError<'backend>> {
+        let slot = self.spill(original, parts, span)?;
+
+        self.builder
+            .build_store(slot, value)
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let shape = self.context.struct_type(parts, false);
+        let mut values = Vec::with_capacity(parts.len());
+
+        for (index, part) in parts.iter().enumerate() {
+            let pointer = self
+                .builder
+                .build_struct_gep(shape, slot, index as u32, "part")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+            let value = self
+                .builder
+                .build_load(*part, pointer, "part")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+
+    fn join(
+        &mut self,
+        value: BasicValueEnum<'backend>,
+        original: BasicTypeEnum<'backend>,
+        parts: &[BasicTypeEnum<'backend>],
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let slot = self.spill(original, parts, span)?;
+
+        self.builder
+            .build_store(slot, value)
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder
+            .build_load(original, slot, "joined")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))
+    }
+
+    pub fn foreign(
+        &mut self,
+        function: FunctionValue<'backend>,
+        convention: Convention<'backend>,
+        values: Vec<Analysis<'backend>>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let parent = self.parent(span)?;
+        let params = function.get_type().get_param_types();
+        let mut arguments: Vec<BasicMetadataValueEnum> = Vec::with_capacity(params.len());
+
+        let returned = match &convention.output {
+            Some(Passing::Memory(layout)) => {
+                let slot = self.build_entry(parent, *layout, Str::from("returned"));
+                arguments.push(slot.into());
+                Some((slot, *layout))
+            }
+            _ => None,
+        };
+
+        for (index, argument) in values.into_iter().enumerate() {
+            let value = self.analysis(argument)?;
+
+            match convention.parameters.get(index) {
+                Some(Passing::Coerced { original, parts }) => {
+                    let value = self.coerce(value, *original, span)?;
+
+                    for part in self.split(value, *original, parts, span)? {
+                        arguments.push(part.into());
+                    }
+                }
+                Some(Passing::Memory(layout)) => {
+                    let value = self.coerce(value, *layout, span)?;
+                    let copy = self.build_entry(parent, *layout, Str::from("copy"));
+
+                    self.builder.build_store(copy, value).map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?;
+
+                    arguments.push(copy.into());
+                }
+                _ => {
+                    let value = match params
+                        .get(arguments.len())
+                        .and_then(|layout| BasicTypeEnum::try_from(*layout).ok())
+                    {
+                        Some(expected) => self.coerce(value, expected, span)?,
+                        None => value,
+                    };
+
+                    arguments.push(value.into());
+                }
+            }
+        }
+
+        let call = self
+            .builder
+            .build_call(function, &arguments, "call")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        for (index, attribute) in self.attributes(&convention) {
+            call.add_attribute(AttributeLoc::Param(index), attribute);
+        }
+
+        let result = call.try_as_basic_value().basic();
+
+        match (returned, &convention.output, result) {
+            (Some((slot, layout)), _, _) => self
+                .builder
+                .build_load(layout, slot, "returned")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span)),
+            (None, Some(Passing::Coerced { original, parts }), Some(value)) => {
+                self.join(value, *original, parts, span)
+            }
+            (None, _, Some(value)) => Ok(value),
+            _ => Ok(self.context.i64_type().const_zero().into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            analyzer::Analyzer,
+            data::{memory::Arc, Str},
+            emitter::{EmitCombinator, GenerateCombinator, RunCombinator},
+            internal::{
+                session::{Initialize, Prepare},
+                Session,
+            },
+            parser::Parser,
+            resolver::Resolver,
+            scanner::Scanner,
+        },
+        chaint::Operation,
+    };
+
+    #[test]
+    fn structures_cross_the_c_boundary() {
+        let pipeline = Operation::plan(vec![
+            Operation::new(Arc::new(Initialize {
+                flag: Str::from("./examples/abi.axo ./examples/abi.c"),
+            })),
+            Operation::new(Arc::new(Prepare)),
+            Operation::new(Arc::new(Scanner::default())),
+            Operation::new(Arc::new(Parser::default())),
+            Operation::new(Arc::new(Resolver::default())),
+            Operation::new(Arc::new(Analyzer::default())),
+            Operation::new(Arc::new(GenerateCombinator)),
+            Operation::new(Arc::new(EmitCombinator)),
+            Operation::new(Arc::new(RunCombinator)),
+        ]);
+
+        let session = Session::new().run(pipeline);
+
+        assert!(
+            session.errors.is_empty(),
+            "expected no error, got {}",
+            session.errors.len()
+        );
+    }
+}
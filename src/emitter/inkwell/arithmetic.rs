@@ -1,10 +1,14 @@
 use {
     crate::{
         analyzer::Analysis,
-        emitter::{ErrorKind, GenerateError, Inkwell},
+        emitter::{BuilderError, ErrorKind, FunctionError, GenerateError, Inkwell},
         tracker::Span,
     },
-    inkwell::{values::BasicValueEnum, IntPredicate},
+    inkwell::{
+        intrinsics::Intrinsic,
+        values::{BasicValueEnum, IntValue},
+        IntPredicate,
+    },
 };
 
 impl<'backend> Inkwell<'backend> {
@@ -46,12 +50,62 @@ impl<'backend> Inkwell<'backend> {
         }
     }
 
+    pub fn overflow(
+        &self,
+        name: &str,
+        left: IntValue<'backend>,
+        right: IntValue<'backend>,
+        span: Span,
+    ) -> Result<IntValue<'backend>, GenerateError<'backend>> {
+        let declaration = Intrinsic::find(name)
+            .and_then(|intrinsic| {
+                intrinsic.get_declaration(self.current_module(), &[left.get_type().into()])
+            })
+            .ok_or_else(|| {
+                GenerateError::new(
+                    ErrorKind::Function(FunctionError::Undefined {
+                        name: name.to_string(),
+                    }),
+                    span,
+                )
+            })?;
+
+        let outcome = self
+            .builder
+            .build_call(declaration, &[left.into(), right.into()], "checked")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?
+            .try_as_basic_value()
+            .basic()
+            .ok_or_else(|| {
+                GenerateError::new(ErrorKind::BuilderError(BuilderError::Function), span)
+            })?
+            .into_struct_value();
+
+        let value = self
+            .builder
+            .build_extract_value(outcome, 0, "value")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?
+            .into_int_value();
+
+        let overflowed = self
+            .builder
+            .build_extract_value(outcome, 1, "overflowed")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?
+            .into_int_value();
+
+        self.trap(Some(overflowed), "integer overflow", span)?;
+
+        Ok(value)
+    }
+
     pub fn add(
         &mut self,
         left: Box<Analysis<'backend>>,
         right: Box<Analysis<'backend>>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let signed = self.infer_signedness(&left).unwrap_or(true);
+
         let left_value = self.analysis(*left)?;
         let right_value = self.analysis(*right)?;
 
@@ -70,6 +124,23 @@ impl<'backend> Inkwell<'backend> {
             return Ok(result.into());
         }
 
+        if self.checked {
+            let name = if signed {
+                "llvm.sadd.with.overflow"
+            } else {
+                "llvm.uadd.with.overflow"
+            };
+
+            return self
+                .overflow(
+                    name,
+                    primary.into_int_value(),
+                    secondary.into_int_value(),
+                    span,
+                )
+                .map(Into::into);
+        }
+
         let result = self
             .builder
             .build_int_add(primary.into_int_value(), secondary.into_int_value(), "add")
@@ -84,6 +155,8 @@ impl<'backend> Inkwell<'backend> {
         right: Box<Analysis<'backend>>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let signed = self.infer_signedness(&left).unwrap_or(true);
+
         let left_value = self.analysis(*left)?;
         let right_value = self.analysis(*right)?;
 
@@ -102,6 +175,23 @@ impl<'backend> Inkwell<'backend> {
             return Ok(result.into());
         }
 
+        if self.checked {
+            let name = if signed {
+                "llvm.ssub.with.overflow"
+            } else {
+                "llvm.usub.with.overflow"
+            };
+
+            return self
+                .overflow(
+                    name,
+                    primary.into_int_value(),
+                    secondary.into_int_value(),
+                    span,
+                )
+                .map(Into::into);
+        }
+
         let result = self
             .builder
             .build_int_sub(
@@ -120,6 +210,8 @@ impl<'backend> Inkwell<'backend> {
         right: Box<Analysis<'backend>>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let signed = self.infer_signedness(&left).unwrap_or(true);
+
         let left_value = self.analysis(*left)?;
         let right_value = self.analysis(*right)?;
 
@@ -138,6 +230,23 @@ impl<'backend> Inkwell<'backend> {
             return Ok(result.into());
         }
 
+        if self.checked {
+            let name = if signed {
+                "llvm.smul.with.overflow"
+            } else {
+                "llvm.umul.with.overflow"
+            };
+
+            return self
+                .overflow(
+                    name,
+                    primary.into_int_value(),
+                    secondary.into_int_value(),
+                    span,
+                )
+                .map(Into::into);
+        }
+
         let result = self
             .builder
             .build_int_mul(
@@ -217,7 +326,7 @@ impl<'backend> Inkwell<'backend> {
                 .build_or(is_zero, is_overflow, "condition")
                 .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
 
-            self.trap(Some(condition), span)?;
+            self.trap(Some(condition), "division overflow", span)?;
 
             let result = self
                 .builder
@@ -226,7 +335,7 @@ impl<'backend> Inkwell<'backend> {
 
             Ok(result.into())
         } else {
-            self.trap(Some(is_zero), span)?;
+            self.trap(Some(is_zero), "division by zero", span)?;
 
             let result = self
                 .builder
@@ -304,7 +413,7 @@ impl<'backend> Inkwell<'backend> {
                 .build_or(is_zero, is_overflow, "condition")
                 .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
 
-            self.trap(Some(condition), span)?;
+            self.trap(Some(condition), "modulus overflow", span)?;
 
             let result = self
                 .builder
@@ -313,7 +422,7 @@ impl<'backend> Inkwell<'backend> {
 
             Ok(result.into())
         } else {
-            self.trap(Some(is_zero), span)?;
+            self.trap(Some(is_zero), "modulus by zero", span)?;
 
             let result = self
                 .builder
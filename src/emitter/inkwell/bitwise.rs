@@ -143,7 +143,7 @@ impl<'backend> Inkwell<'backend> {
             .build_int_compare(IntPredicate::UGE, amount, limit, "check")
             .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
 
-        self.trap(Some(condition), span)?;
+        self.trap(Some(condition), "shift amount exceeds width", span)?;
 
         Ok(BasicValueEnum::from(
             self.builder
@@ -187,7 +187,7 @@ impl<'backend> Inkwell<'backend> {
             .build_int_compare(IntPredicate::UGE, amount, limit, "check")
             .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
 
-        self.trap(Some(condition), span)?;
+        self.trap(Some(condition), "shift amount exceeds width", span)?;
 
         Ok(BasicValueEnum::from(
             self.builder
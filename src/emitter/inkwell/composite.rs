@@ -1,7 +1,7 @@
 use {
     crate::{
         analyzer::{Analysis, AnalysisKind, Target},
-        data::{Aggregate, Index, Scale, Str},
+        data::{Aggregate, Binding, BindingKind, Index, Scale, Str},
         emitter::{
             inkwell::{Entity, GenerateError, Inkwell},
             BuilderError, DataStructureError, ErrorKind,
@@ -10,9 +10,9 @@ use {
         tracker::Span,
     },
     inkwell::{
-        types::{BasicType, BasicTypeEnum},
+        types::{BasicType, BasicTypeEnum, StructType},
         values::{BasicValue, BasicValueEnum, IntValue},
-        IntPredicate,
+        AddressSpace, IntPredicate,
     },
 };
 
@@ -32,30 +32,112 @@ impl<'backend> Inkwell<'backend> {
             .unwrap_or(0)
     }
 
+    pub fn alignment(&self, typing: BasicTypeEnum<'backend>) -> u64 {
+        typing
+            .get_alignment()
+            .get_zero_extended_constant()
+            .unwrap_or(1)
+    }
+
+    pub fn overlay(&self, shape: StructType<'backend>, typings: &[BasicTypeEnum<'backend>]) {
+        let size = typings
+            .iter()
+            .map(|typing| self.size(*typing))
+            .max()
+            .unwrap_or(0);
+
+        let Some(anchor) = typings
+            .iter()
+            .copied()
+            .max_by_key(|typing| (self.alignment(*typing), self.size(*typing)))
+        else {
+            shape.set_body(&[], false);
+            return;
+        };
+
+        let padding = size - self.size(anchor);
+
+        if padding == 0 {
+            shape.set_body(&[anchor], false);
+        } else {
+            let filler = self.context.i8_type().array_type(padding as u32);
+            shape.set_body(&[anchor, filler.into()], false);
+        }
+    }
+
+    pub fn locate(&self, span: Span) -> String {
+        let Some(record) = self.sources.get(&span.identity) else {
+            return String::from("<unknown>");
+        };
+
+        let length = record.content().map_or(0, |content| content.len() as u32);
+        let (line, column) = record
+            .offset_to_line_column(span.start.min(length))
+            .unwrap_or((0, 0));
+
+        format!("{}:{}:{}", record.location, line + 1, column + 1)
+    }
+
+    fn abort(&self, reason: &str, span: Span) -> Result<(), GenerateError<'backend>> {
+        let module = self.current_module();
+
+        if self.trapping {
+            let function = module.get_function("llvm.trap").unwrap_or_else(|| {
+                let shape = self.context.void_type().fn_type(&[], false);
+                module.add_function("llvm.trap", shape, None)
+            });
+
+            self.builder
+                .build_call(function, &[], "")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+        } else {
+            let pointer = self.context.ptr_type(AddressSpace::default());
+
+            let function = module.get_function("trap_report").unwrap_or_else(|| {
+                let shape = self
+                    .context
+                    .void_type()
+                    .fn_type(&[pointer.into(), pointer.into()], false);
+                module.add_function("trap_report", shape, None)
+            });
+
+            let kind = self
+                .builder
+                .build_global_string_ptr(reason, "trap.kind")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+            let location = self
+                .builder
+                .build_global_string_ptr(&self.locate(span), "trap.location")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+            self.builder
+                .build_call(
+                    function,
+                    &[
+                        kind.as_pointer_value().into(),
+                        location.as_pointer_value().into(),
+                    ],
+                    "",
+                )
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+        }
+
+        self.builder
+            .build_unreachable()
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        Ok(())
+    }
+
     pub fn trap(
         &self,
         condition: Option<IntValue<'backend>>,
+        reason: &str,
         span: Span,
     ) -> Result<(), GenerateError<'backend>> {
-        let module = self.current_module();
-
-        let function = module.get_function("llvm.trap").unwrap_or_else(|| {
-            let shape = self.context.void_type().fn_type(&[], false);
-            module.add_function("llvm.trap", shape, None)
-        });
-
         match condition {
-            None => {
-                self.builder
-                    .build_call(function, &[], "trap")
-                    .map_err(|error| {
-                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                    })?;
-
-                self.builder.build_unreachable().map_err(|error| {
-                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                })?;
-            }
+            None => self.abort(reason, span)?,
             Some(value) => {
                 let block = self.builder.get_insert_block().ok_or_else(|| {
                     GenerateError::new(ErrorKind::BuilderError(BuilderError::BlockInsertion), span)
@@ -76,15 +158,7 @@ impl<'backend> Inkwell<'backend> {
 
                 self.builder.position_at_end(fail);
 
-                self.builder
-                    .build_call(function, &[], "")
-                    .map_err(|error| {
-                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                    })?;
-
-                self.builder.build_unreachable().map_err(|error| {
-                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                })?;
+                self.abort(reason, span)?;
 
                 self.builder.position_at_end(pass);
             }
@@ -285,8 +359,6 @@ impl<'backend> Inkwell<'backend> {
         let shape = self.context.get_struct_type(name).unwrap();
 
         let mut members = Vec::with_capacity(union.members.len());
-        let mut maximum = 0;
-        let mut largest: Option<BasicTypeEnum> = None;
 
         for member in &union.members {
             if let AnalysisKind::Binding(binding) = &member.kind {
@@ -294,23 +366,13 @@ impl<'backend> Inkwell<'backend> {
                     let typing = self.to_basic_type(&binding.annotation, member.span.clone())?;
 
                     members.push((field.clone(), typing));
-
-                    let limit = self.size(typing);
-
-                    if limit >= maximum || largest.is_none() {
-                        maximum = limit;
-                        largest = Some(typing);
-                    }
                 }
             }
         }
 
         if shape.is_opaque() {
-            if let Some(target) = largest {
-                shape.set_body(&[target], false);
-            } else {
-                shape.set_body(&[], false);
-            }
+            let typings: Vec<_> = members.iter().map(|(_, typing)| *typing).collect();
+            self.overlay(shape, &typings);
         }
 
         self.update_entity(&identifier, Entity::Union { shape, members });
@@ -324,6 +386,156 @@ impl<'backend> Inkwell<'backend> {
         Ok(self.context.i64_type().const_zero().into())
     }
 
+    pub fn declare_enumeration(
+        &mut self,
+        enumeration: Aggregate<Str<'backend>, Analysis<'backend>>,
+        span: Span,
+    ) -> Result<(), GenerateError<'backend>> {
+        for member in &enumeration.members {
+            if let AnalysisKind::Structure(structure) = &member.kind {
+                self.declare_structure(structure.clone(), member.span)?;
+            }
+        }
+
+        let identifier = enumeration.target.clone();
+        let name = identifier.as_str().unwrap_or("enumeration");
+
+        self.declare_union(
+            Aggregate::new(Str::from(format!("{}.payload", name)), Vec::new()),
+            span,
+        )?;
+
+        let shape = self
+            .context
+            .get_struct_type(name)
+            .unwrap_or_else(|| self.context.opaque_struct_type(name));
+
+        self.insert_entity(
+            identifier,
+            Entity::Enumeration {
+                shape,
+                members: vec![],
+            },
+        );
+
+        Ok(())
+    }
+
+    pub fn define_enumeration(
+        &mut self,
+        enumeration: Aggregate<Str<'backend>, Analysis<'backend>>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let identifier = enumeration.target.clone();
+        let name = identifier.as_str().unwrap_or("enumeration");
+
+        let shape = self.context.get_struct_type(name).unwrap();
+
+        let mut members = Vec::with_capacity(enumeration.members.len());
+        let mut payloads = Vec::with_capacity(enumeration.members.len());
+
+        for member in enumeration.members {
+            if let AnalysisKind::Structure(structure) = member.kind {
+                let head = Analysis::new(
+                    AnalysisKind::Usage(structure.target),
+                    member.span,
+                    member.typing.clone(),
+                );
+
+                payloads.push(Analysis::new(
+                    AnalysisKind::Binding(Binding::new(
+                        Box::new(head),
+                        None,
+                        member.typing.clone(),
+                        BindingKind::Let,
+                    )),
+                    member.span,
+                    member.typing.clone(),
+                ));
+
+                members.push(structure.target);
+                self.define_structure(structure, member.span)?;
+            }
+        }
+
+        let payload = Str::from(format!("{}.payload", name));
+        self.define_union(Aggregate::new(payload.clone(), payloads), span)?;
+
+        let union = self
+            .context
+            .get_struct_type(payload.as_str().unwrap_or("union"))
+            .unwrap();
+
+        if shape.is_opaque() {
+            shape.set_body(&[self.context.i64_type().into(), union.into()], false);
+        }
+
+        self.update_entity(&identifier, Entity::Enumeration { shape, members });
+
+        Ok(self.context.i64_type().const_zero().into())
+    }
+
+    pub fn variant(
+        &mut self,
+        target: Target<'backend>,
+        index: Scale,
+        payload: Box<Analysis<'backend>>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let Some(Entity::Enumeration { shape, .. }) = self.get_entity(&target.name).cloned() else {
+            return Err(GenerateError::new(
+                ErrorKind::DataStructure(DataStructureError::NotAStructType {
+                    name: target.name.as_str().unwrap_or("").to_string(),
+                }),
+                span,
+            ));
+        };
+
+        let value = self.analysis(*payload)?;
+
+        self.tagged(shape, index, value, span)
+    }
+
+    pub fn tagged(
+        &mut self,
+        shape: StructType<'backend>,
+        index: Scale,
+        value: BasicValueEnum<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let block = self.builder.get_insert_block().ok_or_else(|| {
+            GenerateError::new(ErrorKind::BuilderError(BuilderError::BlockInsertion), span)
+        })?;
+
+        let parent = block.get_parent().ok_or_else(|| {
+            GenerateError::new(ErrorKind::BuilderError(BuilderError::Parent), span)
+        })?;
+
+        let pointer = self.build_entry(parent, shape.into(), Str::from("variant"));
+
+        let tag = self
+            .builder
+            .build_struct_gep(shape, pointer, 0, "tag")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder
+            .build_store(tag, self.context.i64_type().const_int(index as u64, false))
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let slot = self
+            .builder
+            .build_struct_gep(shape, pointer, 1, "payload")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder
+            .build_store(slot, value)
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder
+            .build_load(shape, pointer, "value")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))
+    }
+
     pub fn constructor(
         &mut self,
         typing: Type<'backend>,
@@ -806,7 +1018,7 @@ impl<'backend> Inkwell<'backend> {
                             })?;
 
                         self.builder.position_at_end(fail);
-                        self.trap(None, span)?;
+                        self.trap(None, "index out of bounds", span)?;
 
                         self.builder.position_at_end(pass);
 
@@ -892,7 +1104,7 @@ impl<'backend> Inkwell<'backend> {
                     })?;
 
                 self.builder.position_at_end(fail);
-                self.trap(None, span)?;
+                self.trap(None, "index out of bounds", span)?;
 
                 self.builder.position_at_end(pass);
 
@@ -943,3 +1155,29 @@ impl<'backend> Inkwell<'backend> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::Str,
+        emitter::{Context, ContextRef, Inkwell},
+        internal::{Record, RecordKind},
+        tracker::{Location, Span},
+    };
+
+    #[test]
+    fn locate_reports_line_and_column() {
+        let context = Context::create();
+        let reference = unsafe { ContextRef::new(context.raw()) };
+        let mut emitter = Inkwell::new(reference);
+
+        let mut record = Record::new(RecordKind::Source, Location::from("main.axo"));
+        record.set_content(Str::from("let first = 1\nlet second = 2\n    trap()"));
+        emitter.sources.insert(7, record);
+
+        assert_eq!(emitter.locate(Span::range(7, 0, 3)), "main.axo:1:1");
+        assert_eq!(emitter.locate(Span::range(7, 33, 39)), "main.axo:3:5");
+        assert_eq!(emitter.locate(Span::range(7, 1000, 1000)), "main.axo:3:11");
+        assert_eq!(emitter.locate(Span::range(8, 0, 1)), "<unknown>");
+    }
+}
@@ -1,7 +1,7 @@
 use {
     crate::{
         analyzer::{Analysis, AnalysisKind, Target},
-        data::*,
+        data::{memory::take, *},
         emitter::{
             inkwell::{
                 error::{ControlFlowError, FunctionError},
@@ -13,11 +13,15 @@ use {
         tracker::Span,
     },
     inkwell::{
+        attributes::AttributeLoc,
         basic_block::BasicBlock,
         module::Linkage,
-        types::{BasicType, BasicTypeEnum},
-        values::{BasicValue, BasicValueEnum, FunctionValue, IntValue},
-        FloatPredicate, IntPredicate,
+        types::{BasicMetadataTypeEnum, BasicType, BasicTypeEnum, StructType},
+        values::{
+            BasicMetadataValueEnum, BasicValue, BasicValueEnum, FunctionValue, IntValue,
+            PointerValue,
+        },
+        AddressSpace, DLLStorageClass, FloatPredicate, GlobalVisibility, IntPredicate,
     },
 };
 
@@ -142,6 +146,43 @@ impl<'backend> Inkwell<'backend> {
         Ok(self.context.i64_type().const_zero().into())
     }
 
+    fn external(target: Str<'backend>, attributes: &[Attribute<Str<'backend>>]) -> Str<'backend> {
+        attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Link(name) => Some(*name),
+                _ => None,
+            })
+            .unwrap_or(target)
+    }
+
+    fn decorate(&self, value: FunctionValue<'backend>, attributes: &[Attribute<Str<'backend>>]) {
+        for attribute in attributes {
+            let flag = match attribute {
+                Attribute::Inline => "alwaysinline",
+                Attribute::Cold => "cold",
+                Attribute::Export => {
+                    let global = value.as_global_value();
+                    global.set_visibility(GlobalVisibility::Default);
+                    global.set_dll_storage_class(DLLStorageClass::Export);
+                    continue;
+                }
+                Attribute::Section(section) => {
+                    value.as_global_value().set_section(section.as_str());
+                    continue;
+                }
+                Attribute::Link(_) => continue,
+            };
+
+            let kind = inkwell::attributes::Attribute::get_named_enum_kind_id(flag);
+
+            value.add_attribute(
+                AttributeLoc::Function,
+                self.context.create_enum_attribute(kind, 0),
+            );
+        }
+    }
+
     pub fn declare_function(
         &mut self,
         function: Function<
@@ -175,7 +216,7 @@ impl<'backend> Inkwell<'backend> {
                     }
                 };
 
-                parameters.push(layout.into());
+                parameters.push(layout);
             }
         }
 
@@ -184,19 +225,38 @@ impl<'backend> Inkwell<'backend> {
             None => None,
         };
 
+        let convention = if matches!(function.interface, Interface::C) {
+            Some(self.convention(&parameters, output)).filter(|convention| !convention.trivial())
+        } else {
+            None
+        };
+
+        let (parameters, output) = match &convention {
+            Some(convention) => self.lower(&parameters, output, convention),
+            None => (
+                parameters.iter().map(|layout| (*layout).into()).collect(),
+                output,
+            ),
+        };
+
+        let variadic = function.variadic && matches!(function.interface, Interface::C);
+
         let signature = match output {
-            Some(layout) => layout.fn_type(&parameters, function.variadic),
-            None => self
-                .context
-                .void_type()
-                .fn_type(&parameters, function.variadic),
+            Some(layout) => layout.fn_type(&parameters, variadic),
+            None => self.context.void_type().fn_type(&parameters, variadic),
         };
 
-        let name = function.target.as_str().unwrap_or("function");
+        let external = Self::external(function.target, &function.attributes);
+        let name = external.as_str().unwrap_or("function");
         let module = self.current_module();
 
-        let linkage = if matches!(function.interface, Interface::C) || function.entry {
-            Some(Linkage::External)
+        let local = matches!(function.interface, Interface::Axo)
+            && !function.entry
+            && function.visibility == Visibility::Private
+            && !function.attributes.contains(&Attribute::Export);
+
+        let linkage = if local {
+            Some(Linkage::Internal)
         } else {
             Some(Linkage::External)
         };
@@ -207,11 +267,55 @@ impl<'backend> Inkwell<'backend> {
             module.add_function(name, signature, linkage)
         };
 
+        self.decorate(value, &function.attributes);
+
+        if let Some(convention) = convention {
+            self.mark(value, &convention);
+            self.conventions.insert(function.target, convention);
+        }
+
         self.insert_entity(function.target.clone(), Entity::Function(value));
 
         Ok(())
     }
 
+    fn parameters(
+        &mut self,
+        function: FunctionValue<'backend>,
+        parameters: impl Iterator<Item = BasicValueEnum<'backend>>,
+        members: &[Analysis<'backend>],
+        span: Span,
+    ) -> Result<(), GenerateError<'backend>> {
+        for (parameter, member) in parameters.zip(members.iter()) {
+            if let AnalysisKind::Binding(binding) = &member.kind {
+                if let Some(target) = Self::bind_name(&binding.target) {
+                    let pointer = self.build_entry(function, parameter.get_type(), target.clone());
+                    let align = self.align(parameter.get_type());
+
+                    self.builder
+                        .build_store(pointer, parameter)
+                        .and_then(|inst| {
+                            inst.set_alignment(align).ok();
+                            Ok(inst)
+                        })
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+
+                    self.insert_entity(
+                        target.clone(),
+                        Entity::Variable {
+                            pointer,
+                            typing: binding.annotation.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn define_function(
         &mut self,
         function: Function<
@@ -226,7 +330,8 @@ impl<'backend> Inkwell<'backend> {
             return Ok(self.context.i64_type().const_zero().into());
         }
 
-        let name = function.target.as_str().unwrap_or("function");
+        let external = Self::external(function.target, &function.attributes);
+        let name = external.as_str().unwrap_or("function");
 
         let value = if let Some(existing) = self.current_module().get_function(name) {
             existing
@@ -251,41 +356,20 @@ impl<'backend> Inkwell<'backend> {
 
         let entities = self.entities.clone();
 
-        for (parameter, member) in value.get_param_iter().zip(function.members.iter()) {
-            if let AnalysisKind::Binding(binding) = &member.kind {
-                if let Some(target) = Self::bind_name(&binding.target) {
-                    let pointer = self.build_entry(value, parameter.get_type(), target.clone());
-                    let align = self.align(parameter.get_type());
-
-                    self.builder
-                        .build_store(pointer, parameter)
-                        .and_then(|inst| {
-                            inst.set_alignment(align).ok();
-                            Ok(inst)
-                        })
-                        .map_err(|error| {
-                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                        })?;
-
-                    self.insert_entity(
-                        target.clone(),
-                        Entity::Variable {
-                            pointer,
-                            typing: binding.annotation.clone(),
-                        },
-                    );
-                }
-            }
-        }
+        self.parameters(value, value.get_param_iter(), &function.members, span)?;
 
         self.clear_loops();
 
+        let deferred = take(&mut self.deferred);
+
         let result = if let Some(body) = function.body {
             Some(self.analysis(*body.clone())?)
         } else {
             None
         };
 
+        self.deferred = deferred;
+
         if !self.terminated() {
             if function.output.is_none() {
                 self.builder.build_return(None).map_err(|error| {
@@ -321,6 +405,9 @@ impl<'backend> Inkwell<'backend> {
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
         let mut value = self.context.i64_type().const_zero().into();
 
+        self.deferred.push(Vec::new());
+        let depth = self.deferred.len() - 1;
+
         for analysis in analyses {
             if self.terminated() {
                 break;
@@ -328,9 +415,44 @@ impl<'backend> Inkwell<'backend> {
             value = self.analysis(analysis)?;
         }
 
+        self.release(depth)?;
+        self.deferred.truncate(depth);
+
         Ok(value)
     }
 
+    pub fn defer(
+        &mut self,
+        body: Analysis<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let Some(scope) = self.deferred.last_mut() else {
+            return Err(GenerateError::new(
+                ErrorKind::ControlFlow(ControlFlowError::DeferOutsideBlock),
+                span,
+            ));
+        };
+
+        scope.push(body);
+
+        Ok(self.context.i64_type().const_zero().into())
+    }
+
+    pub fn release(&mut self, depth: usize) -> Result<(), GenerateError<'backend>> {
+        let pending = self.deferred.split_off(depth.min(self.deferred.len()));
+
+        for body in pending.iter().rev().flat_map(|scope| scope.iter().rev()) {
+            if self.terminated() {
+                break;
+            }
+            self.analysis(body.clone())?;
+        }
+
+        self.deferred.extend(pending);
+
+        Ok(())
+    }
+
     pub fn conditional(
         &mut self,
         condition: Analysis<'backend>,
@@ -455,10 +577,181 @@ impl<'backend> Inkwell<'backend> {
         Ok(phi.as_basic_value())
     }
 
+    pub fn r#match(
+        &mut self,
+        scrutinee: Box<Analysis<'backend>>,
+        arms: Vec<(Option<Scale>, Vec<Str<'backend>>, Analysis<'backend>)>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let variants = match &self.value_type(&scrutinee.typing).kind {
+            TypeKind::Enumeration(enumeration) => enumeration.members.clone(),
+            _ => {
+                return Err(GenerateError::new(
+                    ErrorKind::InvalidType(scrutinee.typing.clone()),
+                    span,
+                ))
+            }
+        };
+
+        let shape = self
+            .to_basic_type(&scrutinee.typing, span)?
+            .into_struct_type();
+        let value = self.analysis(*scrutinee)?;
+        let tag = self.tag(value, span)?.into_int_value();
+
+        let parent = self.parent(span)?;
+        let pointer = self.build_entry(parent, shape.into(), Str::from("scrutinee"));
+
+        self.builder
+            .build_store(pointer, value)
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let merge = self.context.append_basic_block(parent, "merge");
+        let mut edges = Vec::with_capacity(arms.len());
+
+        for (index, binders, body) in arms {
+            let pass = self.context.append_basic_block(parent, "arm");
+            let fail = self.context.append_basic_block(parent, "next");
+
+            match index {
+                Some(index) => {
+                    let flag = self
+                        .builder
+                        .build_int_compare(
+                            IntPredicate::EQ,
+                            tag,
+                            tag.get_type().const_int(index as u64, false),
+                            "case",
+                        )
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+
+                    self.builder
+                        .build_conditional_branch(flag, pass, fail)
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+                }
+                None => {
+                    self.builder
+                        .build_unconditional_branch(pass)
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+                }
+            }
+
+            self.builder.position_at_end(pass);
+
+            if let Some(variant) = index.and_then(|index| variants.get(index)) {
+                let fields = match &self.value_type(variant).kind {
+                    TypeKind::Structure(structure) => structure.members.clone(),
+                    _ => Vec::new(),
+                };
+
+                let layout = self.to_basic_type(variant, span)?.into_struct_type();
+                let payload = self
+                    .builder
+                    .build_struct_gep(shape, pointer, 1, "payload")
+                    .map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?;
+
+                for (position, (binder, field)) in binders.iter().zip(fields.iter()).enumerate() {
+                    if binder.is_empty() || binder.as_str() == Some("_") {
+                        continue;
+                    }
+
+                    let typing = self.value_type(field);
+                    let kind = self.to_basic_type(&typing, span)?;
+
+                    let address = self
+                        .builder
+                        .build_struct_gep(layout, payload, position as u32, "field")
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+
+                    let value =
+                        self.builder
+                            .build_load(kind, address, "field")
+                            .map_err(|error| {
+                                GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                            })?;
+
+                    let local = self.build_entry(parent, kind, *binder);
+
+                    self.builder.build_store(local, value).map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?;
+
+                    self.insert_entity(
+                        *binder,
+                        Entity::Variable {
+                            pointer: local,
+                            typing,
+                        },
+                    );
+                }
+            }
+
+            let result = self.analysis(body)?;
+
+            if !self.terminated() {
+                let end = self.builder.get_insert_block().unwrap_or(pass);
+
+                self.builder
+                    .build_unconditional_branch(merge)
+                    .map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?;
+
+                edges.push((result, end));
+            }
+
+            self.builder.position_at_end(fail);
+        }
+
+        self.trap(None, "no matching arm", span)?;
+        self.builder.position_at_end(merge);
+
+        let Some((first, _)) = edges.first() else {
+            self.builder
+                .build_unreachable()
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+            return Ok(self.context.i64_type().const_zero().into());
+        };
+
+        let layout = first.get_type();
+
+        if edges.iter().any(|(value, _)| value.get_type() != layout) {
+            return Err(GenerateError::new(
+                ErrorKind::ControlFlow(ControlFlowError::IncompatibleArms),
+                span,
+            ));
+        }
+
+        let incoming: Vec<(&dyn BasicValue, BasicBlock)> = edges
+            .iter()
+            .map(|(value, block)| (value as &dyn BasicValue, *block))
+            .collect();
+
+        let phi = self
+            .builder
+            .build_phi(layout, "mapping")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+        phi.add_incoming(&incoming);
+
+        Ok(phi.as_basic_value())
+    }
+
     pub fn r#while(
         &mut self,
         condition: Box<Analysis<'backend>>,
         body: Box<Analysis<'backend>>,
+        label: Option<Str<'backend>>,
+        typing: Type<'backend>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
         if self.terminated() {
@@ -470,11 +763,16 @@ impl<'backend> Inkwell<'backend> {
         let core = self.context.append_basic_block(parent, "core");
         let exit = self.context.append_basic_block(parent, "exit");
 
-        let pointer = self.build_entry(parent, self.context.i64_type().into(), "loop".into());
-        let align = self.align(self.context.i64_type().into());
+        let shape = match self.value_type(&typing).kind {
+            TypeKind::Void | TypeKind::Unknown => self.context.i64_type().into(),
+            _ => self.to_basic_type(&typing, span)?,
+        };
+
+        let pointer = self.build_entry(parent, shape, "loop".into());
+        let align = self.align(shape);
 
         self.builder
-            .build_store(pointer, self.context.i64_type().const_zero())
+            .build_store(pointer, shape.const_zero())
             .and_then(|inst| {
                 inst.set_alignment(align).ok();
                 Ok(inst)
@@ -494,7 +792,7 @@ impl<'backend> Inkwell<'backend> {
             .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
 
         self.builder.position_at_end(core);
-        self.enter_loop(start, exit, Some(pointer));
+        self.enter_loop(start, exit, Some(pointer), label);
         self.analysis(*body)?;
         self.exit_loop();
 
@@ -507,7 +805,7 @@ impl<'backend> Inkwell<'backend> {
         self.builder.position_at_end(exit);
         let completed = self
             .builder
-            .build_load(self.context.i64_type(), pointer, "load")
+            .build_load(shape, pointer, "load")
             .and_then(|value| {
                 if let Some(inst) = value.as_instruction_value() {
                     inst.set_alignment(align).ok();
@@ -519,6 +817,33 @@ impl<'backend> Inkwell<'backend> {
         Ok(completed)
     }
 
+    pub(crate) fn coerce(
+        &mut self,
+        value: BasicValueEnum<'backend>,
+        expected: BasicTypeEnum<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        if value.get_type() != expected && value.is_pointer_value() {
+            let align = self.align(expected);
+            self.builder
+                .build_load(expected, value.into_pointer_value(), "load")
+                .and_then(|inst| {
+                    if let Some(instruction) = inst.as_instruction_value() {
+                        instruction.set_alignment(align).ok();
+                    }
+                    Ok(inst)
+                })
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))
+        } else if value.get_type() != expected && value.is_int_value() && expected.is_int_type() {
+            self.builder
+                .build_int_cast(value.into_int_value(), expected.into_int_type(), "cast")
+                .map(Into::into)
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))
+        } else {
+            Ok(value)
+        }
+    }
+
     pub fn call(
         &mut self,
         target: Target<'backend>,
@@ -537,6 +862,10 @@ impl<'backend> Inkwell<'backend> {
             });
 
         if let Some(function) = function {
+            if let Some(convention) = self.conventions.get(&target.name).cloned() {
+                return self.foreign(function, convention, values, span);
+            }
+
             let mut arguments = vec![];
             let params = function.get_type().get_param_types();
 
@@ -545,36 +874,7 @@ impl<'backend> Inkwell<'backend> {
 
                 if let Some(layout) = params.get(index) {
                     if let Ok(expected) = BasicTypeEnum::try_from(*layout) {
-                        if value.get_type() != expected && value.is_pointer_value() {
-                            let align = self.align(expected);
-                            value = self
-                                .builder
-                                .build_load(expected, value.into_pointer_value(), "load")
-                                .and_then(|inst| {
-                                    if let Some(instruction) = inst.as_instruction_value() {
-                                        instruction.set_alignment(align).ok();
-                                    }
-                                    Ok(inst)
-                                })
-                                .map_err(|error| {
-                                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                                })?;
-                        } else if value.get_type() != expected
-                            && value.is_int_value()
-                            && expected.is_int_type()
-                        {
-                            value = self
-                                .builder
-                                .build_int_cast(
-                                    value.into_int_value(),
-                                    expected.into_int_type(),
-                                    "cast",
-                                )
-                                .map_err(|error| {
-                                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                                })?
-                                .into();
-                        }
+                        value = self.coerce(value, expected, span)?;
                     }
                 }
 
@@ -601,81 +901,379 @@ impl<'backend> Inkwell<'backend> {
         ))
     }
 
+    pub fn assembly(
+        &mut self,
+        template: Str<'backend>,
+        constraints: Str<'backend>,
+        operands: Vec<Analysis<'backend>>,
+        typing: Type<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let mut outputs = Vec::new();
+        let mut inputs = Vec::new();
+        let mut tied = Vec::new();
+        let mut clobbers = Vec::new();
+        let mut indirect = Vec::new();
+        let mut values = Vec::new();
+        let mut operands = operands.into_iter();
+
+        for code in constraints
+            .as_str()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+        {
+            if code.starts_with('~') {
+                clobbers.push(code.to_string());
+            } else if code.starts_with('=') && !code.starts_with("=*") {
+                outputs.push(code.to_string());
+            } else if let Some(operand) = operands.next() {
+                let value = self.analysis(operand)?;
+
+                if let Some(register) = code.strip_prefix('+') {
+                    tied.push((outputs.len().to_string(), value));
+                    outputs.push(format!("={}", register));
+                } else if code.starts_with("=*") {
+                    outputs.push(code.to_string());
+                    indirect.push(value);
+                } else {
+                    inputs.push(code.to_string());
+                    values.push(value);
+                }
+            }
+        }
+
+        let (links, tied): (Vec<_>, Vec<_>) = tied.into_iter().unzip();
+
+        let constraints = [outputs, inputs, links, clobbers].concat().join(",");
+        let arguments = [indirect, values, tied].concat();
+
+        let parameters: Vec<BasicMetadataTypeEnum> = arguments
+            .iter()
+            .map(|value| value.get_type().into())
+            .collect();
+        let arguments: Vec<BasicMetadataValueEnum> =
+            arguments.into_iter().map(|value| value.into()).collect();
+
+        let signature = if matches!(self.value_type(&typing).kind, TypeKind::Void) {
+            self.context.void_type().fn_type(&parameters, false)
+        } else {
+            self.to_basic_type(&typing, span)?
+                .fn_type(&parameters, false)
+        };
+
+        let assembly = self.context.create_inline_asm(
+            signature,
+            template.to_string(),
+            constraints,
+            true,
+            false,
+            None,
+            false,
+        );
+
+        let result = self
+            .builder
+            .build_indirect_call(signature, assembly, &arguments, "asm")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        Ok(result
+            .try_as_basic_value()
+            .basic()
+            .unwrap_or_else(|| self.context.i64_type().const_zero().into()))
+    }
+
     pub fn invoke(
         &mut self,
         call: Invoke<Box<Analysis<'backend>>, Analysis<'backend>>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
-        let name = self
-            .function_target(&call.target.typing)
-            .unwrap_or_default();
-        let entity = self
-            .callable(&call.target.typing)
-            .map(|function| self.linked(name, function));
-
-        if let Some(function) = entity {
-            let mut arguments = vec![];
-            let params = function.get_type().get_param_types();
+        let signature = self.signature(&call.target.typing, span)?;
+        let typing = call.target.typing.clone();
 
-            for (index, argument) in call.members.iter().enumerate() {
-                let mut value = self.analysis(argument.clone())?;
+        let BasicValueEnum::StructValue(closure) = self.analysis(*call.target)? else {
+            return Err(GenerateError::new(ErrorKind::InvalidType(typing), span));
+        };
 
-                if let Some(layout) = params.get(index) {
-                    if let Ok(expected) = BasicTypeEnum::try_from(*layout) {
-                        if value.get_type() != expected && value.is_pointer_value() {
-                            let align = self.align(expected);
-                            value = self
-                                .builder
-                                .build_load(expected, value.into_pointer_value(), "load")
-                                .and_then(|inst| {
-                                    if let Some(instruction) = inst.as_instruction_value() {
-                                        instruction.set_alignment(align).ok();
-                                    }
-                                    Ok(inst)
-                                })
-                                .map_err(|error| {
-                                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                                })?;
-                        } else if value.get_type() != expected
-                            && value.is_int_value()
-                            && expected.is_int_type()
-                        {
-                            value = self
-                                .builder
-                                .build_int_cast(
-                                    value.into_int_value(),
-                                    expected.into_int_type(),
-                                    "cast",
-                                )
-                                .map_err(|error| {
-                                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                                })?
-                                .into();
-                        }
-                    }
+        let function = self
+            .builder
+            .build_extract_value(closure, 0, "function")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?
+            .into_pointer_value();
+
+        let environment = self
+            .builder
+            .build_extract_value(closure, 1, "environment")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let mut arguments = vec![environment.into()];
+        let params = signature.get_param_types();
+
+        for (index, argument) in call.members.into_iter().enumerate() {
+            let mut value = self.analysis(argument)?;
+
+            if let Some(layout) = params.get(index + 1) {
+                if let Ok(expected) = BasicTypeEnum::try_from(*layout) {
+                    value = self.coerce(value, expected, span)?;
                 }
+            }
 
-                arguments.push(value.into());
+            arguments.push(value.into());
+        }
+
+        let result = self
+            .builder
+            .build_indirect_call(signature, function, &arguments, "call")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        if let Some(bound) = result.try_as_basic_value().basic() {
+            Ok(bound)
+        } else {
+            Ok(self.context.i64_type().const_zero().into())
+        }
+    }
+
+    fn pair(
+        &self,
+        function: PointerValue<'backend>,
+        environment: PointerValue<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let closure = self
+            .builder
+            .build_insert_value(self.closure_type().get_undef(), function, 0, "function")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let closure = self
+            .builder
+            .build_insert_value(closure, environment, 1, "environment")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        Ok(closure.into_struct_value().into())
+    }
+
+    pub fn thunk(
+        &self,
+        function: FunctionValue<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let name = format!(
+            "{}.thunk",
+            function.get_name().to_str().unwrap_or("function")
+        );
+        let module = self.current_module();
+
+        let thunk = match module.get_function(&name) {
+            Some(existing) => existing,
+            None => {
+                let layout = function.get_type();
+                let mut parameters: Vec<BasicMetadataTypeEnum> =
+                    vec![self.context.ptr_type(AddressSpace::default()).into()];
+                parameters.extend(layout.get_param_types());
+
+                let signature = match layout.get_return_type() {
+                    Some(output) => output.fn_type(&parameters, false),
+                    None => self.context.void_type().fn_type(&parameters, false),
+                };
+
+                let thunk = module.add_function(&name, signature, Some(Linkage::Internal));
+                let builder = self.context.create_builder();
+                builder.position_at_end(self.context.append_basic_block(thunk, "entry"));
+
+                let arguments: Vec<BasicMetadataValueEnum> =
+                    thunk.get_param_iter().skip(1).map(Into::into).collect();
+
+                let result = builder
+                    .build_call(function, &arguments, "call")
+                    .map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?;
+
+                match result.try_as_basic_value().basic() {
+                    Some(value) => builder.build_return(Some(&value)),
+                    None => builder.build_return(None),
+                }
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+                thunk
             }
+        };
 
-            let result = self
+        self.pair(
+            thunk.as_global_value().as_pointer_value(),
+            self.context.ptr_type(AddressSpace::default()).const_null(),
+            span,
+        )
+    }
+
+    pub fn closure(
+        &mut self,
+        function: Function<
+            Str<'backend>,
+            Analysis<'backend>,
+            Option<Box<Analysis<'backend>>>,
+            Option<Type<'backend>>,
+        >,
+        captures: Vec<Analysis<'backend>>,
+        typing: Type<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let mut values = Vec::with_capacity(captures.len());
+
+        for capture in &captures {
+            values.push(self.analysis(capture.clone())?);
+        }
+
+        let layout = self.context.struct_type(
+            &values
+                .iter()
+                .map(|value| value.get_type())
+                .collect::<Vec<_>>(),
+            false,
+        );
+
+        let environment = if values.is_empty() {
+            self.context.ptr_type(AddressSpace::default()).const_null()
+        } else {
+            let pointer = self
                 .builder
-                .build_call(function, &arguments, "call")
+                .build_malloc(layout, "environment")
                 .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
 
-            return if let Some(bound) = result.try_as_basic_value().basic() {
-                Ok(bound)
-            } else {
-                Ok(self.context.i64_type().const_zero().into())
+            for (index, value) in values.into_iter().enumerate() {
+                let slot = self
+                    .builder
+                    .build_struct_gep(layout, pointer, index as u32, "capture")
+                    .map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?;
+
+                self.builder.build_store(slot, value).map_err(|error| {
+                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                })?;
+            }
+
+            pointer
+        };
+
+        let lifted = self.lift(function, captures, layout, &typing, span)?;
+
+        self.pair(
+            lifted.as_global_value().as_pointer_value(),
+            environment,
+            span,
+        )
+    }
+
+    fn lift(
+        &mut self,
+        function: Function<
+            Str<'backend>,
+            Analysis<'backend>,
+            Option<Box<Analysis<'backend>>>,
+            Option<Type<'backend>>,
+        >,
+        captures: Vec<Analysis<'backend>>,
+        layout: StructType<'backend>,
+        typing: &Type<'backend>,
+        span: Span,
+    ) -> Result<FunctionValue<'backend>, GenerateError<'backend>> {
+        let name = function.target.as_str().unwrap_or("closure");
+
+        if let Some(existing) = self.current_module().get_function(name) {
+            return Ok(existing);
+        }
+
+        let signature = self.signature(typing, span)?;
+        let value = self
+            .current_module()
+            .add_function(name, signature, Some(Linkage::Internal));
+
+        let caller = self.builder.get_insert_block();
+        let entities = self.entities.clone();
+        let headers = take(&mut self.loop_headers);
+        let exits = take(&mut self.loop_exits);
+        let results = take(&mut self.loop_results);
+        let scopes = take(&mut self.loop_scopes);
+        let labels = take(&mut self.loop_labels);
+        let deferred = take(&mut self.deferred);
+
+        let entry = self.context.append_basic_block(value, "entry");
+        self.builder.position_at_end(entry);
+
+        let mut parameters = value.get_param_iter();
+        let environment = parameters.next().unwrap().into_pointer_value();
+
+        for (index, capture) in captures.iter().enumerate() {
+            let AnalysisKind::Symbol(target) = &capture.kind else {
+                continue;
             };
+
+            let kind = layout.get_field_type_at_index(index as u32).unwrap();
+
+            let slot = self
+                .builder
+                .build_struct_gep(layout, environment, index as u32, "capture")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+            let loaded = self
+                .builder
+                .build_load(kind, slot, "capture")
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+            let pointer = self.build_entry(value, kind, target.name);
+
+            self.builder
+                .build_store(pointer, loaded)
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+            self.insert_entity(
+                target.name,
+                Entity::Variable {
+                    pointer,
+                    typing: capture.typing.clone(),
+                },
+            );
         }
 
-        Err(GenerateError::new(
-            ErrorKind::Function(FunctionError::Undefined {
-                name: name.to_string(),
-            }),
-            span,
-        ))
+        self.parameters(value, parameters, &function.members, span)?;
+
+        let result = match function.body {
+            Some(body) => Some(self.analysis(*body)?),
+            None => None,
+        };
+
+        if !self.terminated() {
+            match (value.get_type().get_return_type(), result) {
+                (Some(expected), Some(result)) => {
+                    if result.get_type() != expected {
+                        return Err(GenerateError::new(
+                            ErrorKind::Function(FunctionError::IncompatibleReturnType),
+                            span,
+                        ));
+                    }
+
+                    self.builder.build_return(Some(&result))
+                }
+                _ => self.builder.build_return(None),
+            }
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+        }
+
+        self.entities = entities;
+        self.loop_headers = headers;
+        self.loop_exits = exits;
+        self.loop_results = results;
+        self.loop_scopes = scopes;
+        self.loop_labels = labels;
+        self.deferred = deferred;
+
+        if let Some(block) = caller {
+            self.builder.position_at_end(block);
+        }
+
+        Ok(value)
     }
 
     pub fn r#return(
@@ -689,28 +1287,27 @@ impl<'backend> Inkwell<'backend> {
 
         let function = self.parent(span)?;
 
-        match value {
-            Some(item) => {
-                let check = self.analysis(*item)?;
-                if let Some(layout) = function.get_type().get_return_type() {
-                    if check.get_type() != layout {
-                        return Err(GenerateError::new(
-                            ErrorKind::Function(FunctionError::IncompatibleReturnType),
-                            span,
-                        ));
-                    }
-                    self.builder.build_return(Some(&check)).map_err(|error| {
-                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                    })?;
-                    Ok(check)
-                } else {
-                    self.builder.build_return(None).map_err(|error| {
-                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
-                    })?;
-                    Ok(self.context.i64_type().const_zero().into())
+        let value = match value {
+            Some(item) => Some(self.analysis(*item)?),
+            None => None,
+        };
+
+        self.release(0)?;
+
+        match (value, function.get_type().get_return_type()) {
+            (Some(check), Some(layout)) => {
+                if check.get_type() != layout {
+                    return Err(GenerateError::new(
+                        ErrorKind::Function(FunctionError::IncompatibleReturnType),
+                        span,
+                    ));
                 }
+                self.builder.build_return(Some(&check)).map_err(|error| {
+                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                })?;
+                Ok(check)
             }
-            None => {
+            _ => {
                 self.builder.build_return(None).map_err(|error| {
                     GenerateError::new(ErrorKind::BuilderError(error.into()), span)
                 })?;
@@ -721,12 +1318,13 @@ impl<'backend> Inkwell<'backend> {
 
     pub fn r#break(
         &mut self,
+        label: Option<Str<'backend>>,
         value: Option<Box<Analysis<'backend>>>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
         if let Some(item) = value {
             let check = self.analysis(*item)?;
-            if let Some(pointer) = self.current_loop_result() {
+            if let Some(pointer) = self.current_loop_result(label) {
                 let align = self.align(check.get_type());
                 self.builder
                     .build_store(pointer, check)
@@ -744,7 +1342,9 @@ impl<'backend> Inkwell<'backend> {
             return Ok(self.context.i64_type().const_zero().into());
         }
 
-        if let Some(exit) = self.current_loop_exit() {
+        if let Some(exit) = self.current_loop_exit(label) {
+            self.release(self.current_loop_scope(label))?;
+
             self.builder
                 .build_unconditional_branch(exit)
                 .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
@@ -760,12 +1360,13 @@ impl<'backend> Inkwell<'backend> {
 
     pub fn r#continue(
         &mut self,
+        label: Option<Str<'backend>>,
         value: Option<Box<Analysis<'backend>>>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
         if let Some(item) = value {
             let check = self.analysis(*item)?;
-            if let Some(pointer) = self.current_loop_result() {
+            if let Some(pointer) = self.current_loop_result(label) {
                 let align = self.align(check.get_type());
                 self.builder
                     .build_store(pointer, check)
@@ -783,7 +1384,9 @@ impl<'backend> Inkwell<'backend> {
             return Ok(self.context.i64_type().const_zero().into());
         }
 
-        if let Some(start) = self.current_loop_header() {
+        if let Some(start) = self.current_loop_header(label) {
+            self.release(self.current_loop_scope(label))?;
+
             self.builder
                 .build_unconditional_branch(start)
                 .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
@@ -814,9 +1417,26 @@ impl<'backend> Inkwell<'backend> {
         value: Box<Analysis<'backend>>,
         span: Span,
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let signed = !matches!(
+            self.value_type(&value.typing).kind,
+            TypeKind::Integer { signed: false, .. }
+        );
         let check = self.analysis(*value)?;
 
         match check {
+            BasicValueEnum::IntValue(integer) if self.checked => self
+                .overflow(
+                    if signed {
+                        "llvm.ssub.with.overflow"
+                    } else {
+                        "llvm.usub.with.overflow"
+                    },
+                    integer.get_type().const_zero(),
+                    integer,
+                    span,
+                )
+                .map(Into::into),
+
             BasicValueEnum::IntValue(integer) => self
                 .builder
                 .build_int_neg(integer, "negate")
@@ -846,4 +1466,80 @@ impl<'backend> Inkwell<'backend> {
 
         Ok(size.into())
     }
+
+    pub fn cast(
+        &mut self,
+        value: Box<Analysis<'backend>>,
+        typing: Type<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let source = self.value_type(&value.typing);
+        let target = self.value_type(&typing);
+        let signed = matches!(source.kind, TypeKind::Integer { signed: true, .. });
+
+        let value = self.analysis(*value)?;
+        let destination = self.to_basic_type(&target, span)?;
+
+        let result = match (value, destination) {
+            (BasicValueEnum::IntValue(integer), BasicTypeEnum::IntType(_))
+                if matches!(target.kind, TypeKind::Boolean) =>
+            {
+                self.builder
+                    .build_int_compare(
+                        IntPredicate::NE,
+                        integer,
+                        integer.get_type().const_zero(),
+                        "cast",
+                    )
+                    .map(Into::into)
+            }
+            (BasicValueEnum::IntValue(integer), BasicTypeEnum::IntType(destination)) => self
+                .builder
+                .build_int_cast_sign_flag(integer, destination, signed, "cast")
+                .map(Into::into),
+            (BasicValueEnum::IntValue(integer), BasicTypeEnum::FloatType(destination)) => {
+                if signed {
+                    self.builder
+                        .build_signed_int_to_float(integer, destination, "cast")
+                        .map(Into::into)
+                } else {
+                    self.builder
+                        .build_unsigned_int_to_float(integer, destination, "cast")
+                        .map(Into::into)
+                }
+            }
+            (BasicValueEnum::FloatValue(float), BasicTypeEnum::IntType(destination)) => {
+                if matches!(target.kind, TypeKind::Integer { signed: true, .. }) {
+                    self.builder
+                        .build_float_to_signed_int(float, destination, "cast")
+                        .map(Into::into)
+                } else {
+                    self.builder
+                        .build_float_to_unsigned_int(float, destination, "cast")
+                        .map(Into::into)
+                }
+            }
+            (BasicValueEnum::FloatValue(float), BasicTypeEnum::FloatType(destination)) => self
+                .builder
+                .build_float_cast(float, destination, "cast")
+                .map(Into::into),
+            (BasicValueEnum::PointerValue(pointer), BasicTypeEnum::IntType(destination)) => self
+                .builder
+                .build_ptr_to_int(pointer, destination, "cast")
+                .map(Into::into),
+            (BasicValueEnum::IntValue(integer), BasicTypeEnum::PointerType(destination)) => self
+                .builder
+                .build_int_to_ptr(integer, destination, "cast")
+                .map(Into::into),
+            (BasicValueEnum::PointerValue(pointer), BasicTypeEnum::PointerType(destination)) => {
+                self.builder
+                    .build_pointer_cast(pointer, destination, "cast")
+                    .map(Into::into)
+            }
+            (value, destination) if value.get_type() == destination => Ok(value),
+            _ => return Err(GenerateError::new(ErrorKind::Cast, span)),
+        };
+
+        result.map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))
+    }
 }
@@ -1,3 +1,4 @@
+mod abi;
 mod arithmetic;
 mod bitwise;
 mod comparison;
@@ -5,7 +6,9 @@ mod composite;
 mod error;
 mod functions;
 mod logical;
+mod optional;
 mod primitives;
+mod result;
 mod variables;
 
 pub use {
@@ -19,18 +22,21 @@ pub use {
 use {
     crate::{
         analyzer::{Analysis, AnalysisKind},
-        data::{NonZeroU32, Str},
+        data::{Identity, NonZeroU32, Str},
         emitter::GenerateError,
-        internal::hash::Map,
+        internal::{hash::Map, Record},
         resolver::{Type, TypeKind},
         tracker::Span,
     },
+    abi::Convention,
     inkwell::{
         basic_block::BasicBlock,
         builder::Builder,
         module::Module,
-        types::{BasicType, BasicTypeEnum, StructType},
+        targets::{CodeModel, RelocMode, Target, TargetData},
+        types::{BasicMetadataTypeEnum, BasicType, BasicTypeEnum, FunctionType, StructType},
         values::{BasicValueEnum, FunctionValue, PointerValue},
+        OptimizationLevel,
     },
 };
 
@@ -49,6 +55,10 @@ pub enum Entity<'backend> {
         shape: StructType<'backend>,
         members: Vec<(Str<'backend>, BasicTypeEnum<'backend>)>,
     },
+    Enumeration {
+        shape: StructType<'backend>,
+        members: Vec<Str<'backend>>,
+    },
     Function(FunctionValue<'backend>),
 }
 
@@ -57,13 +67,22 @@ pub struct Inkwell<'backend> {
     pub builder: Builder<'backend>,
     pub modules: Map<Str<'backend>, Module<'backend>>,
     pub current_module: Str<'backend>,
+    pub target: TargetData,
 
     entities: Map<Str<'backend>, Entity<'backend>>,
+    conventions: Map<Str<'backend>, Convention<'backend>>,
     pub errors: Vec<GenerateError<'backend>>,
+    pub checked: bool,
+    pub trapping: bool,
+    pub sources: Map<Identity, Record<'backend>>,
 
     loop_headers: Vec<BasicBlock<'backend>>,
     loop_exits: Vec<BasicBlock<'backend>>,
     loop_results: Vec<Option<PointerValue<'backend>>>,
+    loop_scopes: Vec<usize>,
+    loop_labels: Vec<Option<Str<'backend>>>,
+
+    deferred: Vec<Vec<Analysis<'backend>>>,
 }
 
 impl<'backend> Inkwell<'backend> {
@@ -75,6 +94,7 @@ impl<'backend> Inkwell<'backend> {
                 .cloned()
                 .or_else(|| binding.annotation.as_deref().cloned())
                 .unwrap_or_else(|| Type::from(TypeKind::Unknown)),
+            TypeKind::Newtype { member, .. } => self.value_type(member),
             _ => typing.clone(),
         }
     }
@@ -129,25 +149,44 @@ impl<'backend> Inkwell<'backend> {
         }
     }
 
-    fn function_target(&self, typing: &Type<'backend>) -> Option<Str<'backend>> {
-        match &self.value_type(typing).kind {
-            TypeKind::Function(function) if !function.target.is_empty() => Some(function.target),
-            _ => None,
-        }
+    pub fn closure_type(&self) -> StructType<'backend> {
+        let pointer = self.context.ptr_type(inkwell::AddressSpace::default());
+        self.context
+            .struct_type(&[pointer.into(), pointer.into()], false)
     }
 
-    fn callable(&self, typing: &Type<'backend>) -> Option<FunctionValue<'backend>> {
-        let target = self.function_target(typing)?;
+    fn signature(
+        &self,
+        typing: &Type<'backend>,
+        span: Span,
+    ) -> Result<FunctionType<'backend>, GenerateError<'backend>> {
+        let TypeKind::Function(function) = &self.value_type(typing).kind else {
+            return Err(GenerateError::new(
+                ErrorKind::InvalidType(typing.clone()),
+                span,
+            ));
+        };
 
-        self.get_entity(&target)
-            .and_then(|entity| match entity {
-                Entity::Function(function) => Some(*function),
-                _ => None,
-            })
-            .or_else(|| {
-                self.current_module()
-                    .get_function(target.as_str().unwrap_or_default())
-            })
+        let mut parameters: Vec<BasicMetadataTypeEnum> = vec![self
+            .context
+            .ptr_type(inkwell::AddressSpace::default())
+            .into()];
+
+        for member in &function.members {
+            parameters.push(self.to_basic_type(member, span)?.into());
+        }
+
+        let output = function
+            .output
+            .as_deref()
+            .filter(|output| !output.kind.is_void() && !output.kind.is_unknown());
+
+        Ok(match output {
+            Some(output) => self
+                .to_basic_type(output, span)?
+                .fn_type(&parameters, false),
+            None => self.context.void_type().fn_type(&parameters, false),
+        })
     }
 
     pub fn get_entity(&self, name: &Str<'backend>) -> Option<&Entity<'backend>> {
@@ -170,6 +209,8 @@ impl<'backend> Inkwell<'backend> {
         self.loop_headers.clear();
         self.loop_exits.clear();
         self.loop_results.clear();
+        self.loop_scopes.clear();
+        self.loop_labels.clear();
     }
 
     pub fn enter_loop(
@@ -177,28 +218,55 @@ impl<'backend> Inkwell<'backend> {
         header: BasicBlock<'backend>,
         exit: BasicBlock<'backend>,
         result: Option<PointerValue<'backend>>,
+        label: Option<Str<'backend>>,
     ) {
         self.loop_headers.push(header);
         self.loop_exits.push(exit);
         self.loop_results.push(result);
+        self.loop_scopes.push(self.deferred.len());
+        self.loop_labels.push(label);
     }
 
     pub fn exit_loop(&mut self) {
+        self.loop_labels.pop();
+        self.loop_scopes.pop();
         self.loop_results.pop();
         self.loop_exits.pop();
         self.loop_headers.pop();
     }
 
-    pub fn current_loop_header(&self) -> Option<BasicBlock<'backend>> {
-        self.loop_headers.last().copied()
+    fn find_loop(&self, label: Option<Str<'backend>>) -> Option<usize> {
+        match label {
+            Some(name) => self
+                .loop_labels
+                .iter()
+                .rposition(|label| *label == Some(name)),
+            None => self.loop_labels.len().checked_sub(1),
+        }
     }
 
-    pub fn current_loop_exit(&self) -> Option<BasicBlock<'backend>> {
-        self.loop_exits.last().copied()
+    pub fn current_loop_header(
+        &self,
+        label: Option<Str<'backend>>,
+    ) -> Option<BasicBlock<'backend>> {
+        self.find_loop(label).map(|index| self.loop_headers[index])
+    }
+
+    pub fn current_loop_exit(&self, label: Option<Str<'backend>>) -> Option<BasicBlock<'backend>> {
+        self.find_loop(label).map(|index| self.loop_exits[index])
+    }
+
+    pub fn current_loop_result(
+        &self,
+        label: Option<Str<'backend>>,
+    ) -> Option<PointerValue<'backend>> {
+        self.find_loop(label)
+            .and_then(|index| self.loop_results[index])
     }
 
-    pub fn current_loop_result(&self) -> Option<PointerValue<'backend>> {
-        self.loop_results.last().copied().flatten()
+    pub fn current_loop_scope(&self, label: Option<Str<'backend>>) -> usize {
+        self.find_loop(label)
+            .map_or(0, |index| self.loop_scopes[index])
     }
 
     pub fn update_entity(&mut self, name: &Str<'backend>, new_entity: Entity<'backend>) -> bool {
@@ -302,6 +370,38 @@ impl<'backend> Inkwell<'backend> {
                 let typing = self.to_basic_type(member, span.clone())?;
                 typing.array_type(*size as u32).into()
             }
+            TypeKind::Optional { member } => {
+                let member = self.to_basic_type(member, span.clone())?;
+
+                if self.nullable(&typing) {
+                    member
+                } else {
+                    self.context
+                        .struct_type(&[self.context.bool_type().into(), member], false)
+                        .into()
+                }
+            }
+            TypeKind::Result { value, error } => {
+                let value = self.to_basic_type(value, span.clone())?;
+                let error = self.to_basic_type(error, span.clone())?;
+
+                let name = format!(
+                    "result.{}.{}",
+                    value.print_to_string(),
+                    error.print_to_string()
+                );
+
+                let payload = self.context.get_struct_type(&name).unwrap_or_else(|| {
+                    let shape = self.context.opaque_struct_type(&name);
+                    self.overlay(shape, &[value, error]);
+                    shape
+                });
+
+                self.context
+                    .struct_type(&[self.context.i64_type().into(), payload.into()], false)
+                    .into()
+            }
+            TypeKind::Function(_) => self.closure_type().into(),
             TypeKind::Tuple { members } => {
                 let mut typings = Vec::with_capacity(members.len());
                 for member in &**members {
@@ -414,6 +514,22 @@ impl<'backend> Inkwell<'backend> {
                     }
                 }
             }
+            TypeKind::Enumeration(enumeration) => {
+                match self
+                    .get_entity(&enumeration.target)
+                    .and_then(|entity| match entity {
+                        Entity::Enumeration { shape, .. } => Some((*shape).into()),
+                        _ => None,
+                    }) {
+                    Some(typing) => typing,
+                    None => {
+                        return Err(GenerateError::new(
+                            ErrorKind::InvalidType(typing.clone()),
+                            span,
+                        ));
+                    }
+                }
+            }
             _ => {
                 return Err(GenerateError::new(
                     ErrorKind::InvalidType(typing.clone()),
@@ -430,16 +546,40 @@ impl<'backend> Inkwell<'backend> {
 
         inkwell::targets::Target::initialize_all(&inkwell::targets::InitializationConfig::default());
 
+        let triple = TargetMachine::get_default_triple();
+        let target = Target::from_triple(&triple)
+            .ok()
+            .and_then(|target| {
+                target.create_target_machine(
+                    &triple,
+                    "generic",
+                    "",
+                    OptimizationLevel::Default,
+                    RelocMode::Default,
+                    CodeModel::Default,
+                )
+            })
+            .map(|machine| machine.get_target_data())
+            .unwrap_or_else(|| TargetData::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128"));
+
         Self {
             context,
             builder,
             current_module: Default::default(),
+            target,
             entities: Default::default(),
+            conventions: Default::default(),
             modules: Default::default(),
             errors: Vec::new(),
+            checked: false,
+            trapping: false,
+            sources: Default::default(),
             loop_headers: Vec::new(),
             loop_exits: Vec::new(),
             loop_results: Vec::new(),
+            loop_scopes: Vec::new(),
+            loop_labels: Vec::new(),
+            deferred: Vec::new(),
         }
     }
 
@@ -506,6 +646,13 @@ impl<'backend> Inkwell<'backend> {
                         self.errors.push(error);
                     }
                 }
+                AnalysisKind::Enumeration(enumeration) => {
+                    if let Err(error) =
+                        self.declare_enumeration(enumeration.clone(), analysis.span.clone())
+                    {
+                        self.errors.push(error);
+                    }
+                }
                 AnalysisKind::Function(function) => {
                     if let Err(error) =
                         self.declare_function(function.clone(), analysis.span.clone())
@@ -533,6 +680,13 @@ impl<'backend> Inkwell<'backend> {
                         self.errors.push(error);
                     }
                 }
+                AnalysisKind::Enumeration(enumeration) => {
+                    if let Err(error) =
+                        self.define_enumeration(enumeration.clone(), analysis.span.clone())
+                    {
+                        self.errors.push(error);
+                    }
+                }
                 AnalysisKind::Function(function) => {
                     if function.entry {
                         entry = Some((function, analysis.span.clone()));
@@ -599,7 +753,11 @@ impl<'backend> Inkwell<'backend> {
         match analysis.kind {
             AnalysisKind::Structure(structure) => self.define_structure(structure, span),
             AnalysisKind::Union(structure) => self.define_union(structure, span),
+            AnalysisKind::Enumeration(enumeration) => self.define_enumeration(enumeration, span),
             AnalysisKind::Function(function) => self.define_function(function, span),
+            AnalysisKind::Closure(function, captures) => {
+                self.closure(function, captures, typing, span)
+            }
 
             AnalysisKind::Integer {
                 value,
@@ -614,6 +772,12 @@ impl<'backend> Inkwell<'backend> {
             AnalysisKind::Tuple(values) => self.tuple(values, span),
             AnalysisKind::Negate(value) => self.negate(value, span),
             AnalysisKind::SizeOf(typing) => self.size_of(typing, span),
+            AnalysisKind::Cast(value, typing) => self.cast(value, typing, span),
+            AnalysisKind::Wrap(value) => self.wrap(value, typing, span),
+            AnalysisKind::Nothing => self.nothing(typing, span),
+            AnalysisKind::Success(value) => self.success(value, typing, span),
+            AnalysisKind::Failure(error) => self.failure(error, typing, span),
+            AnalysisKind::Propagate(value) => self.propagate(value, span),
             AnalysisKind::Add(left, right) => self.add(left, right, span),
             AnalysisKind::Subtract(left, right) => self.subtract(left, right, span),
             AnalysisKind::Multiply(left, right) => self.multiply(left, right, span),
@@ -644,6 +808,13 @@ impl<'backend> Inkwell<'backend> {
             AnalysisKind::Slot(target, slot) => self.slot(target, slot, span),
             AnalysisKind::Constructor(structure) => self.constructor(typing, structure, span),
             AnalysisKind::Pack(target, values) => self.pack(typing, target, values, span),
+            AnalysisKind::Pattern(_) => Err(GenerateError::new(
+                ErrorKind::Variable(VariableError::InvalidAssignmentTarget),
+                span,
+            )),
+            AnalysisKind::Variant(target, index, payload) => {
+                self.variant(target, index, payload, span)
+            }
             AnalysisKind::Assign(target, value) => self.assign(target, value, span),
             AnalysisKind::Write(target, value) => self.write(target, value, span),
             AnalysisKind::Store(target, value) => self.store(target, value, span),
@@ -656,13 +827,20 @@ impl<'backend> Inkwell<'backend> {
                 span,
                 false,
             ),
-            AnalysisKind::While(condition, body) => self.r#while(condition, body, span),
+            AnalysisKind::While(condition, body, label) => {
+                self.r#while(condition, body, label, typing, span)
+            }
+            AnalysisKind::Match(scrutinee, arms) => self.r#match(scrutinee, arms, span),
             AnalysisKind::Module(name, analyses) => self.module(name, analyses, span),
             AnalysisKind::Invoke(invoke) => self.invoke(invoke, span),
             AnalysisKind::Call(target, values) => self.call(target, values, span),
+            AnalysisKind::Assembly(template, constraints, operands) => {
+                self.assembly(template, constraints, operands, typing, span)
+            }
             AnalysisKind::Return(value) => self.r#return(value, span),
-            AnalysisKind::Break(value) => self.r#break(value, span),
-            AnalysisKind::Continue(value) => self.r#continue(value, span),
+            AnalysisKind::Break(label, value) => self.r#break(label, value, span),
+            AnalysisKind::Continue(label, value) => self.r#continue(label, value, span),
+            AnalysisKind::Defer(body) => self.defer(*body, span),
             AnalysisKind::Composite(composite) => self.composite(composite, span),
         }
     }
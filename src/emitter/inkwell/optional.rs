@@ -0,0 +1,139 @@
+use {
+    crate::{
+        analyzer::Analysis,
+        emitter::{
+            inkwell::{GenerateError, Inkwell},
+            ErrorKind,
+        },
+        resolver::{Type, TypeKind},
+        tracker::Span,
+    },
+    inkwell::{types::BasicTypeEnum, values::BasicValueEnum},
+};
+
+impl<'backend> Inkwell<'backend> {
+    pub fn nullable(&self, typing: &Type<'backend>) -> bool {
+        self.value_type(typing)
+            .kind
+            .try_unwrap_optional()
+            .map_or(false, |member| {
+                matches!(
+                    self.value_type(member).kind,
+                    TypeKind::Pointer { .. } | TypeKind::String
+                )
+            })
+    }
+
+    pub fn wrap(
+        &mut self,
+        value: Box<Analysis<'backend>>,
+        typing: Type<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let value = self.analysis(*value)?;
+
+        if self.nullable(&typing) {
+            return Ok(value);
+        }
+
+        let BasicTypeEnum::StructType(layout) = self.to_basic_type(&typing, span)? else {
+            return Err(GenerateError::new(ErrorKind::InvalidType(typing), span));
+        };
+
+        let present = self
+            .builder
+            .build_insert_value(
+                layout.get_undef(),
+                self.context.bool_type().const_int(1, false),
+                0,
+                "present",
+            )
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?
+            .into_struct_value();
+
+        let wrapped = self
+            .builder
+            .build_insert_value(present, value, 1, "some")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?
+            .into_struct_value();
+
+        Ok(wrapped.into())
+    }
+
+    pub fn nothing(
+        &mut self,
+        typing: Type<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        Ok(self.to_basic_type(&typing, span)?.const_zero())
+    }
+
+    pub fn propagate(
+        &mut self,
+        value: Box<Analysis<'backend>>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let typing = value.typing.clone();
+
+        if self.value_type(&typing).kind.is_result() {
+            return self.unwind(value, span);
+        }
+
+        let nullable = self.nullable(&typing);
+        let optional = self.analysis(*value)?;
+
+        let (present, inner) = match optional {
+            BasicValueEnum::PointerValue(pointer) if nullable => {
+                let present =
+                    self.builder
+                        .build_is_not_null(pointer, "present")
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+
+                (present, optional)
+            }
+            BasicValueEnum::StructValue(shape) if !nullable => {
+                let present = self
+                    .builder
+                    .build_extract_value(shape, 0, "present")
+                    .map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?
+                    .into_int_value();
+
+                let inner =
+                    self.builder
+                        .build_extract_value(shape, 1, "some")
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+
+                (present, inner)
+            }
+            _ => return Err(GenerateError::new(ErrorKind::InvalidType(typing), span)),
+        };
+
+        let function = self.parent(span)?;
+        let some = self.context.append_basic_block(function, "some");
+        let none = self.context.append_basic_block(function, "none");
+
+        self.builder
+            .build_conditional_branch(present, some, none)
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder.position_at_end(none);
+
+        self.release(0)?;
+
+        match function.get_type().get_return_type() {
+            Some(layout) => self.builder.build_return(Some(&layout.const_zero())),
+            None => self.builder.build_return(None),
+        }
+        .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder.position_at_end(some);
+
+        Ok(inner)
+    }
+}
@@ -0,0 +1,147 @@
+use {
+    crate::{
+        analyzer::Analysis,
+        data::Str,
+        emitter::{
+            inkwell::{GenerateError, Inkwell},
+            ErrorKind, FunctionError,
+        },
+        resolver::Type,
+        tracker::Span,
+    },
+    inkwell::{
+        types::{BasicTypeEnum, StructType},
+        values::BasicValueEnum,
+        IntPredicate,
+    },
+};
+
+impl<'backend> Inkwell<'backend> {
+    fn outcome(
+        &self,
+        typing: &Type<'backend>,
+        span: Span,
+    ) -> Result<StructType<'backend>, GenerateError<'backend>> {
+        match self.to_basic_type(typing, span)? {
+            BasicTypeEnum::StructType(shape) => Ok(shape),
+            _ => Err(GenerateError::new(
+                ErrorKind::InvalidType(typing.clone()),
+                span,
+            )),
+        }
+    }
+
+    pub fn success(
+        &mut self,
+        value: Box<Analysis<'backend>>,
+        typing: Type<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let shape = self.outcome(&typing, span)?;
+        let value = self.analysis(*value)?;
+
+        self.tagged(shape, 0, value, span)
+    }
+
+    pub fn failure(
+        &mut self,
+        error: Box<Analysis<'backend>>,
+        typing: Type<'backend>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let shape = self.outcome(&typing, span)?;
+        let error = self.analysis(*error)?;
+
+        self.tagged(shape, 1, error, span)
+    }
+
+    pub fn unwind(
+        &mut self,
+        value: Box<Analysis<'backend>>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let typing = self.value_type(&value.typing);
+
+        let Some((success, failure)) = typing.kind.try_unwrap_result() else {
+            return Err(GenerateError::new(
+                ErrorKind::InvalidType(typing.clone()),
+                span,
+            ));
+        };
+
+        let success = self.to_basic_type(success, span)?;
+        let failure = self.to_basic_type(failure, span)?;
+        let shape = self.outcome(&typing, span)?;
+
+        let result = self.analysis(*value)?;
+        let function = self.parent(span)?;
+
+        let pointer = self.build_entry(function, shape.into(), Str::from("result"));
+
+        self.builder
+            .build_store(pointer, result)
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let tag = self
+            .builder
+            .build_struct_gep(shape, pointer, 0, "tag")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let slot = self
+            .builder
+            .build_struct_gep(shape, pointer, 1, "payload")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let tag = self
+            .builder
+            .build_load(self.context.i64_type(), tag, "tag")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?
+            .into_int_value();
+
+        let passed = self
+            .builder
+            .build_int_compare(
+                IntPredicate::EQ,
+                tag,
+                self.context.i64_type().const_zero(),
+                "passed",
+            )
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let pass = self.context.append_basic_block(function, "pass");
+        let fail = self.context.append_basic_block(function, "fail");
+
+        self.builder
+            .build_conditional_branch(passed, pass, fail)
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder.position_at_end(fail);
+
+        let error = self
+            .builder
+            .build_load(failure, slot, "error")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        let returned = match function.get_type().get_return_type() {
+            Some(BasicTypeEnum::StructType(output)) => self.tagged(output, 1, error, span)?,
+            _ => {
+                return Err(GenerateError::new(
+                    ErrorKind::Function(FunctionError::IncompatibleReturnType),
+                    span,
+                ))
+            }
+        };
+
+        self.release(0)?;
+
+        self.builder
+            .build_return(Some(&returned))
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+
+        self.builder.position_at_end(pass);
+
+        self.builder
+            .build_load(success, slot, "value")
+            .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))
+    }
+}
@@ -392,11 +392,10 @@ impl<'backend> Inkwell<'backend> {
     ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
         if let Some(entity) = self.get_entity(&identifier) {
             return match entity {
-                Entity::Function(function) => Ok(BasicValueEnum::from(
-                    self.linked(identifier, *function)
-                        .as_global_value()
-                        .as_pointer_value(),
-                )),
+                Entity::Function(function) => {
+                    let function = self.linked(identifier, *function);
+                    self.thunk(function, span)
+                }
                 Entity::Variable { pointer, typing } => {
                     let kind = self.to_basic_type(typing, span)?;
 
@@ -525,6 +524,133 @@ impl<'backend> Inkwell<'backend> {
         Ok(result)
     }
 
+    fn initializer(
+        &mut self,
+        expression: Analysis<'backend>,
+        global: bool,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let scope = if global {
+            let void = self.context.void_type();
+            let signature = void.fn_type(&[], false);
+            let function = self.current_module().add_function("init", signature, None);
+            let block = self.context.append_basic_block(function, "entry");
+
+            self.builder.position_at_end(block);
+            Some(function)
+        } else {
+            None
+        };
+
+        let result = self.analysis(expression);
+
+        if let Some(function) = scope {
+            self.builder.clear_insertion_position();
+            unsafe {
+                function.delete();
+            }
+        }
+
+        result
+    }
+
+    fn destructure(
+        &mut self,
+        binders: Vec<(Scale, Analysis<'backend>)>,
+        value: Option<Box<Analysis<'backend>>>,
+        kind: BindingKind,
+        span: Span,
+    ) -> Result<BasicValueEnum<'backend>, GenerateError<'backend>> {
+        let expression = value.ok_or_else(|| {
+            GenerateError::new(
+                ErrorKind::Variable(VariableError::BindingWithoutInitializer {
+                    name: String::from("pattern"),
+                }),
+                span,
+            )
+        })?;
+
+        let typing = expression.typing.clone();
+        let global = self.builder.get_insert_block().is_none();
+        let mut result = self.initializer(*expression, global)?;
+
+        if result.is_pointer_value() && !global {
+            let shape = self.to_basic_type(&typing, span)?;
+            let align = self.align(shape);
+
+            result = self
+                .builder
+                .build_load(shape, result.into_pointer_value(), "pattern")
+                .and_then(|inst| {
+                    if let Some(instruction) = inst.as_instruction_value() {
+                        instruction.set_alignment(align).ok();
+                    }
+                    Ok(inst)
+                })
+                .map_err(|error| GenerateError::new(ErrorKind::BuilderError(error.into()), span))?;
+        }
+
+        let BasicValueEnum::StructValue(aggregate) = result else {
+            return Err(GenerateError::new(ErrorKind::InvalidType(typing), span));
+        };
+
+        for (slot, binder) in binders {
+            let AnalysisKind::Symbol(target) = binder.kind else {
+                continue;
+            };
+
+            let field = if global {
+                aggregate.get_field_at_index(slot as u32).ok_or_else(|| {
+                    GenerateError::new(ErrorKind::InvalidType(typing.clone()), span)
+                })?
+            } else {
+                self.builder
+                    .build_extract_value(aggregate, slot as u32, &target.name)
+                    .map_err(|error| {
+                        GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                    })?
+            };
+
+            let declared = field.get_type();
+
+            let pointer = if global || matches!(kind, BindingKind::Static) {
+                let variable = self
+                    .current_module()
+                    .add_global(declared, None, &target.name);
+                variable.set_initializer(&field);
+                variable.set_alignment(self.align(declared));
+                variable.as_pointer_value()
+            } else {
+                let allocate =
+                    self.builder
+                        .build_alloca(declared, &target.name)
+                        .map_err(|error| {
+                            GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                        })?;
+
+                if let Some(instruction) = allocate.as_instruction_value() {
+                    instruction.set_alignment(self.align(declared)).ok();
+                }
+
+                let store = self.builder.build_store(allocate, field).map_err(|error| {
+                    GenerateError::new(ErrorKind::BuilderError(error.into()), span)
+                })?;
+                store.set_alignment(self.align(declared)).ok();
+
+                allocate
+            };
+
+            self.insert_entity(
+                target.name,
+                Entity::Variable {
+                    pointer,
+                    typing: binder.typing,
+                },
+            );
+        }
+
+        Ok(result)
+    }
+
     pub fn binding(
         &mut self,
         binding: Binding<Box<Analysis<'backend>>, Box<Analysis<'backend>>, Type<'backend>>,
@@ -533,8 +659,14 @@ impl<'backend> Inkwell<'backend> {
         let target = match binding.target.kind {
             AnalysisKind::Usage(name) => name,
             AnalysisKind::Symbol(target) => target.name,
+            AnalysisKind::Pattern(binders) => {
+                return self.destructure(binders, binding.value, binding.kind, span);
+            }
             _ => {
-                unimplemented!("destruction isn't implemented yet!");
+                return Err(GenerateError::new(
+                    ErrorKind::Variable(VariableError::InvalidAssignmentTarget),
+                    span,
+                ));
             }
         };
 
@@ -580,26 +712,7 @@ impl<'backend> Inkwell<'backend> {
                 let typing = binding.annotation.clone();
                 let global = self.builder.get_insert_block().is_none();
 
-                let scope = if global {
-                    let void = self.context.void_type();
-                    let signature = void.fn_type(&[], false);
-                    let function = self.current_module().add_function("init", signature, None);
-                    let block = self.context.append_basic_block(function, "entry");
-
-                    self.builder.position_at_end(block);
-                    Some(function)
-                } else {
-                    None
-                };
-
-                let result = self.analysis(*expression)?;
-
-                if let Some(function) = scope {
-                    self.builder.clear_insertion_position();
-                    unsafe {
-                        function.delete();
-                    }
-                }
+                let result = self.initializer(*expression, global)?;
 
                 let declared = result.get_type();
 
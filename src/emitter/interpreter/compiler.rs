@@ -2,10 +2,10 @@ use {
     crate::emitter::interpreter::error::InterpretError,
     crate::{
         analyzer::{Analysis, AnalysisKind},
-        data::{BindingKind, Str},
+        data::{Binding, BindingKind, Function, Str},
         emitter::{
-            interpreter::instruction::Instruction, DataStructureError, ErrorKind, FunctionError,
-            VariableError,
+            interpreter::instruction::{Arithmetic, Conversion, Instruction},
+            ControlFlowError, DataStructureError, ErrorKind, VariableError,
         },
         resolver::{Type, TypeKind},
     },
@@ -40,19 +40,29 @@ impl<'a> Chunk<'a> {
 }
 
 pub struct Compiler<'a> {
+    pub lifted: Vec<Function<Str<'a>, Analysis<'a>, Option<Box<Analysis<'a>>>, Option<Type<'a>>>>,
+    pub checked: bool,
     locals: Vec<Str<'a>>,
     depth: usize,
     loop_starts: Vec<usize>,
     loop_exits: Vec<Vec<usize>>,
+    loop_scopes: Vec<usize>,
+    loop_labels: Vec<Option<Str<'a>>>,
+    deferred: Vec<Vec<Analysis<'a>>>,
 }
 
 impl<'a> Compiler<'a> {
     pub fn new() -> Self {
         Self {
+            lifted: Vec::new(),
+            checked: false,
             locals: Vec::new(),
             depth: 0,
             loop_starts: Vec::new(),
             loop_exits: Vec::new(),
+            loop_scopes: Vec::new(),
+            loop_labels: Vec::new(),
+            deferred: Vec::new(),
         }
     }
 
@@ -60,6 +70,16 @@ impl<'a> Compiler<'a> {
         self.locals.iter().rposition(|n| n == name)
     }
 
+    fn find_loop(&self, label: Option<Str<'a>>) -> Option<usize> {
+        match label {
+            Some(name) => self
+                .loop_labels
+                .iter()
+                .rposition(|label| *label == Some(name)),
+            None => self.loop_labels.len().checked_sub(1),
+        }
+    }
+
     pub fn define_local(&mut self, name: Str<'a>) -> usize {
         self.locals.push(name);
         self.locals.len() - 1
@@ -74,6 +94,7 @@ impl<'a> Compiler<'a> {
             TypeKind::String | TypeKind::Pointer { .. } => 8,
             TypeKind::Array { member, size } => self.sizeof_type(member) * *size,
             TypeKind::Tuple { members } => members.iter().map(|m| self.sizeof_type(m)).sum(),
+            TypeKind::Newtype { member, .. } => self.sizeof_type(member),
             _ => 0,
         }
     }
@@ -85,10 +106,74 @@ impl<'a> Compiler<'a> {
                 .as_deref()
                 .or(binding.annotation.as_deref())
                 .unwrap_or(typing),
+            TypeKind::Newtype { member, .. } => self.value_type(member),
             _ => typing,
         }
     }
 
+    fn zero(&self, typing: &Type<'a>, chunk: &mut Chunk<'a>) {
+        match &self.value_type(typing).kind {
+            TypeKind::Integer { .. } => chunk.emit(Instruction::Integer(0)),
+            TypeKind::Float { .. } => chunk.emit(Instruction::Float(0.0)),
+            TypeKind::Boolean => chunk.emit(Instruction::Boolean(false)),
+            TypeKind::Character => chunk.emit(Instruction::Character('\0')),
+            TypeKind::String => chunk.emit(Instruction::String(Str::default())),
+            TypeKind::Pointer { .. } => {
+                chunk.emit(Instruction::Void);
+                chunk.emit(Instruction::AddressOf)
+            }
+            TypeKind::Array { member, size } => {
+                for _ in 0..*size {
+                    self.zero(member, chunk);
+                }
+                chunk.emit(Instruction::MakeArray(*size))
+            }
+            TypeKind::Tuple { members } => {
+                for member in members.iter() {
+                    self.zero(member, chunk);
+                }
+                chunk.emit(Instruction::MakeTuple(members.len()))
+            }
+            TypeKind::Structure(structure) => {
+                for member in &structure.members {
+                    self.zero(member, chunk);
+                }
+                chunk.emit(Instruction::MakeStruct(
+                    structure.target,
+                    structure.members.len(),
+                ))
+            }
+            _ => chunk.emit(Instruction::Void),
+        };
+    }
+
+    fn arithmetic(
+        &self,
+        wrapping: Instruction<'a>,
+        arithmetic: Arithmetic,
+        analysis: &Analysis<'a>,
+    ) -> Instruction<'a> {
+        match &self.value_type(&analysis.typing).kind {
+            TypeKind::Integer { size, signed } if self.checked => {
+                Instruction::Checked(arithmetic, *size, *signed, analysis.span)
+            }
+            _ => wrapping,
+        }
+    }
+
+    fn release(&mut self, depth: usize, chunk: &mut Chunk<'a>) -> Result<(), InterpretError<'a>> {
+        let pending = self.deferred.split_off(depth.min(self.deferred.len()));
+
+        for body in pending.iter().rev().flat_map(|scope| scope.iter().rev()) {
+            self.compile_one(body, chunk)?;
+            chunk.emit(Instruction::Pop);
+        }
+
+        self.deferred.extend(pending);
+
+        Ok(())
+    }
+
     pub fn compile(
         &mut self,
         analyses: &[Analysis<'a>],
@@ -126,7 +211,7 @@ impl<'a> Compiler<'a> {
 
             AnalysisKind::Negate(operand) => {
                 self.compile_one(operand, chunk)?;
-                chunk.emit(Instruction::Negate);
+                chunk.emit(self.arithmetic(Instruction::Negate, Arithmetic::Negate, analysis));
             }
             AnalysisKind::LogicalNot(operand) => {
                 self.compile_one(operand, chunk)?;
@@ -148,17 +233,17 @@ impl<'a> Compiler<'a> {
             AnalysisKind::Add(l, r) => {
                 self.compile_one(l, chunk)?;
                 self.compile_one(r, chunk)?;
-                chunk.emit(Instruction::Add);
+                chunk.emit(self.arithmetic(Instruction::Add, Arithmetic::Add, analysis));
             }
             AnalysisKind::Subtract(l, r) => {
                 self.compile_one(l, chunk)?;
                 self.compile_one(r, chunk)?;
-                chunk.emit(Instruction::Subtract);
+                chunk.emit(self.arithmetic(Instruction::Subtract, Arithmetic::Subtract, analysis));
             }
             AnalysisKind::Multiply(l, r) => {
                 self.compile_one(l, chunk)?;
                 self.compile_one(r, chunk)?;
-                chunk.emit(Instruction::Multiply);
+                chunk.emit(self.arithmetic(Instruction::Multiply, Arithmetic::Multiply, analysis));
             }
             AnalysisKind::Divide(l, r) => {
                 self.compile_one(l, chunk)?;
@@ -267,6 +352,73 @@ impl<'a> Compiler<'a> {
                 let size = self.sizeof_type(typing);
                 chunk.emit(Instruction::SizeOf(size));
             }
+            AnalysisKind::Cast(value, typing) => {
+                self.compile_one(value, chunk)?;
+
+                let conversion = match &self.value_type(typing).kind {
+                    TypeKind::Integer { size, signed } => Conversion::Integer {
+                        size: *size,
+                        signed: *signed,
+                    },
+                    TypeKind::Float { size } => Conversion::Float { size: *size },
+                    TypeKind::Boolean => Conversion::Boolean,
+                    TypeKind::Character => Conversion::Character,
+                    TypeKind::Pointer { .. } => Conversion::Pointer,
+                    _ => return Err(InterpretError::new(ErrorKind::Cast, span)),
+                };
+
+                chunk.emit(Instruction::Cast(conversion, span));
+            }
+            AnalysisKind::Wrap(value) => {
+                self.compile_one(value, chunk)?;
+            }
+            AnalysisKind::Nothing => {
+                chunk.emit(Instruction::Void);
+            }
+            AnalysisKind::Success(value) | AnalysisKind::Failure(value) => {
+                let tag = if matches!(analysis.kind, AnalysisKind::Success(_)) {
+                    0
+                } else {
+                    1
+                };
+
+                chunk.emit(Instruction::Integer(tag));
+                self.compile_one(value, chunk)?;
+                chunk.emit(Instruction::MakeStruct(Str::from("Result"), 2));
+            }
+            AnalysisKind::Propagate(value) if self.value_type(&value.typing).kind.is_result() => {
+                self.compile_one(value, chunk)?;
+                chunk.emit(Instruction::Dup);
+                chunk.emit(Instruction::Tag);
+                chunk.emit(Instruction::Integer(0));
+                chunk.emit(Instruction::Equal);
+                let passed = chunk.emit(Instruction::JumpIf(0));
+
+                chunk.emit(Instruction::Pop);
+                self.release(0, chunk)?;
+                chunk.emit(Instruction::ReturnSignal);
+
+                let end = chunk.here();
+                chunk.patch_jump(passed, end);
+                chunk.emit(Instruction::Pop);
+                chunk.emit(Instruction::GetField(1));
+            }
+            AnalysisKind::Propagate(value) => {
+                self.compile_one(value, chunk)?;
+                chunk.emit(Instruction::Dup);
+                chunk.emit(Instruction::Present);
+                let present = chunk.emit(Instruction::JumpIf(0));
+
+                chunk.emit(Instruction::Pop);
+                chunk.emit(Instruction::Pop);
+                chunk.emit(Instruction::Void);
+                self.release(0, chunk)?;
+                chunk.emit(Instruction::ReturnSignal);
+
+                let end = chunk.here();
+                chunk.patch_jump(present, end);
+                chunk.emit(Instruction::Pop);
+            }
 
             AnalysisKind::Usage(_) | AnalysisKind::Symbol(_) => {
                 let name = match &analysis.kind {
@@ -306,6 +458,39 @@ impl<'a> Compiler<'a> {
                 let name = match &binding.target.kind {
                     AnalysisKind::Usage(n) => *n,
                     AnalysisKind::Symbol(t) => t.name,
+                    AnalysisKind::Pattern(binders) => {
+                        let Some(expr) = &binding.value else {
+                            return Err(InterpretError::new(
+                                ErrorKind::Variable(VariableError::BindingWithoutInitializer {
+                                    name: String::from("pattern"),
+                                }),
+                                span,
+                            ));
+                        };
+
+                        self.compile_one(expr, chunk)?;
+                        let slot = self.define_local(Str::from("pattern"));
+                        chunk.emit(Instruction::Store(slot));
+
+                        for (position, binder) in binders {
+                            let AnalysisKind::Symbol(target) = &binder.kind else {
+                                continue;
+                            };
+
+                            chunk.emit(Instruction::Load(slot));
+                            chunk.emit(Instruction::GetField(*position as usize));
+
+                            if matches!(binding.kind, BindingKind::Static) || self.depth == 0 {
+                                chunk.emit(Instruction::DefineGlobal(target.name));
+                            } else {
+                                let local = self.define_local(target.name);
+                                chunk.emit(Instruction::Store(local));
+                            }
+                        }
+
+                        chunk.emit(Instruction::Load(slot));
+                        return Ok(());
+                    }
                     _ => {
                         chunk.emit(Instruction::Void);
                         return Ok(());
@@ -338,12 +523,18 @@ impl<'a> Compiler<'a> {
                 let base = self.locals.len();
                 chunk.emit(Instruction::EnterBlock);
 
+                self.deferred.push(Vec::new());
+                let scope = self.deferred.len() - 1;
+
                 let mut last_void = true;
                 for inner in analyses {
                     self.compile_one(inner, chunk)?;
                     last_void = false;
                 }
 
+                self.release(scope, chunk)?;
+                self.deferred.truncate(scope);
+
                 self.locals.truncate(base);
                 self.depth -= 1;
 
@@ -374,10 +565,12 @@ impl<'a> Compiler<'a> {
                 chunk.patch_jump(to_end, end);
             }
 
-            AnalysisKind::While(condition, body) => {
+            AnalysisKind::While(condition, body, label) => {
                 let loop_start = chunk.here();
                 self.loop_starts.push(loop_start);
                 self.loop_exits.push(Vec::new());
+                self.loop_scopes.push(self.deferred.len());
+                self.loop_labels.push(*label);
 
                 self.compile_one(condition, chunk)?;
                 let exit_jump = chunk.emit(Instruction::JumpIfNot(0));
@@ -386,16 +579,84 @@ impl<'a> Compiler<'a> {
                 chunk.emit(Instruction::Pop);
                 chunk.emit(Instruction::Jump(loop_start));
 
-                let exit = chunk.here();
-                chunk.patch_jump(exit_jump, exit);
+                let completed = chunk.here();
+                chunk.patch_jump(exit_jump, completed);
 
+                match self.value_type(&analysis.typing).kind {
+                    TypeKind::Void | TypeKind::Unknown => {
+                        chunk.emit(Instruction::Integer(0));
+                    }
+                    _ => self.zero(&analysis.typing, chunk),
+                }
+
+                let exit = chunk.here();
                 let exits = self.loop_exits.pop().unwrap_or_default();
                 for at in exits {
                     chunk.patch_jump(at, exit);
                 }
                 self.loop_starts.pop();
+                self.loop_scopes.pop();
+                self.loop_labels.pop();
+            }
 
-                chunk.emit(Instruction::Integer(0));
+            AnalysisKind::Match(scrutinee, arms) => {
+                let base = self.locals.len();
+
+                self.compile_one(scrutinee, chunk)?;
+                let slot = self.define_local(Str::from("match"));
+                chunk.emit(Instruction::Store(slot));
+
+                let mut exits = Vec::with_capacity(arms.len());
+
+                for (index, binders, body) in arms {
+                    let scope = self.locals.len();
+
+                    let next = match index {
+                        Some(index) => {
+                            chunk.emit(Instruction::Load(slot));
+                            chunk.emit(Instruction::Tag);
+                            chunk.emit(Instruction::Integer(*index as i64));
+                            chunk.emit(Instruction::Equal);
+                            let next = chunk.emit(Instruction::JumpIfNot(0));
+                            chunk.emit(Instruction::Pop);
+                            Some(next)
+                        }
+                        None => None,
+                    };
+
+                    if index.is_some() {
+                        for (position, binder) in binders.iter().enumerate() {
+                            if binder.is_empty() || binder.as_str() == Some("_") {
+                                continue;
+                            }
+
+                            chunk.emit(Instruction::Load(slot));
+                            chunk.emit(Instruction::GetField(1));
+                            chunk.emit(Instruction::GetField(position));
+                            let local = self.define_local(*binder);
+                            chunk.emit(Instruction::Store(local));
+                        }
+                    }
+
+                    self.compile_one(body, chunk)?;
+                    self.locals.truncate(scope);
+                    exits.push(chunk.emit(Instruction::Jump(0)));
+
+                    if let Some(next) = next {
+                        let here = chunk.here();
+                        chunk.patch_jump(next, here);
+                        chunk.emit(Instruction::Pop);
+                    }
+                }
+
+                chunk.emit(Instruction::Unmatched(span));
+
+                let end = chunk.here();
+                for at in exits {
+                    chunk.patch_jump(at, end);
+                }
+
+                self.locals.truncate(base);
             }
 
             AnalysisKind::Return(value) => {
@@ -404,29 +665,56 @@ impl<'a> Compiler<'a> {
                 } else {
                     chunk.emit(Instruction::Void);
                 }
+                self.release(0, chunk)?;
                 chunk.emit(Instruction::ReturnSignal);
             }
-            AnalysisKind::Break(value) => {
+            AnalysisKind::Break(label, value) => {
+                let Some(index) = self.find_loop(*label) else {
+                    return Err(InterpretError::new(
+                        ErrorKind::ControlFlow(ControlFlowError::BreakOutsideLoop),
+                        span,
+                    ));
+                };
+
                 if let Some(v) = value {
                     self.compile_one(v, chunk)?;
                 } else {
-                    chunk.emit(Instruction::Void);
+                    chunk.emit(Instruction::Integer(0));
                 }
-                chunk.emit(Instruction::BreakSignal);
+                self.release(self.loop_scopes[index], chunk)?;
                 let at = chunk.emit(Instruction::Jump(0));
-                if let Some(exits) = self.loop_exits.last_mut() {
-                    exits.push(at);
-                }
+                self.loop_exits[index].push(at);
             }
-            AnalysisKind::Continue(value) => {
+            AnalysisKind::Continue(label, value) => {
+                let Some(index) = self.find_loop(*label) else {
+                    return Err(InterpretError::new(
+                        ErrorKind::ControlFlow(ControlFlowError::ContinueOutsideLoop),
+                        span,
+                    ));
+                };
+
                 if let Some(v) = value {
                     self.compile_one(v, chunk)?;
-                } else {
-                    chunk.emit(Instruction::Void);
+                    chunk.emit(Instruction::Pop);
                 }
-                chunk.emit(Instruction::ContinueSignal);
-                let top = self.loop_starts.last().copied().unwrap_or(0);
-                chunk.emit(Instruction::Jump(top));
+                self.release(self.loop_scopes[index], chunk)?;
+                chunk.emit(Instruction::Jump(self.loop_starts[index]));
+            }
+
+            AnalysisKind::Defer(body) => {
+                let Some(scope) = self.deferred.last_mut() else {
+                    return Err(InterpretError::new(
+                        ErrorKind::ControlFlow(ControlFlowError::DeferOutsideBlock),
+                        span,
+                    ));
+                };
+
+                scope.push(*body.clone());
+                chunk.emit(Instruction::Void);
+            }
+
+            AnalysisKind::Assembly(..) => {
+                return Err(InterpretError::new(ErrorKind::Assembly, span));
             }
 
             AnalysisKind::Call(target, args) => {
@@ -436,28 +724,44 @@ impl<'a> Compiler<'a> {
                 chunk.emit(Instruction::Call(target.name, args.len()));
             }
             AnalysisKind::Invoke(invoke) => {
-                let name = match &invoke.target.typing.kind {
-                    TypeKind::Function(f) if !f.target.is_empty() => f.target,
-                    _ => {
-                        return Err(InterpretError::new(
-                            ErrorKind::Function(FunctionError::Undefined {
-                                name: String::from("unknown"),
-                            }),
-                            span,
-                        ))
-                    }
-                };
+                self.compile_one(&invoke.target, chunk)?;
                 for arg in &invoke.members {
                     self.compile_one(arg, chunk)?;
                 }
-                chunk.emit(Instruction::Call(name, invoke.members.len()));
+                chunk.emit(Instruction::Invoke(invoke.members.len()));
             }
 
             AnalysisKind::Function(function) => {
                 chunk.emit(Instruction::DefineGlobal(function.target));
                 chunk.emit(Instruction::Void);
             }
-            AnalysisKind::Structure(_) | AnalysisKind::Union(_) => {
+            AnalysisKind::Closure(function, captures) => {
+                for capture in captures {
+                    self.compile_one(capture, chunk)?;
+                }
+
+                let mut lifted = function.clone();
+                lifted.members = captures
+                    .iter()
+                    .map(|capture| {
+                        Analysis::new(
+                            AnalysisKind::Binding(Binding::new(
+                                Box::new(capture.clone()),
+                                None,
+                                capture.typing.clone(),
+                                BindingKind::Let,
+                            )),
+                            capture.span,
+                            capture.typing.clone(),
+                        )
+                    })
+                    .chain(function.members.iter().cloned())
+                    .collect();
+                self.lifted.push(lifted);
+
+                chunk.emit(Instruction::MakeClosure(function.target, captures.len()));
+            }
+            AnalysisKind::Structure(_) | AnalysisKind::Union(_) | AnalysisKind::Enumeration(_) => {
                 chunk.emit(Instruction::Void);
             }
             AnalysisKind::Module(_, inner) => {
@@ -485,6 +789,16 @@ impl<'a> Compiler<'a> {
                 }
                 chunk.emit(Instruction::MakeStruct(target.name, count));
             }
+            AnalysisKind::Variant(target, index, payload) => {
+                let name = match &self.value_type(&payload.typing).kind {
+                    TypeKind::Structure(structure) => structure.target,
+                    _ => target.name,
+                };
+                chunk.emit(Instruction::Integer(*index as i64));
+                self.compile_one(payload, chunk)?;
+                chunk.emit(Instruction::MakeUnion(name));
+                chunk.emit(Instruction::MakeStruct(target.name, 2));
+            }
             AnalysisKind::Composite(composite) => {
                 let name = composite.target.name;
                 let count = composite.members.len();
@@ -131,6 +131,80 @@ impl<'a> Engine<'a> {
                 Value::Void
             }),
         );
+
+        self.machine.register(
+            Str::from("integer_string"),
+            Foreign::native(|args| match args.first() {
+                Some(Value::Integer(n)) => Value::String(Str::from(n.to_string())),
+                _ => Value::Void,
+            }),
+        );
+
+        self.machine.register(
+            Str::from("float_string"),
+            Foreign::native(|args| match args.first() {
+                Some(Value::Float(f)) => Value::String(Str::from(format!("{:.6}", f))),
+                _ => Value::Void,
+            }),
+        );
+
+        self.machine.register(
+            Str::from("boolean_string"),
+            Foreign::native(|args| match args.first() {
+                Some(Value::Boolean(b)) => Value::String(Str::from(b.to_string())),
+                _ => Value::Void,
+            }),
+        );
+
+        self.machine.register(
+            Str::from("character_string"),
+            Foreign::native(|args| match args.first() {
+                Some(Value::Character(c)) => Value::String(Str::from(c.to_string())),
+                _ => Value::Void,
+            }),
+        );
+
+        self.machine.register(
+            Str::from("string_concatenate"),
+            Foreign::native(|args| match (args.first(), args.get(1)) {
+                (Some(Value::String(left)), Some(Value::String(right))) => {
+                    Value::String(Str::from(format!("{}{}", left, right)))
+                }
+                _ => Value::Void,
+            }),
+        );
+
+        self.machine.register(
+            Str::from("format_start"),
+            Foreign::native(|_| Value::String(Str::from(String::new()))),
+        );
+
+        for name in [
+            "format_string",
+            "format_integer",
+            "format_float",
+            "format_boolean",
+            "format_character",
+        ] {
+            self.machine.register(
+                Str::from(name),
+                Foreign::native(|args| match (args.first(), args.get(1)) {
+                    (Some(Value::String(buffer)), Some(value)) => {
+                        let text = match value {
+                            Value::String(text) => text.to_string(),
+                            Value::Integer(n) => n.to_string(),
+                            Value::Float(f) => format!("{:.6}", f),
+                            Value::Boolean(b) => b.to_string(),
+                            Value::Character(c) => c.to_string(),
+                            _ => return Value::Void,
+                        };
+
+                        Value::String(Str::from(format!("{}{}", buffer, text)))
+                    }
+                    _ => Value::Void,
+                }),
+            );
+        }
     }
 
     pub fn register(&mut self, name: Str<'a>, foreign: Foreign<'a>) {
@@ -144,8 +218,10 @@ impl<'a> Engine<'a> {
         self.machine.load(&analyses)?;
 
         let mut compiler = Compiler::new();
+        compiler.checked = self.machine.checked;
         let mut chunk = Chunk::new();
         compiler.compile(&analyses, &mut chunk)?;
+        self.machine.lift(&mut compiler);
 
         self.machine.run(&chunk)
     }
@@ -162,8 +238,10 @@ impl<'a> Engine<'a> {
             .collect();
 
         let mut compiler = Compiler::new();
+        compiler.checked = self.machine.checked;
         let mut chunk = Chunk::new();
         compiler.compile(&non_functions, &mut chunk)?;
+        self.machine.lift(&mut compiler);
 
         self.machine.run(&chunk)
     }
@@ -1,4 +1,4 @@
-use crate::data::Str;
+use crate::{data::Str, tracker::Span};
 
 #[derive(Clone, Debug)]
 pub enum Instruction<'a> {
@@ -35,6 +35,7 @@ pub enum Instruction<'a> {
     BitwiseXor,
     ShiftLeft,
     ShiftRight,
+    Checked(Arithmetic, usize, bool, Span),
 
     Equal,
     NotEqual,
@@ -50,6 +51,8 @@ pub enum Instruction<'a> {
     MakeTuple(usize),
     MakeStruct(Str<'a>, usize),
     MakeUnion(Str<'a>),
+    MakeClosure(Str<'a>, usize),
+    Tag,
 
     GetField(usize),
     GetIndex,
@@ -58,12 +61,16 @@ pub enum Instruction<'a> {
     Jump(usize),
     JumpIf(usize),
     JumpIfNot(usize),
+    Present,
+    Unmatched(Span),
 
     Call(Str<'a>, usize),
     CallForeign(Str<'a>, usize),
+    Invoke(usize),
     Return,
 
     SizeOf(usize),
+    Cast(Conversion, Span),
 
     EnterBlock,
     LeaveBlock,
@@ -72,3 +79,20 @@ pub enum Instruction<'a> {
     ContinueSignal,
     ReturnSignal,
 }
+
+#[derive(Clone, Copy, Debug)]
+pub enum Arithmetic {
+    Negate,
+    Add,
+    Subtract,
+    Multiply,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Conversion {
+    Integer { size: usize, signed: bool },
+    Float { size: usize },
+    Boolean,
+    Character,
+    Pointer,
+}
@@ -5,11 +5,12 @@ use crate::{
         interpreter::{
             compiler::{Chunk, Compiler},
             error::InterpretError,
-            instruction::Instruction,
+            instruction::{Arithmetic, Conversion, Instruction},
             value::Value,
             Foreign,
         },
-        BitwiseError, DataStructureError, ErrorKind, FunctionError, VariableError,
+        BitwiseError, ControlFlowError, DataStructureError, ErrorKind, FunctionError,
+        VariableError,
     },
     internal::hash::Map,
     resolver::Type,
@@ -31,6 +32,7 @@ pub struct Machine<'a> {
     foreigns: Map<Str<'a>, Foreign<'a>>,
     frames: Vec<usize>,
     signal: Option<Signal<'a>>,
+    pub checked: bool,
 }
 
 impl<'a> Machine<'a> {
@@ -42,6 +44,7 @@ impl<'a> Machine<'a> {
             foreigns: Map::default(),
             frames: Vec::new(),
             signal: None,
+            checked: false,
         }
     }
 
@@ -442,6 +445,15 @@ impl<'a> Machine<'a> {
                     let value = self.stack.pop().unwrap_or(Value::Void);
                     self.stack.push(Value::Union(name, Box::new(value)));
                 }
+                Instruction::MakeClosure(name, count) => {
+                    let start = self.stack.len().saturating_sub(count);
+                    let captured: Vec<Value<'a>> = self.stack.drain(start..).collect();
+                    self.stack.push(Value::Closure(name, captured));
+                }
+                Instruction::Tag => {
+                    let value = self.stack.pop().unwrap_or(Value::Void);
+                    self.stack.push(value.tag());
+                }
 
                 Instruction::GetField(index) => {
                     let value = self.stack.pop().unwrap_or(Value::Void);
@@ -453,6 +465,12 @@ impl<'a> Machine<'a> {
                             fields.into_iter().nth(index).unwrap_or(Value::Void)
                         }
                         Value::Array(items) => items.into_iter().nth(index).unwrap_or(Value::Void),
+                        Value::Union(_, value) | Value::Pointer(value) => match *value {
+                            Value::Structure(_, fields) | Value::Tuple(fields) => {
+                                fields.into_iter().nth(index).unwrap_or(Value::Void)
+                            }
+                            _ => Value::Void,
+                        },
                         _ => Value::Void,
                     };
                     self.stack.push(result);
@@ -495,6 +513,15 @@ impl<'a> Machine<'a> {
                 Instruction::SizeOf(size) => {
                     self.stack.push(Value::Integer(size as i64));
                 }
+                Instruction::Cast(conversion, span) => {
+                    let value = self.stack.pop().unwrap_or(Value::Void);
+                    let result = self.convert(value, conversion, span)?;
+                    self.stack.push(result);
+                }
+                Instruction::Checked(arithmetic, size, signed, span) => {
+                    let result = self.overflow(arithmetic, size, signed, span)?;
+                    self.stack.push(result);
+                }
 
                 Instruction::Jump(dest) => {
                     ip = dest;
@@ -511,6 +538,13 @@ impl<'a> Machine<'a> {
                         ip = dest;
                     }
                 }
+                Instruction::Present => {
+                    let top = self.stack.last().cloned().unwrap_or(Value::Void);
+                    self.stack.push(Value::Boolean(top.is_present()));
+                }
+                Instruction::Unmatched(span) => {
+                    return Err(self.err(ErrorKind::ControlFlow(ControlFlowError::Unmatched), span));
+                }
 
                 Instruction::ReturnSignal => {
                     let v = self.stack.pop().unwrap_or(Value::Void);
@@ -553,6 +587,11 @@ impl<'a> Machine<'a> {
                     }
                 }
 
+                Instruction::Invoke(arity) => {
+                    let result = self.invoke(arity)?;
+                    self.stack.push(result);
+                }
+
                 Instruction::CallForeign(name, arity) => {
                     let start = self.stack.len().saturating_sub(arity);
                     let args: Vec<Value<'a>> = self.stack.drain(start..).collect();
@@ -584,6 +623,46 @@ impl<'a> Machine<'a> {
         Ok(result)
     }
 
+    pub fn lift(&mut self, compiler: &mut Compiler<'a>) {
+        for function in compiler.lifted.drain(..) {
+            self.functions.insert(function.target, function);
+        }
+    }
+
+    fn invoke(&mut self, arity: usize) -> Result<Value<'a>, InterpretError<'a>> {
+        let start = self.stack.len().saturating_sub(arity);
+        let mut args: Vec<Value<'a>> = self.stack.drain(start..).collect();
+
+        let (name, captured) = match self.stack.pop().unwrap_or(Value::Void) {
+            Value::Function(name) => (name, Vec::new()),
+            Value::Closure(name, captured) => (name, captured),
+            _ => {
+                return Err(self.err(
+                    ErrorKind::Function(FunctionError::Undefined {
+                        name: String::from("unknown"),
+                    }),
+                    Span::void(),
+                ))
+            }
+        };
+
+        if let Some(foreign) = self.foreigns.get(&name).cloned() {
+            return Ok(foreign.call(&args));
+        }
+
+        let Some(function) = self.functions.get(&name).cloned() else {
+            return Err(self.err(
+                ErrorKind::Function(FunctionError::Undefined {
+                    name: name.to_string(),
+                }),
+                Span::void(),
+            ));
+        };
+
+        args.splice(0..0, captured);
+        self.call_function(&function, args)
+    }
+
     fn call_function(
         &mut self,
         function: &AxoFn<'a>,
@@ -597,6 +676,7 @@ impl<'a> Machine<'a> {
         }
 
         let mut compiler = Compiler::new();
+        compiler.checked = self.checked;
 
         for param in &function.members {
             if let AnalysisKind::Binding(binding) = &param.kind {
@@ -612,6 +692,7 @@ impl<'a> Machine<'a> {
         let result = if let Some(body) = &function.body {
             let mut chunk = Chunk::new();
             compiler.compile_one(body, &mut chunk)?;
+            self.lift(&mut compiler);
             self.run_frame(&chunk, frame_base)?
         } else {
             Value::Void
@@ -715,6 +796,10 @@ impl<'a> Machine<'a> {
                         ));
                     }
                 }
+                Instruction::Invoke(arity) => {
+                    let result = self.invoke(*arity)?;
+                    self.stack.push(result);
+                }
                 other => {
                     self.dispatch(other.clone())?;
                 }
@@ -752,7 +837,23 @@ impl<'a> Machine<'a> {
                 self.globals.insert(name, value);
             }
             Instruction::EnterBlock | Instruction::LeaveBlock => {}
+            Instruction::Present => {
+                let top = self.stack.last().cloned().unwrap_or(Value::Void);
+                self.stack.push(Value::Boolean(top.is_present()));
+            }
+            Instruction::Unmatched(span) => {
+                return Err(self.err(ErrorKind::ControlFlow(ControlFlowError::Unmatched), span));
+            }
             Instruction::SizeOf(size) => self.stack.push(Value::Integer(size as i64)),
+            Instruction::Cast(conversion, span) => {
+                let value = self.stack.pop().unwrap_or(Value::Void);
+                let result = self.convert(value, conversion, span)?;
+                self.stack.push(result);
+            }
+            Instruction::Checked(arithmetic, size, signed, span) => {
+                let result = self.overflow(arithmetic, size, signed, span)?;
+                self.stack.push(result);
+            }
             Instruction::MakeArray(count) => {
                 let start = self.stack.len().saturating_sub(count);
                 let items: Vec<Value<'a>> = self.stack.drain(start..).collect();
@@ -772,6 +873,15 @@ impl<'a> Machine<'a> {
                 let value = self.stack.pop().unwrap_or(Value::Void);
                 self.stack.push(Value::Union(name, Box::new(value)));
             }
+            Instruction::MakeClosure(name, count) => {
+                let start = self.stack.len().saturating_sub(count);
+                let captured: Vec<Value<'a>> = self.stack.drain(start..).collect();
+                self.stack.push(Value::Closure(name, captured));
+            }
+            Instruction::Tag => {
+                let value = self.stack.pop().unwrap_or(Value::Void);
+                self.stack.push(value.tag());
+            }
             Instruction::GetField(index) => {
                 let value = self.stack.pop().unwrap_or(Value::Void);
                 let result = match value {
@@ -780,6 +890,12 @@ impl<'a> Machine<'a> {
                     }
                     Value::Tuple(fields) => fields.into_iter().nth(index).unwrap_or(Value::Void),
                     Value::Array(items) => items.into_iter().nth(index).unwrap_or(Value::Void),
+                    Value::Union(_, value) | Value::Pointer(value) => match *value {
+                        Value::Structure(_, fields) | Value::Tuple(fields) => {
+                            fields.into_iter().nth(index).unwrap_or(Value::Void)
+                        }
+                        _ => Value::Void,
+                    },
                     _ => Value::Void,
                 };
                 self.stack.push(result);
@@ -1130,6 +1246,115 @@ impl<'a> Machine<'a> {
         Ok(())
     }
 
+    fn convert(
+        &self,
+        value: Value<'a>,
+        conversion: Conversion,
+        span: Span,
+    ) -> Result<Value<'a>, InterpretError<'a>> {
+        let result = match (value, conversion) {
+            (Value::Integer(n), Conversion::Integer { size, signed }) => {
+                Value::Integer(Self::truncate(n, size, signed))
+            }
+            (Value::Float(f), Conversion::Integer { size, signed }) => {
+                let n = if signed { f as i64 } else { f as u64 as i64 };
+                Value::Integer(Self::truncate(n, size, signed))
+            }
+            (Value::Boolean(b), Conversion::Integer { .. }) => Value::Integer(b as i64),
+            (Value::Character(c), Conversion::Integer { size, signed }) => {
+                Value::Integer(Self::truncate(c as i64, size, signed))
+            }
+            (Value::Pointer(_), Conversion::Integer { .. }) => {
+                return Err(self.err(ErrorKind::Address, span))
+            }
+            (Value::Integer(n), Conversion::Float { size }) => {
+                Value::Float(Self::narrow(n as f64, size))
+            }
+            (Value::Float(f), Conversion::Float { size }) => Value::Float(Self::narrow(f, size)),
+            (Value::Integer(n), Conversion::Character) => match char::from_u32(n as u32) {
+                Some(c) => Value::Character(c),
+                None => return Err(self.err(ErrorKind::Cast, span)),
+            },
+            (Value::Character(c), Conversion::Character) => Value::Character(c),
+            (Value::Boolean(b), Conversion::Boolean) => Value::Boolean(b),
+            (Value::Integer(n), Conversion::Boolean) => Value::Boolean(n != 0),
+            (Value::Pointer(p), Conversion::Pointer) => Value::Pointer(p),
+            (Value::Integer(_), Conversion::Pointer) => {
+                return Err(self.err(ErrorKind::Address, span))
+            }
+            _ => return Err(self.err(ErrorKind::Cast, span)),
+        };
+
+        Ok(result)
+    }
+
+    fn truncate(value: i64, size: usize, signed: bool) -> i64 {
+        if size == 0 || size >= 64 {
+            return value;
+        }
+
+        let bits = value & ((1i64 << size) - 1);
+
+        if signed && (bits >> (size - 1)) & 1 == 1 {
+            bits - (1i64 << size)
+        } else {
+            bits
+        }
+    }
+
+    fn narrow(value: f64, size: usize) -> f64 {
+        if size == 32 {
+            value as f32 as f64
+        } else {
+            value
+        }
+    }
+
+    fn overflow(
+        &mut self,
+        arithmetic: Arithmetic,
+        size: usize,
+        signed: bool,
+        span: Span,
+    ) -> Result<Value<'a>, InterpretError<'a>> {
+        let size = if size == 0 || size > 64 { 64 } else { size };
+
+        let widen = |value: i64| -> i128 {
+            if signed {
+                value as i128
+            } else {
+                value as u64 as i128 & ((1i128 << size) - 1)
+            }
+        };
+
+        let (minimum, maximum) = if signed {
+            (-(1i128 << (size - 1)), (1i128 << (size - 1)) - 1)
+        } else {
+            (0, (1i128 << size) - 1)
+        };
+
+        let right = widen(self.integer(span)?);
+
+        let result = match arithmetic {
+            Arithmetic::Negate => right.checked_neg(),
+            Arithmetic::Add => widen(self.integer(span)?).checked_add(right),
+            Arithmetic::Subtract => widen(self.integer(span)?).checked_sub(right),
+            Arithmetic::Multiply => widen(self.integer(span)?).checked_mul(right),
+        };
+
+        result
+            .filter(|result| (minimum..=maximum).contains(result))
+            .map(|result| Value::Integer(result as i64))
+            .ok_or_else(|| self.err(ErrorKind::Overflow, span))
+    }
+
+    fn integer(&mut self, span: Span) -> Result<i64, InterpretError<'a>> {
+        match self.stack.pop().unwrap_or(Value::Void) {
+            Value::Integer(value) => Ok(value),
+            _ => Err(self.err(ErrorKind::Normalize, span)),
+        }
+    }
+
     fn err(&self, kind: ErrorKind<'a>, span: Span) -> InterpretError<'a> {
         InterpretError::new(kind, span)
     }
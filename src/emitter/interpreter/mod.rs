@@ -10,3 +10,465 @@ pub use engine::Engine;
 pub use error::InterpretError;
 pub use foreign::Foreign;
 pub use value::Value;
+
+#[cfg(test)]
+mod tests {
+    use super::{Engine, Foreign, InterpretError, Value};
+    use crate::{
+        analyzer::Analyzer,
+        data::{Identity, Str},
+        emitter::ErrorKind,
+        internal::{Artifact, Record, RecordKind, Session, SessionError},
+        parser::Parser,
+        resolver::{self, Resolver},
+        scanner::Scanner,
+        tracker::Location,
+    };
+
+    fn analyze(source: &str) -> (Session<'static>, Identity) {
+        let mut session = Session::new();
+
+        let identity = session.records.len() | 0x40000000;
+        let mut record = Record::new(RecordKind::Source, Location::from("test"));
+        record.set_content(Str::from(source.to_string()));
+        session.records.insert(identity, record);
+
+        let mut keys: Vec<_> = session
+            .records
+            .iter()
+            .filter_map(|(&key, record)| (record.kind == RecordKind::Source).then_some(key))
+            .collect();
+        keys.sort();
+
+        assert!(session.prepare());
+        Scanner::execute(&mut session, &keys);
+        Parser::execute(&mut session, &keys);
+        Resolver::execute(&mut session, &keys);
+        Analyzer::execute(&mut session, &keys);
+
+        (session, identity)
+    }
+
+    fn interpret(
+        source: &str,
+        engine: &mut Engine<'static>,
+    ) -> Result<Value<'static>, InterpretError<'static>> {
+        let (session, identity) = analyze(source);
+        assert!(
+            session.errors.is_empty(),
+            "expected no error, got {}",
+            session.errors.len()
+        );
+
+        for key in session.all_source_keys() {
+            if key == identity {
+                continue;
+            }
+
+            if let Some(Artifact::Analyses(analyses)) = session.records[&key].fetch(3) {
+                let _ = engine.execute(analyses.clone());
+            }
+        }
+
+        let Some(Artifact::Analyses(analyses)) = session.records[&identity].fetch(3) else {
+            panic!("expected analyses");
+        };
+
+        engine.process(analyses.clone())
+    }
+
+    fn execute(source: &str, checked: bool) -> Result<Value<'static>, InterpretError<'static>> {
+        let mut engine = Engine::new();
+        engine.machine.checked = checked;
+        interpret(source, &mut engine)
+    }
+
+    fn run(source: &str) -> Value<'static> {
+        execute(source, false).unwrap()
+    }
+
+    fn reject(source: &str) -> Vec<resolver::ErrorKind<'static>> {
+        analyze(source)
+            .0
+            .errors
+            .into_iter()
+            .filter_map(|error| match error {
+                SessionError::Resolve(error) => Some(error.kind),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn fail(source: &str, checked: bool) -> ErrorKind<'static> {
+        match execute(source, checked) {
+            Ok(value) => panic!("expected a failure, got {:?}", value),
+            Err(error) => error.kind,
+        }
+    }
+
+    #[test]
+    fn defer_runs_in_reverse_on_every_exit() {
+        let value = run("
+            let trace = 0
+
+            func step(let digit: Int64) {
+                trace = trace * 10 + digit
+            }
+
+            func early(): Int64 {
+                defer { step(1) }
+                defer { step(2) }
+                return(0)
+            }
+
+            func looped(): Int64 {
+                while(true, {
+                    defer { step(4) }
+                    defer { step(5) }
+                    break()
+                })
+                0
+            }
+
+            func missing(): Int64? { none() }
+
+            func propagated(): Int64? {
+                defer { step(3) }
+                let value = missing()?
+                some(value)
+            }
+
+            early()
+            looped()
+            propagated()
+            trace
+        ");
+
+        assert_eq!(value, Value::Integer(21543));
+    }
+
+    #[test]
+    fn cast_converts_between_scalars() {
+        assert_eq!(run("(300 as UInt8) as Int64"), Value::Integer(44));
+        assert_eq!(run("2 as Boolean"), Value::Boolean(true));
+        assert_eq!(run("0 as Boolean"), Value::Boolean(false));
+    }
+
+    #[test]
+    fn cast_rejects_structures() {
+        let errors = reject(
+            "
+            struct Point {
+                let x: Int64,
+                let y: Int64
+            }
+
+            let point = Point { x = 1, y = 2 }
+            point as Int64
+        ",
+        );
+
+        assert!(errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::Uncastable(..))));
+    }
+
+    #[test]
+    fn cast_refuses_pointer_addresses() {
+        let kind = fail(
+            "
+            let value = 1
+            let pointer = &value
+            pointer as Int64
+        ",
+            false,
+        );
+
+        assert!(matches!(kind, ErrorKind::Address));
+    }
+
+    #[test]
+    fn result_passes_ok_through_propagation() {
+        let value = run("
+            func half(let value: Int64): Result[Int64, Int64] {
+                if value % 2 != 0 { return(err(value)) }
+                ok(value / 2)
+            }
+
+            func quarter(let value: Int64): Result[Int64, Int64] {
+                let first = half(value)?
+                let second = half(first)?
+                ok(second)
+            }
+
+            quarter(12)
+        ");
+
+        assert_eq!(
+            value,
+            Value::Structure(
+                Str::from("Result"),
+                vec![Value::Integer(0), Value::Integer(3)]
+            )
+        );
+    }
+
+    #[test]
+    fn result_returns_early_on_err() {
+        let value = run("
+            let trace = 0
+
+            func half(let value: Int64): Result[Int64, Int64] {
+                if value % 2 != 0 { return(err(value)) }
+                ok(value / 2)
+            }
+
+            func quarter(let value: Int64): Result[Int64, Int64] {
+                let first = half(value)?
+                trace = 1
+                let second = half(first)?
+                trace = 2
+                ok(second)
+            }
+
+            let outcome = quarter(6)
+            trace
+        ");
+
+        assert_eq!(value, Value::Integer(1));
+
+        let value = run("
+            func half(let value: Int64): Result[Int64, Int64] {
+                if value % 2 != 0 { return(err(value)) }
+                ok(value / 2)
+            }
+
+            func quarter(let value: Int64): Result[Int64, Int64] {
+                let first = half(value)?
+                let second = half(first)?
+                ok(second)
+            }
+
+            quarter(6)
+        ");
+
+        assert_eq!(
+            value,
+            Value::Structure(
+                Str::from("Result"),
+                vec![Value::Integer(1), Value::Integer(3)]
+            )
+        );
+    }
+
+    #[test]
+    fn open_file_wraps_descriptor_in_result() {
+        let source = "file.open_file(\"axo\", 0, 0)";
+
+        let mut engine = Engine::new();
+        engine.register(
+            Str::from("file_open"),
+            Foreign::native(|_| Value::Integer(3)),
+        );
+
+        assert_eq!(
+            interpret(source, &mut engine).unwrap(),
+            Value::Structure(
+                Str::from("Result"),
+                vec![Value::Integer(0), Value::Integer(3)]
+            )
+        );
+
+        let mut engine = Engine::new();
+        engine.register(
+            Str::from("file_open"),
+            Foreign::native(|_| Value::Integer(-1)),
+        );
+
+        let Value::Structure(_, members) = interpret(source, &mut engine).unwrap() else {
+            panic!("expected a result");
+        };
+
+        assert_eq!(members.first(), Some(&Value::Integer(1)));
+    }
+
+    #[test]
+    fn checked_arithmetic_traps_at_operand_width() {
+        for source in [
+            "(100 as Int8) + (100 as Int8)",
+            "((0 - 100) as Int8) - (100 as Int8)",
+            "(16 as Int8) * (8 as Int8)",
+            "-((0 - 128) as Int8)",
+            "(2147483647 as Int32) + (1 as Int32)",
+            "9223372036854775807 + 1",
+            "(200 as UInt8) + (56 as UInt8)",
+            "(0 as UInt8) - (1 as UInt8)",
+            "(16 as UInt8) * (16 as UInt8)",
+            "-(1 as UInt8)",
+            "(0 as UInt64) - (1 as UInt64)",
+        ] {
+            assert!(
+                matches!(fail(source, true), ErrorKind::Overflow),
+                "expected `{}` to overflow",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic_keeps_values_in_range() {
+        for (source, expected) in [
+            ("(100 as Int8) + (27 as Int8)", 127),
+            ("((0 - 100) as Int8) - (28 as Int8)", -128),
+            ("-((0 - 127) as Int8)", 127),
+            ("(255 as UInt8) * (1 as UInt8)", 255),
+            ("-(0 as UInt8)", 0),
+            ("(2147483646 as Int32) + (1 as Int32)", 2147483647),
+        ] {
+            assert_eq!(execute(source, true).unwrap(), Value::Integer(expected));
+        }
+    }
+
+    #[test]
+    fn operators_resolve_to_structure_methods() {
+        let source = "
+            struct Money {
+                let cents: Int64
+            }
+
+            impl Money {
+                func add(let self: Money, let other: Money): Money {
+                    Money { cents = self.cents + other.cents }
+                }
+
+                func equal(let self: Money, let other: Money): Boolean {
+                    self.cents == other.cents
+                }
+            }
+
+            let small = Money { cents = 150 }
+            let large = Money { cents = 250 }
+        ";
+
+        let sum = run(&format!("{}\n(small + large).cents", source));
+        assert_eq!(sum, Value::Integer(400));
+
+        for (expression, expected) in [
+            ("small == small", true),
+            ("small == large", false),
+            ("small != large", true),
+            ("small != small", false),
+        ] {
+            let value = run(&format!("{}\n{}", source, expression));
+            assert_eq!(value, Value::Boolean(expected), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn operators_borrow_pointer_receivers() {
+        let value = run("
+            struct Counter {
+                let count: Int64
+            }
+
+            impl Counter {
+                func add(let self: *Counter, let amount: Int64): Int64 {
+                    self.count + amount
+                }
+            }
+
+            let counter = Counter { count = 5 }
+            counter + 10
+        ");
+
+        assert_eq!(value, Value::Integer(15));
+    }
+
+    #[test]
+    fn attributes_are_checked_against_their_declaration() {
+        let errors = reject("@foo func first(): Int64 { 1 }");
+        assert!(errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::UnknownAttribute(..))));
+
+        let errors = reject("@inline @inline func second(): Int64 { 2 }");
+        assert!(errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::DuplicateAttribute(..))));
+
+        let errors = reject("@inline struct Point { let x: Int64 }");
+        assert!(errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::MisplacedAttribute { .. })));
+
+        let errors = reject("@link_name(\"axo_pick\") func pick[T](let value: T): T { value }");
+        assert!(errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::MisplacedAttribute { .. })));
+    }
+
+    #[test]
+    fn assembly_checks_operands_against_constraints() {
+        let errors = reject("asm(\"mov $1, $0\", \"=r,r\")");
+        assert!(errors.iter().any(|kind| matches!(
+            kind,
+            resolver::ErrorKind::AssemblyOperands {
+                expected: 1,
+                found: 0
+            }
+        )));
+
+        let errors = reject("asm(\"inc $0\", \"+r,~{cc}\", 1)");
+        assert!(!errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::AssemblyOperands { .. })));
+
+        let errors = reject(
+            "
+            struct Point {
+                let x: Int64
+            }
+
+            asm(\"nop\", \"r\", Point { x = 1 })
+        ",
+        );
+        assert!(errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::Unassemblable(..))));
+    }
+
+    #[test]
+    fn assembly_types_outputs_from_annotations() {
+        let errors = reject(
+            "
+            let small = 1 as Int8
+            let wide: Int32 = asm(\"mov $1, $0\", \"=r,r\", 2 as Int32)
+            let same: Int8 = asm(\"inc $0\", \"+r\", small)
+        ",
+        );
+        assert!(errors.is_empty());
+
+        let errors = reject(
+            "
+            let small = 1 as Int8
+            let wrong: Boolean = asm(\"inc $0\", \"+r\", small)
+        ",
+        );
+        assert!(errors
+            .iter()
+            .any(|kind| matches!(kind, resolver::ErrorKind::Mismatch(..))));
+    }
+
+    #[test]
+    fn assembly_is_rejected_by_the_interpreter() {
+        let kind = fail(
+            "
+            let value: Int64 = asm(\"mov $1, $0\", \"=r,r\", 5)
+            value
+        ",
+            false,
+        );
+
+        assert!(matches!(kind, ErrorKind::Assembly));
+    }
+}
@@ -13,6 +13,7 @@ pub enum Value<'a> {
     Structure(Str<'a>, Vec<Value<'a>>),
     Union(Str<'a>, Box<Value<'a>>),
     Function(Str<'a>),
+    Closure(Str<'a>, Vec<Value<'a>>),
     Void,
 }
 
@@ -27,6 +28,14 @@ impl<'a> Value<'a> {
         }
     }
 
+    pub fn is_present(&self) -> bool {
+        match self {
+            Value::Void => false,
+            Value::Pointer(inner) => !matches!(**inner, Value::Void),
+            _ => true,
+        }
+    }
+
     pub fn tag(&self) -> Value<'a> {
         match self {
             Value::Structure(_, fields) => fields.first().cloned().unwrap_or(Value::Void),
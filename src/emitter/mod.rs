@@ -29,7 +29,7 @@ pub use inkwell::{Context, ContextRef, Inkwell, TargetMachine};
 use crate::{
     data::memory::{Arc},
     internal::{
-        SessionError,
+        Record, SessionError,
         platform::Lock,
     },
     tracker::{
@@ -106,6 +106,18 @@ fn generate_inkwell<'source>(
     keys.sort();
 
     let discard = session.get_directive(Str::from("Discard")).is_some();
+    emitter.checked = session.get_directive(Str::from("Checked")).is_some();
+    emitter.trapping = session.get_directive(Str::from("Trap")).is_some();
+
+    for &key in &keys {
+        let record = session.records.get(&key).unwrap();
+
+        if let Some(content) = record.content() {
+            let mut source = Record::new(record.kind.clone(), record.location);
+            source.set_content(*content);
+            emitter.sources.insert(key, source);
+        }
+    }
 
     for &key in &keys {
         let record = session.records.get_mut(&key).unwrap();
@@ -119,6 +131,7 @@ fn generate_inkwell<'source>(
             let module = emitter.context.create_module(stem.as_str().unwrap());
 
             module.set_triple(&triple);
+            module.set_data_layout(&emitter.target.get_data_layout());
 
             emitter.modules.insert(stem, module);
             emitter.current_module = stem;
@@ -60,6 +60,23 @@ impl<'analysis> Show<'analysis> for AnalysisKind<'analysis> {
             AnalysisKind::SizeOf(analysis) => base
                 .variant("SizeOf")
                 .field("target", analysis.format(config.clone())),
+            AnalysisKind::Cast(value, typing) => base
+                .variant("Cast")
+                .field("value", value.format(config.clone()))
+                .field("target", typing.format(config.clone())),
+            AnalysisKind::Wrap(value) => base
+                .variant("Wrap")
+                .field("value", value.format(config.clone())),
+            AnalysisKind::Nothing => base.variant("Nothing"),
+            AnalysisKind::Success(value) => base
+                .variant("Success")
+                .field("value", value.format(config.clone())),
+            AnalysisKind::Failure(error) => base
+                .variant("Failure")
+                .field("error", error.format(config.clone())),
+            AnalysisKind::Propagate(value) => base
+                .variant("Propagate")
+                .field("value", value.format(config.clone())),
             AnalysisKind::Add(left, right) => {
                 format_binary(&config, "AnalysisKind", "Add", left, right)
             }
@@ -139,6 +156,11 @@ impl<'analysis> Show<'analysis> for AnalysisKind<'analysis> {
                 .variant("Call")
                 .field("target", target.format(config.clone()))
                 .field("members", values.format(config.clone())),
+            AnalysisKind::Assembly(template, constraints, operands) => base
+                .variant("Assembly")
+                .field("template", template.format(config.clone()))
+                .field("constraints", constraints.format(config.clone()))
+                .field("operands", operands.format(config.clone())),
             AnalysisKind::Block(block) => base
                 .variant("Block")
                 .field("statements", block.format(config.clone())),
@@ -147,10 +169,16 @@ impl<'analysis> Show<'analysis> for AnalysisKind<'analysis> {
                 .field("condition", condition.format(config.clone()))
                 .field("then", then.format(config.clone()))
                 .field("alternate", alternate.format(config.clone())),
-            AnalysisKind::While(condition, then) => base
-                .variant("While")
-                .field("condition", condition.format(config.clone()))
-                .field("then", then.format(config.clone())),
+            AnalysisKind::While(condition, then, label) => {
+                let mut base = base
+                    .variant("While")
+                    .field("condition", condition.format(config.clone()))
+                    .field("then", then.format(config.clone()));
+                if let Some(label) = label {
+                    base = base.field("label", label.format(config.clone()));
+                }
+                base
+            }
             AnalysisKind::Return(value) => {
                 let mut base = base.variant("Return");
                 if let Some(value) = value {
@@ -158,20 +186,29 @@ impl<'analysis> Show<'analysis> for AnalysisKind<'analysis> {
                 }
                 base
             }
-            AnalysisKind::Break(value) => {
+            AnalysisKind::Break(label, value) => {
                 let mut base = base.variant("Break");
+                if let Some(label) = label {
+                    base = base.field("label", label.format(config.clone()));
+                }
                 if let Some(value) = value {
                     base = base.field("value", value.format(config.clone()));
                 }
                 base
             }
-            AnalysisKind::Continue(value) => {
+            AnalysisKind::Continue(label, value) => {
                 let mut base = base.variant("Continue");
+                if let Some(label) = label {
+                    base = base.field("label", label.format(config.clone()));
+                }
                 if let Some(value) = value {
                     base = base.field("value", value.format(config.clone()));
                 }
                 base
             }
+            AnalysisKind::Defer(body) => base
+                .variant("Defer")
+                .field("body", body.format(config.clone())),
             AnalysisKind::Usage(target) => base
                 .variant("Usage")
                 .field("target", target.format(config.clone())),
@@ -199,6 +236,14 @@ impl<'analysis> Show<'analysis> for AnalysisKind<'analysis> {
                     .field("target", target.format(config.clone()))
                     .field("members", members)
             }
+            AnalysisKind::Pattern(binders) => {
+                let mut members = config.clone().new("Slots");
+                for (slot, binder) in binders {
+                    members = members.field(&slot.to_string(), binder.format(config.clone()));
+                }
+
+                base.variant("Pattern").field("members", members)
+            }
             AnalysisKind::Assign(target, value) => base
                 .variant("Assign")
                 .field("target", target.format(config.clone()))
@@ -220,12 +265,42 @@ impl<'analysis> Show<'analysis> for AnalysisKind<'analysis> {
             AnalysisKind::Union(union) => base
                 .variant("Union")
                 .field("value", union.format(config.clone())),
+            AnalysisKind::Enumeration(enumeration) => base
+                .variant("Enumeration")
+                .field("value", enumeration.format(config.clone())),
+            AnalysisKind::Variant(target, index, payload) => base
+                .variant("Variant")
+                .field("target", target.format(config.clone()))
+                .field("index", index.to_string())
+                .field("payload", payload.format(config.clone())),
+            AnalysisKind::Match(scrutinee, arms) => {
+                let mut members = config.clone().new("Arms");
+                for (index, binders, body) in arms {
+                    let label = index.map_or_else(|| String::from("_"), |index| index.to_string());
+                    members = members.field(
+                        &label,
+                        config
+                            .clone()
+                            .new("Arm")
+                            .field("binders", binders.format(config.clone()))
+                            .field("body", body.format(config.clone())),
+                    );
+                }
+
+                base.variant("Match")
+                    .field("scrutinee", scrutinee.format(config.clone()))
+                    .field("arms", members)
+            }
             AnalysisKind::Composite(composite) => base
                 .variant("Composite")
                 .field("value", composite.format(config.clone())),
             AnalysisKind::Function(function) => base
                 .variant("Function")
                 .field("value", function.format(config.clone())),
+            AnalysisKind::Closure(function, captures) => base
+                .variant("Closure")
+                .field("value", function.format(config.clone()))
+                .field("captures", captures.format(config.clone())),
             AnalysisKind::Module(name, members) => base
                 .variant("Module")
                 .field("name", name.format(config.clone()))
@@ -22,6 +22,17 @@ impl<'show, Target: Show<'show>, Value: Show<'show>, Type: Show<'show>> Show<'sh
     }
 }
 
+impl<'show, Target: Show<'show>, Value: Show<'show>> Show<'show> for Alias<Target, Value> {
+    fn format(&self, config: Stencil) -> Stencil {
+        config
+            .clone()
+            .new("Alias")
+            .field("kind", format!("{:?}", self.kind))
+            .field("target", self.target.format(config.clone()))
+            .field("value", self.value.format(config.clone()))
+    }
+}
+
 impl<'show, Target: Show<'show>, Member: Show<'show>> Show<'show> for Aggregate<Target, Member> {
     fn format(&self, config: Stencil) -> Stencil {
         config
@@ -66,12 +66,27 @@ impl<'symbol> Show<'symbol> for SymbolKind<'symbol> {
             SymbolKind::Module(module) => base
                 .variant("Module")
                 .field("value", module.format(config.clone())),
+            SymbolKind::Import(import) => base
+                .variant("Import")
+                .field("value", import.format(config.clone())),
+            SymbolKind::Alias(alias) => base
+                .variant("Alias")
+                .field("value", alias.format(config.clone())),
             SymbolKind::Structure(structure) => base
                 .variant("Structure")
                 .field("value", structure.format(config.clone())),
             SymbolKind::Union(union) => base
                 .variant("Union")
                 .field("value", union.format(config.clone())),
+            SymbolKind::Enumeration(enumeration) => base
+                .variant("Enumeration")
+                .field("value", enumeration.format(config.clone())),
+            SymbolKind::Implementation(implementation) => base
+                .variant("Implementation")
+                .field("value", implementation.format(config.clone())),
+            SymbolKind::Interface(interface) => base
+                .variant("Interface")
+                .field("value", interface.format(config.clone())),
         }
     }
 }
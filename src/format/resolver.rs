@@ -37,6 +37,17 @@ impl<'typing> Show<'typing> for TypeKind<'typing> {
             TypeKind::Tuple { members } => base
                 .variant("Tuple")
                 .field("members", members.format(config.clone())),
+            TypeKind::Newtype { target, member } => base
+                .variant("Newtype")
+                .field("target", target.format(config.clone()))
+                .field("member", member.format(config.clone())),
+            TypeKind::Optional { member } => base
+                .variant("Optional")
+                .field("member", member.format(config.clone())),
+            TypeKind::Result { value, error } => base
+                .variant("Result")
+                .field("value", value.format(config.clone()))
+                .field("error", error.format(config.clone())),
             TypeKind::Function(function) => base
                 .variant("Function")
                 .field("name", function.target.format(config.clone()))
@@ -53,6 +64,9 @@ impl<'typing> Show<'typing> for TypeKind<'typing> {
             TypeKind::Union(union) => base
                 .variant("Union")
                 .field("union", union.format(config.clone())),
+            TypeKind::Enumeration(enumeration) => base
+                .variant("Enumeration")
+                .field("enumeration", enumeration.format(config.clone())),
             TypeKind::Binding(binding) => base
                 .variant("Binding")
                 .field("target", binding.target.format(config.clone()))
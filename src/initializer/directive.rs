@@ -298,6 +298,16 @@ impl<'a> Initializer<'a> {
         Self::flag(Str::from("Bare"), |identifier| *identifier == "bare")
     }
 
+    pub fn checked<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Symbol<'a>, InitializeError<'a>> {
+        Self::flag(Str::from("Checked"), |identifier| *identifier == "checked")
+    }
+
+    pub fn trap<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Symbol<'a>, InitializeError<'a>> {
+        Self::flag(Str::from("Trap"), |identifier| *identifier == "trap")
+    }
+
     pub fn cranelift<'source>(
     ) -> Formation<'a, 'source, Self, Token<'a>, Symbol<'a>, InitializeError<'a>> {
         Self::flag(Str::from("Cranelift"), |identifier| {
@@ -121,6 +121,8 @@ impl<'a> Initializer<'a> {
             Self::target(),
             Self::discard(),
             Self::bare(),
+            Self::checked(),
+            Self::trap(),
             Self::cranelift(),
             Self::implicit(),
             Formation::anything().with_panic(
@@ -260,4 +262,45 @@ mod tests {
             .iter()
             .any(|(location, _)| location.to_string() == "./examples/calculator.axo"));
     }
+
+    #[test]
+    fn c_input_becomes_target() {
+        let mut initializer =
+            Initializer::new(Str::from("./examples/geometry.axo ./examples/geometry.c"));
+        let targets = initializer.initialize();
+
+        for path in ["./examples/geometry.axo", "./examples/geometry.c"] {
+            assert!(targets
+                .iter()
+                .any(|(location, _)| location.to_string() == path));
+        }
+    }
+
+    #[test]
+    fn checked_flag_becomes_directive() {
+        let mut initializer = Initializer::new(Str::from("--checked ./examples/calculator.axo"));
+        let targets = initializer.initialize();
+
+        assert!(initializer
+            .output
+            .iter()
+            .any(|symbol| symbol.target() == Some(Str::from("Checked"))));
+        assert!(targets
+            .iter()
+            .any(|(location, _)| location.to_string() == "./examples/calculator.axo"));
+    }
+
+    #[test]
+    fn trap_flag_combines_with_checked() {
+        let mut initializer =
+            Initializer::new(Str::from("--checked --trap ./examples/calculator.axo"));
+        initializer.initialize();
+
+        for name in ["Checked", "Trap"] {
+            assert!(initializer
+                .output
+                .iter()
+                .any(|symbol| symbol.target() == Some(Str::from(name))));
+        }
+    }
 }
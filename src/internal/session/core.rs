@@ -43,6 +43,7 @@ pub const BASE: &[(&str, &str)] = &[
         include_str!("../../../base/string.axo"),
     ),
     ("./base/string.c", include_str!("../../../base/string.c")),
+    ("./base/trap.c", include_str!("../../../base/trap.c")),
     ("./base/input.axo", include_str!("../../../base/input.axo")),
     ("./base/input.c", include_str!("../../../base/input.c")),
     (
@@ -372,13 +373,31 @@ impl<'session> Session<'session> {
         xprintln!();
     }
 
+    pub fn report_note(&self, note: String, span: Span) {
+        let error = Error::new(note, span);
+        let (message, details) = error.handle_record(self.records.get(&span.identity));
+        xprintln!(
+            "{}{}\n{}",
+            "note: ".colorize(Color::Blue).bold(),
+            message,
+            details
+        );
+        xprintln!();
+    }
+
     pub fn report_all(&self) {
         for error in &self.errors {
             match error {
                 SessionError::Initialize(error) => self.report_error(error),
                 SessionError::Scan(error) => self.report_error(error),
                 SessionError::Parse(error) => self.report_error(error),
-                SessionError::Resolve(error) => self.report_error(error),
+                SessionError::Resolve(error) => {
+                    self.report_error(error);
+
+                    if let Some((note, span)) = error.kind.note() {
+                        self.report_note(note, span);
+                    }
+                }
                 SessionError::Analyze(error) => self.report_error(error),
                 SessionError::Track(error) => self.report_error(error),
                 #[cfg(any(feature = "llvm", feature = "interpreter"))]
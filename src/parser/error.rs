@@ -9,6 +9,7 @@ pub enum ErrorKind<'error> {
     ExpectedHead,
     ExpectedBody,
     ExpectedAnnotation,
+    ExpectedSymbol,
     MissingSeparator(TokenKind<'error>),
     UnclosedDelimiter(TokenKind<'error>),
     UnexpectedToken(TokenKind<'error>),
@@ -29,6 +30,9 @@ impl<'error> Display for ErrorKind<'error> {
             ErrorKind::ExpectedAnnotation => {
                 write!(f, "expected an annotation.")
             }
+            ErrorKind::ExpectedSymbol => {
+                write!(f, "expected a declaration after the attribute.")
+            }
             ErrorKind::MissingSeparator(kind) => {
                 write!(
                     f,
@@ -0,0 +1,156 @@
+use {
+    crate::{
+        data::*,
+        parser::{Element, ElementKind, ErrorKind, ParseError, Parser},
+        scanner::Token,
+        tracker::{Span, Spanned},
+    },
+    chaint::{Form, Formation},
+};
+
+impl<'a> Parser<'a> {
+    fn keyword<'source>(
+        name: &'static str,
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::predicate(move |token: &Token| {
+            if let Some(id) = token.kind.try_unwrap_identifier() {
+                id.as_str() == Some(name)
+            } else {
+                false
+            }
+        })
+    }
+
+    fn operand<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Self::alternative([
+            Formation::deferred(Self::prefixed),
+            Formation::deferred(Self::bare),
+            Formation::deferred(Self::primary),
+        ])
+    }
+
+    fn guard<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Self::chain(Self::operand)
+    }
+
+    pub fn condition<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Self::alternative([
+            Formation::deferred(Self::guard),
+            Formation::deferred(Self::operand),
+        ])
+    }
+
+    fn branch<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Self::keyword("else"),
+            Self::alternative([
+                Formation::deferred(Self::r#if),
+                Self::block(Formation::deferred(Self::element)),
+            ])
+            .with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedBody, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let branch = sequence[1].unwrap_output().clone();
+
+            *form = Form::output(branch);
+
+            Ok(())
+        })
+    }
+
+    fn statement(keyword: Token<'a>, members: Vec<Element<'a>>) -> Element<'a> {
+        let span = members.last().map_or(keyword.span, |last| {
+            Span::merge(&keyword.span(), &last.span())
+        });
+        let head = Element::new(ElementKind::literal(keyword.clone()), keyword.span);
+
+        Element::new(ElementKind::invoke(Invoke::new(head, members)), span)
+    }
+
+    pub fn r#if<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Self::keyword("if"),
+            Formation::deferred(Self::condition),
+            Self::block(Formation::deferred(Self::element)),
+            Formation::deferred(Self::branch).into_optional(),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input().clone();
+            let members = sequence[1..]
+                .iter()
+                .map(|form| form.unwrap_output().clone())
+                .collect::<Vec<_>>();
+
+            *form = Form::output(Self::statement(keyword, members));
+
+            Ok(())
+        })
+    }
+
+    pub fn r#while<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
+    {
+        Formation::sequence([
+            Self::keyword("while"),
+            Formation::deferred(Self::condition),
+            Self::block(Formation::deferred(Self::element)),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input().clone();
+            let members = sequence[1..]
+                .iter()
+                .map(|form| form.unwrap_output().clone())
+                .collect::<Vec<_>>();
+
+            *form = Form::output(Self::statement(keyword, members));
+
+            Ok(())
+        })
+    }
+
+    pub fn r#defer<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
+    {
+        Formation::sequence([
+            Self::keyword("defer"),
+            Self::block(Formation::deferred(Self::element)),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input().clone();
+            let members = sequence[1..]
+                .iter()
+                .map(|form| form.unwrap_output().clone())
+                .collect::<Vec<_>>();
+
+            *form = Form::output(Self::statement(keyword, members));
+
+            Ok(())
+        })
+    }
+}
@@ -1,3 +1,4 @@
+mod control;
 mod delimited;
 mod symbol;
 
@@ -5,7 +6,7 @@ use {
     crate::{
         data::*,
         parser::{Element, ElementKind, ErrorKind, ParseError, Parser},
-        scanner::{PunctuationKind, Token, TokenKind},
+        scanner::{OperatorKind, PunctuationKind, Token, TokenKind},
         tracker::{Span, Spanned},
     },
     chaint::{formation::Joint, Form, Formation, Peekable},
@@ -93,7 +94,23 @@ impl<'a> Parser<'a> {
             | TokenKind::Integer(_) => true,
             TokenKind::Identifier(identifier) => !matches!(
                 identifier.unwrap_str(),
-                "static" | "let" | "struct" | "union" | "func" | "module"
+                "static"
+                    | "let"
+                    | "struct"
+                    | "union"
+                    | "enum"
+                    | "impl"
+                    | "interface"
+                    | "func"
+                    | "module"
+                    | "use"
+                    | "pub"
+                    | "const"
+                    | "type"
+                    | "newtype"
+                    | "in"
+                    | "as"
+                    | "else"
             ),
             _ => false,
         })
@@ -111,10 +128,42 @@ impl<'a> Parser<'a> {
         })
     }
 
+    pub fn head<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::deferred(Self::literal),
+            Self::collection(Formation::deferred(Self::expression)).into_optional(),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let mut outputs = form.collect_outputs().into_iter();
+            let name = outputs.next().unwrap();
+
+            *form = Form::output(match outputs.next() {
+                Some(parameters) => {
+                    let span = Span::merge(&name.span(), &parameters.span());
+
+                    Element::new(
+                        ElementKind::index(Index::new(name, Self::get_body(parameters))),
+                        span,
+                    )
+                }
+                None => name,
+            });
+
+            Ok(())
+        })
+    }
+
     pub fn primary<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
     {
         Self::alternative([
             Formation::deferred(Self::delimited),
+            Formation::deferred(Self::r#if),
+            Formation::deferred(Self::r#while),
+            Formation::deferred(Self::r#defer),
+            Formation::deferred(Self::closure),
             Formation::deferred(Self::literal),
         ])
     }
@@ -151,24 +200,42 @@ impl<'a> Parser<'a> {
 
     pub fn suffixed<'source>(
     ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Self::suffixes(true)
+    }
+
+    pub fn bare<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Self::suffixes(false)
+    }
+
+    fn suffixes<'source>(
+        construct: bool,
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        let operator = Formation::predicate(|token: &Token| {
+            if let TokenKind::Operator(operator) = &token.kind {
+                operator.is_suffix()
+            } else {
+                false
+            }
+        });
+
+        let suffix = if construct {
+            Self::alternative([
+                Self::group(Formation::deferred(Self::element)),
+                Self::collection(Formation::deferred(Self::element)),
+                Self::bundle(Formation::deferred(Self::element)),
+                operator,
+            ])
+        } else {
+            Self::alternative([
+                Self::group(Formation::deferred(Self::element)),
+                Self::collection(Formation::deferred(Self::element)),
+                operator,
+            ])
+        };
+
         Formation::sequence([
             Formation::deferred(Self::primary),
-            Formation::repetition(
-                Self::alternative([
-                    Self::group(Formation::deferred(Self::element)),
-                    Self::collection(Formation::deferred(Self::element)),
-                    Self::bundle(Formation::deferred(Self::element)),
-                    Formation::predicate(|token: &Token| {
-                        if let TokenKind::Operator(operator) = &token.kind {
-                            operator.is_suffix()
-                        } else {
-                            false
-                        }
-                    }),
-                ]),
-                0,
-                None,
-            ),
+            Formation::repetition(suffix, 0, None),
         ])
         .with_transform(move |joint| {
             let (former, formation) = (&mut joint.0, &mut joint.1);
@@ -246,19 +313,25 @@ impl<'a> Parser<'a> {
 
     pub fn binary<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
     {
+        Self::chain(Self::unary)
+    }
+
+    fn chain<'source>(
+        operand: fn() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>,
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
         Self::alternative([Formation::with_transform(
             Formation::sequence([
-                Formation::deferred(Self::unary),
+                Formation::deferred(operand),
                 Formation::repetition(
                     Formation::sequence([
-                        Formation::predicate(|token: &Token| {
-                            if let TokenKind::Operator(operator) = &token.kind {
-                                operator.precedence().is_some()
-                            } else {
-                                false
-                            }
+                        Formation::predicate(|token: &Token| match &token.kind {
+                            TokenKind::Operator(operator) => operator.precedence().is_some(),
+                            TokenKind::Identifier(identifier) => identifier
+                                .as_str()
+                                .is_some_and(|name| OperatorKind::keyword(name).is_some()),
+                            _ => false,
                         }),
-                        Formation::deferred(Self::unary),
+                        Formation::deferred(operand),
                     ]),
                     1,
                     None,
@@ -279,10 +352,13 @@ impl<'a> Parser<'a> {
                     if inner.len() >= 2 {
                         let operator = inner[0].unwrap_input().clone();
                         let operand = inner[1].unwrap_output().clone();
-                        let precedence = if let TokenKind::Operator(op) = &operator.kind {
-                            op.precedence().unwrap_or(0)
-                        } else {
-                            0
+                        let precedence = match &operator.kind {
+                            TokenKind::Operator(op) => op.precedence().unwrap_or(0),
+                            TokenKind::Identifier(identifier) => identifier
+                                .as_str()
+                                .and_then(OperatorKind::keyword)
+                                .unwrap_or(0),
+                            _ => 0,
                         };
                         pairs.push((operator, operand, precedence));
                     }
@@ -12,13 +12,182 @@ impl<'a> Parser<'a> {
     pub fn symbolization<'source>(
     ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
         Self::alternative([
+            Formation::deferred(Self::attribute),
+            Formation::deferred(Self::visibility),
+            Formation::deferred(Self::constant),
             Formation::deferred(Self::binding),
+            Formation::deferred(Self::synonym),
             Formation::deferred(Self::structure),
             Formation::deferred(Self::union),
+            Formation::deferred(Self::enumeration),
+            Formation::deferred(Self::implementation),
+            Formation::deferred(Self::interface),
             Formation::deferred(Self::function),
+            Formation::deferred(Self::import),
         ])
     }
 
+    pub fn visibility<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::predicate(|token: &Token| {
+                if let Some(id) = token.kind.try_unwrap_identifier() {
+                    id.as_str() == Some("pub")
+                } else {
+                    false
+                }
+            }),
+            Formation::deferred(Self::symbolization).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedBody, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input().clone();
+            let mut element = sequence[1].unwrap_output().clone();
+
+            if let ElementKind::Symbolize(symbol) = &mut element.kind {
+                symbol.visibility = Visibility::Public;
+                symbol.span = Span::merge(&keyword.span(), &symbol.span);
+            }
+
+            element.span = Span::merge(&keyword.span(), &element.span);
+            *form = Form::output(element);
+
+            Ok(())
+        })
+    }
+
+    fn tag<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::deferred(Self::literal),
+            Self::group(Formation::deferred(Self::expression)).into_optional(),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let mut outputs = form.collect_outputs().into_iter();
+            let name = outputs.next().unwrap();
+
+            *form = Form::output(match outputs.next() {
+                Some(arguments) => {
+                    let span = Span::merge(&name.span(), &arguments.span());
+
+                    Element::new(
+                        ElementKind::invoke(Invoke::new(name, Self::get_body(arguments))),
+                        span,
+                    )
+                }
+                None => name,
+            });
+
+            Ok(())
+        })
+    }
+
+    pub fn attribute<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::predicate(|token: &Token| {
+                matches!(
+                    &token.kind,
+                    TokenKind::Operator(operator) if operator.as_slice() == [OperatorKind::At]
+                )
+            }),
+            Formation::deferred(Self::tag),
+            Formation::deferred(Self::symbolization).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedSymbol, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let marker = sequence[0].unwrap_input().clone();
+            let tag = sequence[1].unwrap_output().clone();
+            let mut element = sequence[2].unwrap_output().clone();
+
+            if let ElementKind::Symbolize(symbol) = &mut element.kind {
+                symbol.attributes.insert(0, tag);
+                symbol.span = Span::merge(&marker.span(), &symbol.span);
+            }
+
+            element.span = Span::merge(&marker.span(), &element.span);
+            *form = Form::output(element);
+
+            Ok(())
+        })
+    }
+
+    pub fn constant<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::predicate(|token: &Token| {
+                if let Some(id) = token.kind.try_unwrap_identifier() {
+                    id.as_str() == Some("const")
+                } else {
+                    false
+                }
+            }),
+            Formation::deferred(Self::function).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedBody, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input().clone();
+            let mut element = sequence[1].unwrap_output().clone();
+
+            if let ElementKind::Symbolize(symbol) = &mut element.kind {
+                if let SymbolKind::Function(function) = &mut symbol.kind {
+                    function.constant = true;
+                }
+
+                symbol.span = Span::merge(&keyword.span(), &symbol.span);
+            }
+
+            element.span = Span::merge(&keyword.span(), &element.span);
+            *form = Form::output(element);
+
+            Ok(())
+        })
+    }
+
     pub fn binding<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
     {
         Formation::sequence([
@@ -62,61 +231,336 @@ impl<'a> Parser<'a> {
             let mut body = sequence[1].unwrap_output().clone();
             let span = Span::merge(&keyword.span(), &body.span());
 
-            let mut value = None;
-            let mut annotation = None;
+            let mut value = None;
+            let mut annotation = None;
+
+            if let ElementKind::Binary(binary) = &body.kind.clone() {
+                if let Some(OperatorKind::Equal) = binary.operator.kind.try_unwrap_operator() {
+                    if let ElementKind::Binary(inner_binary) = &binary.left.kind {
+                        value = Some(binary.right.clone());
+                        if let Some(OperatorKind::Colon) =
+                            inner_binary.operator.kind.try_unwrap_operator()
+                        {
+                            body = inner_binary.left.clone();
+                            annotation = Some(inner_binary.right.clone());
+                        }
+                    } else {
+                        body = binary.left.clone();
+                        value = Some(binary.right.clone());
+                    }
+                } else if let Some(OperatorKind::Colon) = binary.operator.kind.try_unwrap_operator()
+                {
+                    body = binary.left.clone();
+                    annotation = Some(binary.right.clone());
+                } else {
+                    if let ElementKind::Binary(assigned) = &binary.left.kind {
+                        if let Some(OperatorKind::Equal) =
+                            assigned.operator.kind.try_unwrap_operator()
+                        {
+                            let merged_span =
+                                Span::merge(&assigned.right.span(), &binary.right.span());
+                            let merged_value = Element::new(
+                                ElementKind::binary(Binary::new(
+                                    assigned.right.clone(),
+                                    binary.operator.clone(),
+                                    binary.right.clone(),
+                                )),
+                                merged_span,
+                            );
+                            value = Some(merged_value);
+
+                            body = assigned.left.clone();
+                            if let ElementKind::Binary(annotation_pair) = &body.kind.clone() {
+                                if let Some(OperatorKind::Colon) =
+                                    annotation_pair.operator.kind.try_unwrap_operator()
+                                {
+                                    body = annotation_pair.left.clone();
+                                    annotation = Some(annotation_pair.right.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            *form = Form::output(Element::new(
+                ElementKind::Symbolize(Box::from(Symbol::new(
+                    SymbolKind::binding(Binding::new(body, value, annotation, kind)),
+                    span,
+                ))),
+                span,
+            ));
+
+            Ok(())
+        })
+    }
+
+    pub fn synonym<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
+    {
+        Formation::sequence([
+            Formation::predicate(|token: &Token| {
+                if let Some(id) = token.kind.try_unwrap_identifier() {
+                    matches!(id.as_str().unwrap(), "type" | "newtype")
+                } else {
+                    false
+                }
+            }),
+            Formation::deferred(Self::expression).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedBody, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input();
+
+            let kind = match keyword
+                .kind
+                .try_unwrap_identifier()
+                .and_then(|id| id.as_str())
+            {
+                Some("newtype") => AliasKind::Nominal,
+                _ => AliasKind::Transparent,
+            };
+
+            let body = sequence[1].unwrap_output().clone();
+            let span = Span::merge(&keyword.span(), &body.span());
+
+            let ElementKind::Binary(binary) = &body.kind else {
+                return Err(ParseError::new(ErrorKind::ExpectedBody, span));
+            };
+
+            if !matches!(
+                binary.operator.kind.try_unwrap_operator(),
+                Some(OperatorKind::Equal)
+            ) {
+                return Err(ParseError::new(ErrorKind::ExpectedBody, span));
+            }
+
+            *form = Form::output(Element::new(
+                ElementKind::Symbolize(Box::from(Symbol::new(
+                    SymbolKind::alias(Alias::new(binary.left.clone(), binary.right.clone(), kind)),
+                    span,
+                ))),
+                span,
+            ));
+
+            Ok(())
+        })
+    }
+
+    pub fn structure<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::sequence([
+                Formation::predicate(|token: &Token| {
+                    if let Some(id) = token.kind.try_unwrap_identifier() {
+                        id.as_str() == Some("struct")
+                    } else {
+                        false
+                    }
+                }),
+                Formation::deferred(Self::head).with_panic(|joint| {
+                    let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                    let consumed = formation
+                        .consumed
+                        .iter()
+                        .map(|index| former.consumed.get(*index).unwrap().clone())
+                        .collect::<Vec<_>>();
+                    let span = consumed.span();
+
+                    ParseError::new(ErrorKind::ExpectedHead, span)
+                }),
+            ]),
+            Formation::deferred(Self::expression).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedBody, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let head = sequence[0].as_forms();
+
+            let keyword = head[0].unwrap_input();
+            let name = head[1].unwrap_output().clone();
+
+            let body = sequence[1].unwrap_output().clone();
+
+            let members: Vec<_> = Self::get_body(body.clone())
+                .into_iter()
+                .filter_map(|element| match element.kind {
+                    ElementKind::Symbolize(symbol) => Some(*symbol),
+                    _ => None,
+                })
+                .collect();
+
+            let span = Span::merge(&keyword.span(), &body.span());
+
+            *form = Form::output(Element::new(
+                ElementKind::Symbolize(Box::new(Symbol::new(
+                    SymbolKind::structure(Aggregate::new(name, members)),
+                    span,
+                ))),
+                span,
+            ));
+
+            Ok(())
+        })
+    }
+
+    pub fn union<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
+    {
+        Formation::sequence([
+            Formation::sequence([
+                Formation::predicate(|token: &Token| {
+                    if let Some(id) = token.kind.try_unwrap_identifier() {
+                        id.as_str() == Some("union")
+                    } else {
+                        false
+                    }
+                }),
+                Formation::deferred(Self::literal).with_panic(|joint| {
+                    let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                    let consumed = formation
+                        .consumed
+                        .iter()
+                        .map(|index| former.consumed.get(*index).unwrap().clone())
+                        .collect::<Vec<_>>();
+                    let span = consumed.span();
+
+                    ParseError::new(ErrorKind::ExpectedHead, span)
+                }),
+            ]),
+            Formation::deferred(Self::expression).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedBody, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let head = sequence[0].as_forms();
+
+            let keyword = head[0].unwrap_input();
+            let name = head[1].unwrap_output().clone();
+
+            let body = sequence[1].unwrap_output().clone();
+
+            let members: Vec<_> = Self::get_body(body.clone())
+                .into_iter()
+                .filter_map(|element| match element.kind {
+                    ElementKind::Symbolize(symbol) => Some(*symbol),
+                    _ => None,
+                })
+                .collect();
+
+            let span = Span::merge(&keyword.span(), &body.span());
+
+            *form = Form::output(Element::new(
+                ElementKind::Symbolize(Box::from(Symbol::new(
+                    SymbolKind::union(Aggregate::new(name, members)),
+                    span,
+                ))),
+                span,
+            ));
+
+            Ok(())
+        })
+    }
+
+    pub fn enumeration<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::sequence([
+                Formation::predicate(|token: &Token| {
+                    if let Some(id) = token.kind.try_unwrap_identifier() {
+                        id.as_str() == Some("enum")
+                    } else {
+                        false
+                    }
+                }),
+                Formation::deferred(Self::literal).with_panic(|joint| {
+                    let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                    let consumed = formation
+                        .consumed
+                        .iter()
+                        .map(|index| former.consumed.get(*index).unwrap().clone())
+                        .collect::<Vec<_>>();
+                    let span = consumed.span();
+
+                    ParseError::new(ErrorKind::ExpectedHead, span)
+                }),
+            ]),
+            Formation::deferred(Self::expression).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedBody, span)
+            }),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let head = sequence[0].as_forms();
+
+            let keyword = head[0].unwrap_input();
+            let name = head[1].unwrap_output().clone();
+
+            let body = sequence[1].unwrap_output().clone();
 
-            if let ElementKind::Binary(binary) = &body.kind.clone() {
-                if let Some(OperatorKind::Equal) = binary.operator.kind.try_unwrap_operator() {
-                    if let ElementKind::Binary(inner_binary) = &binary.left.kind {
-                        value = Some(binary.right.clone());
-                        if let Some(OperatorKind::Colon) =
-                            inner_binary.operator.kind.try_unwrap_operator()
-                        {
-                            body = inner_binary.left.clone();
-                            annotation = Some(inner_binary.right.clone());
-                        }
-                    } else {
-                        body = binary.left.clone();
-                        value = Some(binary.right.clone());
-                    }
-                } else if let Some(OperatorKind::Colon) = binary.operator.kind.try_unwrap_operator()
-                {
-                    body = binary.left.clone();
-                    annotation = Some(binary.right.clone());
-                } else {
-                    if let ElementKind::Binary(assigned) = &binary.left.kind {
-                        if let Some(OperatorKind::Equal) =
-                            assigned.operator.kind.try_unwrap_operator()
-                        {
-                            let merged_span =
-                                Span::merge(&assigned.right.span(), &binary.right.span());
-                            let merged_value = Element::new(
-                                ElementKind::binary(Binary::new(
-                                    assigned.right.clone(),
-                                    binary.operator.clone(),
-                                    binary.right.clone(),
-                                )),
-                                merged_span,
-                            );
-                            value = Some(merged_value);
+            let members: Vec<_> = Self::get_body(body.clone())
+                .into_iter()
+                .filter_map(Self::variant)
+                .collect();
 
-                            body = assigned.left.clone();
-                            if let ElementKind::Binary(annotation_pair) = &body.kind.clone() {
-                                if let Some(OperatorKind::Colon) =
-                                    annotation_pair.operator.kind.try_unwrap_operator()
-                                {
-                                    body = annotation_pair.left.clone();
-                                    annotation = Some(annotation_pair.right.clone());
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+            let span = Span::merge(&keyword.span(), &body.span());
 
             *form = Form::output(Element::new(
                 ElementKind::Symbolize(Box::from(Symbol::new(
-                    SymbolKind::binding(Binding::new(body, value, annotation, kind)),
+                    SymbolKind::enumeration(Aggregate::new(name, members)),
                     span,
                 ))),
                 span,
@@ -126,13 +570,13 @@ impl<'a> Parser<'a> {
         })
     }
 
-    pub fn structure<'source>(
+    pub fn implementation<'source>(
     ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
         Formation::sequence([
             Formation::sequence([
                 Formation::predicate(|token: &Token| {
                     if let Some(id) = token.kind.try_unwrap_identifier() {
-                        id.as_str() == Some("struct")
+                        id.as_str() == Some("impl")
                     } else {
                         false
                     }
@@ -149,6 +593,24 @@ impl<'a> Parser<'a> {
 
                     ParseError::new(ErrorKind::ExpectedHead, span)
                 }),
+                Formation::sequence([
+                    Formation::predicate(|token: &Token| {
+                        matches!(token.kind.try_unwrap_operator(), Some(OperatorKind::Colon))
+                    }),
+                    Formation::deferred(Self::literal).with_panic(|joint| {
+                        let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                        let consumed = formation
+                            .consumed
+                            .iter()
+                            .map(|index| former.consumed.get(*index).unwrap().clone())
+                            .collect::<Vec<_>>();
+                        let span = consumed.span();
+
+                        ParseError::new(ErrorKind::ExpectedAnnotation, span)
+                    }),
+                ])
+                .into_optional(),
             ]),
             Formation::deferred(Self::expression).with_panic(|joint| {
                 let (former, formation) = (&mut joint.0, &mut joint.1);
@@ -171,14 +633,28 @@ impl<'a> Parser<'a> {
             let head = sequence[0].as_forms();
 
             let keyword = head[0].unwrap_input();
-            let name = head[1].unwrap_output().clone();
+            let owner = head[1].unwrap_output().clone();
+
+            let name = match head.get(2).map(|form| form.as_forms()) {
+                Some(interface) if interface.len() == 2 => {
+                    let operator = interface[0].unwrap_input().clone();
+                    let interface = interface[1].unwrap_output().clone();
+                    let span = Span::merge(&owner.span(), &interface.span());
+
+                    Element::new(
+                        ElementKind::binary(Binary::new(owner.clone(), operator, interface)),
+                        span,
+                    )
+                }
+                _ => owner.clone(),
+            };
 
             let body = sequence[1].unwrap_output().clone();
 
             let members: Vec<_> = Self::get_body(body.clone())
                 .into_iter()
                 .filter_map(|element| match element.kind {
-                    ElementKind::Symbolize(symbol) => Some(*symbol),
+                    ElementKind::Symbolize(symbol) => Self::method(&owner, *symbol),
                     _ => None,
                 })
                 .collect();
@@ -186,8 +662,8 @@ impl<'a> Parser<'a> {
             let span = Span::merge(&keyword.span(), &body.span());
 
             *form = Form::output(Element::new(
-                ElementKind::Symbolize(Box::new(Symbol::new(
-                    SymbolKind::structure(Aggregate::new(name, members)),
+                ElementKind::Symbolize(Box::from(Symbol::new(
+                    SymbolKind::implementation(Aggregate::new(name, members)),
                     span,
                 ))),
                 span,
@@ -197,13 +673,13 @@ impl<'a> Parser<'a> {
         })
     }
 
-    pub fn union<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
-    {
+    pub fn interface<'source>(
+    ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
         Formation::sequence([
             Formation::sequence([
                 Formation::predicate(|token: &Token| {
                     if let Some(id) = token.kind.try_unwrap_identifier() {
-                        id.as_str() == Some("union")
+                        id.as_str() == Some("interface")
                     } else {
                         false
                     }
@@ -249,7 +725,7 @@ impl<'a> Parser<'a> {
             let members: Vec<_> = Self::get_body(body.clone())
                 .into_iter()
                 .filter_map(|element| match element.kind {
-                    ElementKind::Symbolize(symbol) => Some(*symbol),
+                    ElementKind::Symbolize(symbol) => Self::method(&name, *symbol),
                     _ => None,
                 })
                 .collect();
@@ -258,7 +734,7 @@ impl<'a> Parser<'a> {
 
             *form = Form::output(Element::new(
                 ElementKind::Symbolize(Box::from(Symbol::new(
-                    SymbolKind::union(Aggregate::new(name, members)),
+                    SymbolKind::interface(Aggregate::new(name, members)),
                     span,
                 ))),
                 span,
@@ -268,6 +744,53 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn method(owner: &Element<'a>, mut symbol: Symbol<'a>) -> Option<Symbol<'a>> {
+        let function = symbol.kind.try_unwrap_function_mut()?;
+        let head = match &mut function.target.kind {
+            ElementKind::Index(index) => &mut index.target,
+            _ => &mut function.target,
+        };
+
+        let span = head.span;
+        let name = Str::from(format!("{}.{}", owner.target()?, head.target()?));
+
+        *head = Element::new(
+            ElementKind::literal(Token::new(TokenKind::identifier(name), span)),
+            span,
+        );
+
+        Some(symbol)
+    }
+
+    fn variant(element: Element<'a>) -> Option<Symbol<'a>> {
+        let span = element.span();
+
+        match element.kind {
+            ElementKind::Literal(_) => Some(Symbol::new(
+                SymbolKind::structure(Aggregate::new(element, Vec::new())),
+                span,
+            )),
+            ElementKind::Invoke(invoke) => {
+                let invoke = *invoke;
+                let members = invoke
+                    .members
+                    .into_iter()
+                    .filter_map(|member| match member.kind {
+                        ElementKind::Symbolize(symbol) => Some(*symbol),
+                        _ => None,
+                    })
+                    .collect();
+
+                Some(Symbol::new(
+                    SymbolKind::structure(Aggregate::new(invoke.target, members)),
+                    span,
+                ))
+            }
+            ElementKind::Symbolize(symbol) if symbol.kind.is_structure() => Some(*symbol),
+            _ => None,
+        }
+    }
+
     pub fn function<'source>(
     ) -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
         Self::alternative([
@@ -279,7 +802,7 @@ impl<'a> Parser<'a> {
                         false
                     }
                 }),
-                Formation::deferred(Self::literal).with_panic(|joint| {
+                Formation::deferred(Self::head).with_panic(|joint| {
                     let (former, formation) = (&mut joint.0, &mut joint.1);
 
                     let consumed = formation
@@ -463,7 +986,7 @@ impl<'a> Parser<'a> {
                         false
                     }
                 }),
-                Formation::deferred(Self::literal),
+                Formation::deferred(Self::head),
                 Self::group(Self::alternative([
                     Formation::deferred(Self::symbolization),
                     Formation::predicate(|token: &Token| {
@@ -577,4 +1100,270 @@ impl<'a> Parser<'a> {
             }),
         ])
     }
+
+    fn path<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::deferred(Self::literal),
+            Formation::repetition(
+                Formation::sequence([
+                    Formation::predicate(|token: &Token| {
+                        matches!(token.kind.try_unwrap_operator(), Some(OperatorKind::Dot))
+                    }),
+                    Formation::deferred(Self::literal),
+                ]),
+                0,
+                None,
+            ),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let operators = form.collect_inputs();
+            let mut segments = form.collect_outputs().into_iter();
+            let mut path = segments.next().unwrap();
+
+            for (operator, segment) in operators.into_iter().zip(segments) {
+                let span = Span::merge(&path.span(), &segment.span());
+                path = Element::new(
+                    ElementKind::binary(Binary::new(path, operator, segment)),
+                    span,
+                );
+            }
+
+            *form = Form::output(path);
+            Ok(())
+        })
+    }
+
+    fn alias<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>> {
+        Formation::sequence([
+            Formation::deferred(Self::literal),
+            Formation::sequence([
+                Formation::predicate(|token: &Token| {
+                    if let Some(id) = token.kind.try_unwrap_identifier() {
+                        id.as_str() == Some("as")
+                    } else {
+                        false
+                    }
+                }),
+                Formation::deferred(Self::literal).with_panic(|joint| {
+                    let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                    let consumed = formation
+                        .consumed
+                        .iter()
+                        .map(|index| former.consumed.get(*index).unwrap().clone())
+                        .collect::<Vec<_>>();
+                    let span = consumed.span();
+
+                    ParseError::new(ErrorKind::ExpectedName, span)
+                }),
+            ])
+            .into_optional(),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let keywords = form.collect_inputs();
+            let mut names = form.collect_outputs().into_iter();
+            let name = names.next().unwrap();
+
+            *form = Form::output(match (keywords.into_iter().next(), names.next()) {
+                (Some(keyword), Some(alias)) => {
+                    let span = Span::merge(&name.span(), &alias.span());
+                    Element::new(ElementKind::binary(Binary::new(name, keyword, alias)), span)
+                }
+                _ => name,
+            });
+
+            Ok(())
+        })
+    }
+
+    pub fn import<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
+    {
+        Formation::sequence([
+            Formation::predicate(|token: &Token| {
+                if let Some(id) = token.kind.try_unwrap_identifier() {
+                    id.as_str() == Some("use")
+                } else {
+                    false
+                }
+            }),
+            Formation::deferred(Self::path).with_panic(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let consumed = formation
+                    .consumed
+                    .iter()
+                    .map(|index| former.consumed.get(*index).unwrap().clone())
+                    .collect::<Vec<_>>();
+                let span = consumed.span();
+
+                ParseError::new(ErrorKind::ExpectedHead, span)
+            }),
+            Self::bundle(Formation::deferred(Self::alias)).into_optional(),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input().clone();
+            let path = sequence[1].unwrap_output().clone();
+            let items = sequence.get(2).map(|form| form.unwrap_output().clone());
+
+            let end = items.as_ref().unwrap_or(&path).span();
+            let span = Span::merge(&keyword.span(), &end);
+            let members = items.map(Self::get_body).unwrap_or_default();
+
+            *form = Form::output(Element::new(
+                ElementKind::Symbolize(Box::from(Symbol::new(
+                    SymbolKind::import(Aggregate::new(path, members)),
+                    span,
+                ))),
+                span,
+            ));
+
+            Ok(())
+        })
+    }
+
+    fn unnamed(element: Element<'a>) -> Symbol<'a> {
+        let span = element.span();
+        let head = Element::new(
+            ElementKind::literal(Token::new(TokenKind::identifier(Str::from("_")), span)),
+            span,
+        );
+
+        Symbol::new(
+            SymbolKind::binding(Binding::new(head, None, Some(element), BindingKind::Let)),
+            span,
+        )
+    }
+
+    pub fn closure<'source>() -> Formation<'a, 'source, Self, Token<'a>, Element<'a>, ParseError<'a>>
+    {
+        Formation::sequence([
+            Formation::predicate(|token: &Token| {
+                if let Some(id) = token.kind.try_unwrap_identifier() {
+                    *id == Str::from("func")
+                } else {
+                    false
+                }
+            }),
+            Self::group(Formation::deferred(Self::element)),
+            Formation::sequence([
+                Formation::predicate(|token: &Token| {
+                    if let Some(OperatorKind::Colon) = token.kind.try_unwrap_operator() {
+                        true
+                    } else {
+                        false
+                    }
+                }),
+                Self::alternative([
+                    Formation::deferred(Self::prefixed),
+                    Formation::deferred(Self::primary),
+                ])
+                .with_panic(|joint| {
+                    let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                    let stack = formation
+                        .stack
+                        .iter()
+                        .map(|index| former.forms.get(*index).unwrap().clone())
+                        .collect::<Vec<_>>();
+                    let span = stack.span();
+
+                    ParseError::new(ErrorKind::ExpectedAnnotation, span)
+                }),
+            ])
+            .into_optional()
+            .with_transform(|joint| {
+                let (former, formation) = (&mut joint.0, &mut joint.1);
+
+                let form = former.forms.get_mut(formation.form).unwrap();
+                let sequence = form.as_forms();
+                let colon = sequence[0].unwrap_input().clone();
+                let annotation = sequence[1].unwrap_output().clone();
+                let span = Span::merge(&colon.span(), &annotation.span());
+
+                *form = Form::output(Element::new(
+                    ElementKind::unary(Unary::new(colon, annotation)),
+                    span,
+                ));
+
+                Ok(())
+            }),
+            Formation::deferred(Self::expression).into_optional(),
+        ])
+        .with_transform(|joint| {
+            let (former, formation) = (&mut joint.0, &mut joint.1);
+
+            let form = former.forms.get_mut(formation.form).unwrap();
+            let sequence = form.as_forms();
+            let keyword = sequence[0].unwrap_input().clone();
+            let group = sequence[1].unwrap_output().clone();
+
+            let mut output = None;
+            let mut body = None;
+
+            for form in &sequence[2..] {
+                let element = form.unwrap_output().clone();
+
+                match element.kind {
+                    ElementKind::Unary(unary)
+                        if matches!(
+                            unary.operator.kind.try_unwrap_operator(),
+                            Some(OperatorKind::Colon)
+                        ) =>
+                    {
+                        output = Some(unary.operand)
+                    }
+                    _ => body = Some(element),
+                }
+            }
+
+            let members = Self::get_body(group.clone())
+                .into_iter()
+                .map(|element| match element.kind {
+                    ElementKind::Symbolize(symbol) => *symbol,
+                    _ => Self::unnamed(element),
+                })
+                .collect();
+
+            let end = body.as_ref().or(output.as_ref()).unwrap_or(&group).span();
+            let span = Span::merge(&keyword.span(), &end);
+
+            let name = Element::new(
+                ElementKind::literal(Token::new(
+                    TokenKind::identifier(Str::from(format!(
+                        "closure.{}.{}",
+                        span.identity, span.start
+                    ))),
+                    keyword.span,
+                )),
+                keyword.span,
+            );
+
+            *form = Form::output(Element::new(
+                ElementKind::Symbolize(Box::from(Symbol::new(
+                    SymbolKind::function(Function::new(
+                        name,
+                        members,
+                        body,
+                        output,
+                        Interface::Axo,
+                        false,
+                        false,
+                    )),
+                    span,
+                ))),
+                span,
+            ));
+            Ok(())
+        })
+    }
 }
@@ -60,7 +60,7 @@ impl<'source> Default for Parser<'source> {
 mod tests {
     use super::{ErrorKind, Parser};
     use crate::{
-        data::Str,
+        data::{AliasKind, Attribute, Str, Visibility},
         parser::ElementKind,
         scanner::{OperatorKind, PunctuationKind, Scanner, TokenKind},
         tracker::Position,
@@ -178,6 +178,18 @@ mod tests {
         assert!(matches!(kind(&parser), ErrorKind::ExpectedBody));
     }
 
+    #[test]
+    fn enumeration_head_missing() {
+        let parser = parse("enum");
+        assert!(matches!(kind(&parser), ErrorKind::ExpectedHead));
+    }
+
+    #[test]
+    fn enumeration_body_missing() {
+        let parser = parse("enum E");
+        assert!(matches!(kind(&parser), ErrorKind::ExpectedBody));
+    }
+
     #[test]
     fn function_name_missing() {
         let parser = parse("func");
@@ -263,12 +275,402 @@ mod tests {
         assert!(matches!(parser.output[0].kind, ElementKind::Symbolize(_)));
     }
 
+    #[test]
+    fn enumeration_ok() {
+        let parser = parse_ok("enum Shape { Circle(let radius: f64), Empty }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let enumeration = symbol.kind.try_unwrap_enumeration().unwrap();
+        assert_eq!(enumeration.members.len(), 2);
+        assert!(enumeration
+            .members
+            .iter()
+            .all(|member| member.kind.is_structure()));
+    }
+
     #[test]
     fn function_ok() {
         let parser = parse_ok("func f(x): i32 { x }");
         assert!(matches!(parser.output[0].kind, ElementKind::Symbolize(_)));
     }
 
+    #[test]
+    fn generic_ok() {
+        let parser = parse_ok("func max[T](let a: T, let b: T): T { a }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert_eq!(symbol.target(), Some(Str::from("max")));
+        assert_eq!(symbol.generics(), vec![Str::from("T")]);
+
+        let parser = parse_ok("struct Pair[K, V] { let key: K; let value: V }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert_eq!(symbol.generics(), vec![Str::from("K"), Str::from("V")]);
+    }
+
+    #[test]
+    fn implementation_ok() {
+        let parser = parse_ok("impl Point { func length(let self: *Point): Int64 { 0 } }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let implementation = symbol.kind.try_unwrap_implementation().unwrap();
+        assert_eq!(symbol.target(), Some(Str::from("Point")));
+        assert_eq!(
+            implementation.members[0].target(),
+            Some(Str::from("Point.length"))
+        );
+    }
+
+    #[test]
+    fn interface_ok() {
+        let parser = parse_ok("interface Shape { func area(let self: *Self): Float64 }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let interface = symbol.kind.try_unwrap_interface().unwrap();
+        assert_eq!(symbol.target(), Some(Str::from("Shape")));
+        assert_eq!(interface.members[0].target(), Some(Str::from("Shape.area")));
+
+        let parser = parse_ok("impl Point: Shape { func area(let self: *Point): Float64 { 0.0 } }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let implementation = symbol.kind.try_unwrap_implementation().unwrap();
+        assert_eq!(symbol.target(), Some(Str::from("Point")));
+        assert!(matches!(implementation.target.kind, ElementKind::Binary(_)));
+
+        let parser = parse_ok("func total[T: Shape & Named](let item: T): Float64 { item.area() }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let parameters = symbol.parameters();
+        assert_eq!(parameters.len(), 1);
+        assert_eq!(parameters[0].0, Str::from("T"));
+        assert!(matches!(
+            parameters[0].1.as_ref().map(|bound| &bound.kind),
+            Some(ElementKind::Binary(_))
+        ));
+    }
+
+    #[test]
+    fn closure_ok() {
+        let parser = parse_ok("let add = func(let x: Int64): Int64 { x + step }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let binding = symbol.kind.try_unwrap_binding().unwrap();
+        let ElementKind::Symbolize(closure) = &binding.value.as_ref().unwrap().kind else {
+            panic!("expected closure");
+        };
+        let function = closure.kind.try_unwrap_function().unwrap();
+        assert!(closure.is_closure());
+        assert_eq!(function.members.len(), 1);
+        assert!(function.output.is_some());
+        assert!(function.body.is_some());
+
+        let parser =
+            parse_ok("func apply(let f: func(Int64): Int64, let x: Int64): Int64 { f(x) }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert!(!symbol.is_closure());
+        let function = symbol.kind.try_unwrap_function().unwrap();
+        let parameter = function.members[0].kind.try_unwrap_binding().unwrap();
+        let ElementKind::Symbolize(annotation) = &parameter.annotation.as_ref().unwrap().kind
+        else {
+            panic!("expected function type");
+        };
+        let signature = annotation.kind.try_unwrap_function().unwrap();
+        assert_eq!(signature.members.len(), 1);
+        assert!(signature.body.is_none());
+    }
+
+    #[test]
+    fn destructure_ok() {
+        let parser = parse_ok("let (a, _) = pair");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let binding = symbol.kind.try_unwrap_binding().unwrap();
+        let binders = binding.target.destructure().unwrap();
+        assert_eq!(binders.len(), 2);
+        assert_eq!(binders[0], (None, Str::from("a")));
+        assert!(symbol.target().is_none());
+        assert!(binding.value.is_some());
+
+        let parser = parse_ok("let Point { x, y = z }: Point = p");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let binding = symbol.kind.try_unwrap_binding().unwrap();
+        let binders = binding.target.destructure().unwrap();
+        assert_eq!(binders[0], (Some(Str::from("x")), Str::from("x")));
+        assert_eq!(binders[1], (Some(Str::from("y")), Str::from("z")));
+        assert!(symbol.target().is_none());
+        assert!(binding.annotation.is_some());
+    }
+
+    #[test]
+    fn import_ok() {
+        let parser = parse_ok("use base.print { print_integer, print_string as show }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let import = symbol.kind.try_unwrap_import().unwrap();
+        assert!(matches!(import.target.kind, ElementKind::Binary(_)));
+        assert_eq!(import.target.target(), Some(Str::from("print")));
+        assert_eq!(import.members.len(), 2);
+        assert_eq!(import.members[0].target(), Some(Str::from("print_integer")));
+        let ElementKind::Binary(alias) = &import.members[1].kind else {
+            panic!("expected alias");
+        };
+        assert_eq!(alias.right.target(), Some(Str::from("show")));
+        assert!(symbol.target().is_none());
+
+        let parser = parse_ok("use file");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert!(symbol.kind.try_unwrap_import().unwrap().members.is_empty());
+    }
+
+    #[test]
+    fn visibility_ok() {
+        let parser = parse_ok("pub func area(let w: f64): f64 { w }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert!(symbol.kind.is_function());
+        assert_eq!(symbol.visibility, Visibility::Public);
+
+        let parser = parse_ok("struct Hidden { let x: i32 }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert_eq!(symbol.visibility, Visibility::Private);
+    }
+
+    #[test]
+    fn constant_ok() {
+        let parser = parse_ok("pub const func square(let x: Int64): Int64 { x * x }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert_eq!(symbol.visibility, Visibility::Public);
+        assert!(symbol.kind.try_unwrap_function().unwrap().constant);
+
+        let parser = parse_ok("func square(let x: Int64): Int64 { x * x }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert!(!symbol.kind.try_unwrap_function().unwrap().constant);
+    }
+
+    #[test]
+    fn attribute_ok() {
+        let parser = parse_ok(
+            "@inline @link_name(\"axo_square\") pub func square(let x: Int64): Int64 { x * x }",
+        );
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert!(symbol.kind.is_function());
+        assert_eq!(symbol.visibility, Visibility::Public);
+        assert_eq!(symbol.attributes.len(), 2);
+        assert!(matches!(
+            symbol.attributes[0].attribute(),
+            Some(Attribute::Inline)
+        ));
+        assert!(matches!(
+            symbol.attributes[1].attribute(),
+            Some(Attribute::Link(name)) if name.as_str() == Some("axo_square")
+        ));
+
+        let parser = parse_ok("@packed struct Point { let x: Int64 }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert_eq!(symbol.attributes.len(), 1);
+        assert!(symbol.attributes[0].attribute().is_none());
+    }
+
+    #[test]
+    fn variadic_ok() {
+        let parser = parse_ok("func sum(Variadic, let values: Int64): Int64 { 0 }");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let function = symbol.kind.try_unwrap_function().unwrap();
+        assert!(function.variadic);
+        assert_eq!(function.members.len(), 1);
+        assert!(symbol.is_variadic());
+
+        let parser = parse_ok("func printf(C, Variadic, let format: String): Int32");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert!(symbol.kind.try_unwrap_function().unwrap().variadic);
+        assert!(!symbol.is_variadic());
+    }
+
+    #[test]
+    fn alias_ok() {
+        let parser = parse_ok("type Offset = UInt64");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        let alias = symbol.kind.try_unwrap_alias().unwrap();
+        assert_eq!(alias.kind, AliasKind::Transparent);
+        assert_eq!(
+            alias.target.target().and_then(|name| name.as_str()),
+            Some("Offset")
+        );
+
+        let parser = parse_ok("pub newtype Descriptor = Int32");
+        let ElementKind::Symbolize(symbol) = &parser.output[0].kind else {
+            panic!("expected symbol");
+        };
+        assert_eq!(symbol.visibility, Visibility::Public);
+        let alias = symbol.kind.try_unwrap_alias().unwrap();
+        assert_eq!(alias.kind, AliasKind::Nominal);
+        assert_eq!(
+            alias.value.target().and_then(|name| name.as_str()),
+            Some("Int32")
+        );
+    }
+
+    #[test]
+    fn for_ok() {
+        let parser = parse_ok("for(i in 0..10, { print(i) })");
+        let ElementKind::Invoke(invoke) = &parser.output[0].kind else {
+            panic!("expected invoke");
+        };
+        assert_eq!(invoke.members.len(), 2);
+        let ElementKind::Binary(iteration) = &invoke.members[0].kind else {
+            panic!("expected iteration");
+        };
+        assert_eq!(
+            iteration
+                .operator
+                .kind
+                .try_unwrap_identifier()
+                .and_then(|identifier| identifier.as_str()),
+            Some("in")
+        );
+        let ElementKind::Binary(range) = &iteration.right.kind else {
+            panic!("expected range");
+        };
+        assert!(matches!(
+            range
+                .operator
+                .kind
+                .try_unwrap_operator()
+                .map(|operator| operator.as_slice()),
+            Some([OperatorKind::Dot, OperatorKind::Dot])
+        ));
+    }
+
+    #[test]
+    fn cast_ok() {
+        let parser = parse_ok("index + 1 as UInt64");
+        let ElementKind::Binary(sum) = &parser.output[0].kind else {
+            panic!("expected binary");
+        };
+        assert!(matches!(
+            sum.operator
+                .kind
+                .try_unwrap_operator()
+                .map(|operator| operator.as_slice()),
+            Some([OperatorKind::Plus])
+        ));
+        let ElementKind::Binary(cast) = &sum.right.kind else {
+            panic!("expected cast");
+        };
+        assert_eq!(
+            cast.operator
+                .kind
+                .try_unwrap_identifier()
+                .and_then(|identifier| identifier.as_str()),
+            Some("as")
+        );
+        assert_eq!(cast.right.target(), Some(Str::from("UInt64")));
+    }
+
+    #[test]
+    fn optional_ok() {
+        let parser = parse_ok("open(path)? + 1");
+        let ElementKind::Binary(sum) = &parser.output[0].kind else {
+            panic!("expected binary");
+        };
+        let ElementKind::Unary(unwrap) = &sum.left.kind else {
+            panic!("expected unary");
+        };
+        assert!(matches!(
+            unwrap
+                .operator
+                .kind
+                .try_unwrap_operator()
+                .map(|operator| operator.as_slice()),
+            Some([OperatorKind::QuestionMark])
+        ));
+        assert!(unwrap.operand.kind.is_invoke());
+    }
+
+    #[test]
+    fn keyword_control_ok() {
+        let parser = parse_ok("if a < b { a } else if a > b { b } else { 0 }");
+        let ElementKind::Invoke(conditional) = &parser.output[0].kind else {
+            panic!("expected invoke");
+        };
+        assert_eq!(conditional.target.target(), Some(Str::from("if")));
+        assert_eq!(conditional.members.len(), 3);
+        assert!(matches!(
+            conditional.members[0].kind,
+            ElementKind::Binary(_)
+        ));
+        let ElementKind::Invoke(chained) = &conditional.members[2].kind else {
+            panic!("expected chained conditional");
+        };
+        assert_eq!(chained.target.target(), Some(Str::from("if")));
+        assert_eq!(chained.members.len(), 3);
+
+        let parser = parse_ok("while count < limit { count = count + 1 }");
+        let ElementKind::Invoke(repetition) = &parser.output[0].kind else {
+            panic!("expected invoke");
+        };
+        assert_eq!(repetition.target.target(), Some(Str::from("while")));
+        assert_eq!(repetition.members.len(), 2);
+        assert!(matches!(repetition.members[0].kind, ElementKind::Binary(_)));
+    }
+
+    #[test]
+    fn label_ok() {
+        let parser = parse_ok("outer: while running { while busy { break(outer, 1) } }");
+        let ElementKind::Binary(labeled) = &parser.output[0].kind else {
+            panic!("expected labeled loop");
+        };
+        assert_eq!(labeled.left.target(), Some(Str::from("outer")));
+        let ElementKind::Invoke(repetition) = &labeled.right.kind else {
+            panic!("expected invoke");
+        };
+        assert_eq!(repetition.target.target(), Some(Str::from("while")));
+    }
+
+    #[test]
+    fn defer_ok() {
+        let parser = parse_ok("defer { close(file) }");
+        let ElementKind::Invoke(cleanup) = &parser.output[0].kind else {
+            panic!("expected invoke");
+        };
+        assert_eq!(cleanup.target.target(), Some(Str::from("defer")));
+        assert_eq!(cleanup.members.len(), 1);
+        assert!(matches!(cleanup.members[0].kind, ElementKind::Delimited(_)));
+    }
+
     #[test]
     fn ignores_comment_and_whitespace() {
         let parser = parse_ok("1 // comment\n + 2");
@@ -284,7 +686,50 @@ mod tests {
             "let b: i32 = 2",
             "struct A { let x: i32; let y: i32 }",
             "union U { let i: i32; let f: f64 }",
+            "enum E { A(let x: i32), B }",
+            "match(e, { A(x) => x, B => 0 })",
             "func add(x, y): i32 { x + y }",
+            "func max[T](let a: T, let b: T): T { a }",
+            "struct Pair[T] { let first: T; let second: T }",
+            "Pair[i32] { first = 1, second = 2 }",
+            "impl A { func get(let self: A): i32 { self.x } }",
+            "a.get(1).set(2)",
+            "interface Shape { func area(let self: Self): f64 }",
+            "impl A: Shape { func area(let self: A): f64 { 0.0 } }",
+            "func sum[T: Shape | Named](let item: T): f64 { item.area() }",
+            "let inc = func(let x: i32): i32 { x + 1 }",
+            "apply(func(let x: i32) x * 2, 3)",
+            "let (a, b) = pair",
+            "let Point { x, y = z } = p",
+            "use base.print { print_integer, print_string as show }",
+            "pub let limit = 10",
+            "pub struct A { let x: i32 }",
+            "let r = 1..=5",
+            "for(item in items, { total = total + item })",
+            "if ready { go() }",
+            "let sign = if x < 0 { -1 } else if x > 0 { 1 } else { 0 }",
+            "while !done { done = step() }",
+            "while(count < 10, { count = count + 1 })",
+            "outer: while(row < rows, { while(column < columns, { continue(outer) }) })",
+            "let found = search: for(item in items, { if item == target { break(search, item) } })",
+            "println(\"sum = {a + b}, ok = {ok}\")",
+            "let result = asm(\"syscall\", \"={rax},{rax},{rdi},~{rcx},~{r11},~{memory}\", 60, code)",
+            "static table = [square(1), square(2), square(3)]",
+            "const func square(let x: i32): i32 { x * x }",
+            "func total(let base: Int64, Variadic, let values: Int64): Int64 { base + values[0] }",
+            "@cold func fail(let code: Int64) { exit(code) }",
+            "@export @section(\".text.hot\") func handler(): Int64 { 0 }",
+            "type Offset = UInt64",
+            "newtype Descriptor = Int32",
+            "let byte = 'a' as UInt8",
+            "let address = pointer as Int64 + offset as Int64",
+            "let entry: Int64? = none()",
+            "func first(let items: Optional[*UInt8]): Int64? { some(count(items?)) }",
+            "func parse(let text: String): Result[Int64, String] { if empty(text) { return(err(text)) }; ok(1) }",
+            "let total = parse(text)? + parse(other)?",
+            "{ let file = open(path); defer { close(file) }; read(file) }",
+            "impl Complex { func add(let self: Complex, let other: Complex): Complex { Complex { real = self.real + other.real, imaginary = self.imaginary + other.imaginary } } }",
+            "let sum = first + second * third == expected",
             "a(1, 2)[0]{3,4}",
             "{ let x = 1; let y = x + 2; y }",
         ];
@@ -298,7 +743,8 @@ mod tests {
     #[test]
     fn invalid_corpus_has_parse_errors() {
         let corpus = [
-            "(", "[1,2", "{1,2", "func", "func f(", "struct", "union", "let",
+            "(", "[1,2", "{1,2", "func", "func f(", "struct", "union", "impl", "let", "const",
+            "newtype", "@inline",
         ];
 
         for source in corpus {
@@ -3,7 +3,7 @@ use crate::{
     internal::hash::{Hash, Set},
     parser::{Element, ElementKind},
     resolver::{next_identity, scope::Scope, Type, TypeKind},
-    scanner::{OperatorKind, TokenKind},
+    scanner::{OperatorKind, PunctuationKind, TokenKind},
     tracker::Span,
 };
 
@@ -13,13 +13,19 @@ pub struct Symbol<'symbol> {
     pub span: Span,
     pub scope: Box<Scope>,
     pub typing: Type<'symbol>,
+    pub visibility: Visibility,
+    pub attributes: Vec<Element<'symbol>>,
 }
 
 #[derive(Clone, Hash, PartialEq)]
 pub enum SymbolKind<'symbol> {
     Binding(Box<Binding<Element<'symbol>, Element<'symbol>, Option<Element<'symbol>>>>),
+    Alias(Box<Alias<Element<'symbol>, Element<'symbol>>>),
     Structure(Box<Aggregate<Element<'symbol>, Symbol<'symbol>>>),
     Union(Box<Aggregate<Element<'symbol>, Symbol<'symbol>>>),
+    Enumeration(Box<Aggregate<Element<'symbol>, Symbol<'symbol>>>),
+    Implementation(Box<Aggregate<Element<'symbol>, Symbol<'symbol>>>),
+    Interface(Box<Aggregate<Element<'symbol>, Symbol<'symbol>>>),
     Function(
         Box<
             Function<
@@ -31,6 +37,7 @@ pub enum SymbolKind<'symbol> {
         >,
     ),
     Module(Box<Module<Element<'symbol>>>),
+    Import(Box<Aggregate<Element<'symbol>, Element<'symbol>>>),
 }
 
 impl<'symbol> Symbol<'symbol> {
@@ -41,6 +48,8 @@ impl<'symbol> Symbol<'symbol> {
             span,
             scope: Box::from(Scope::new(None)),
             typing: Type::from(TypeKind::Unknown),
+            visibility: Visibility::Private,
+            attributes: Vec::new(),
         }
     }
 
@@ -75,12 +84,90 @@ impl<'symbol> Symbol<'symbol> {
 
     pub fn target(&self) -> Option<Str<'symbol>> {
         match &self.kind {
-            SymbolKind::Binding(binding) => binding.target.target(),
+            SymbolKind::Binding(binding) => match binding.target.destructure() {
+                Some(_) => None,
+                None => binding.target.target(),
+            },
+            SymbolKind::Alias(alias) => alias.target.target(),
             SymbolKind::Structure(structure) => structure.target.target(),
             SymbolKind::Union(union) => union.target.target(),
+            SymbolKind::Enumeration(enumeration) => enumeration.target.target(),
+            SymbolKind::Implementation(implementation) => implementation.target.target(),
+            SymbolKind::Interface(interface) => interface.target.target(),
             SymbolKind::Function(function) => function.target.target(),
             SymbolKind::Module(module) => module.target.target(),
+            SymbolKind::Import(_) => None,
+        }
+    }
+
+    pub fn generics(&self) -> Vec<Str<'symbol>> {
+        self.parameters()
+            .into_iter()
+            .map(|(parameter, _)| parameter)
+            .collect()
+    }
+
+    pub fn parameters(&self) -> Vec<(Str<'symbol>, Option<Element<'symbol>>)> {
+        let head = match &self.kind {
+            SymbolKind::Structure(structure) => &structure.target,
+            SymbolKind::Function(function) => &function.target,
+            _ => return Vec::new(),
+        };
+
+        match &head.kind {
+            ElementKind::Index(index) => index.members.iter().filter_map(Self::parameter).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn parameter(element: &Element<'symbol>) -> Option<(Str<'symbol>, Option<Element<'symbol>>)> {
+        let ElementKind::Binary(binary) = &element.kind else {
+            return Some((element.target()?, None));
+        };
+
+        if matches!(&binary.operator.kind, TokenKind::Operator(operator) if **operator == OperatorKind::Colon)
+        {
+            return Some((binary.left.target()?, Some(binary.right.clone())));
         }
+
+        let (parameter, bound) = Self::parameter(&binary.left)?;
+        let bound = bound?;
+        let span = Span::merge(&bound.span, &binary.right.span);
+
+        Some((
+            parameter,
+            Some(Element::new(
+                ElementKind::binary(Binary::new(
+                    bound,
+                    binary.operator.clone(),
+                    binary.right.clone(),
+                )),
+                span,
+            )),
+        ))
+    }
+
+    #[inline(always)]
+    pub fn is_generic(&self) -> bool {
+        !self.generics().is_empty()
+    }
+
+    #[inline(always)]
+    pub fn is_variadic(&self) -> bool {
+        self.kind.try_unwrap_function().is_some_and(|function| {
+            function.variadic
+                && function.interface == Interface::Axo
+                && !function.members.is_empty()
+        })
+    }
+
+    #[inline(always)]
+    pub fn is_closure(&self) -> bool {
+        self.kind.is_function()
+            && self
+                .target()
+                .and_then(|target| target.as_str())
+                .is_some_and(|target| target.starts_with("closure."))
     }
 }
 
@@ -92,6 +179,11 @@ impl<'symbol> SymbolKind<'symbol> {
         Self::Binding(Box::new(binding))
     }
 
+    #[inline]
+    pub fn alias(alias: Alias<Element<'symbol>, Element<'symbol>>) -> Self {
+        Self::Alias(Box::new(alias))
+    }
+
     #[inline]
     pub fn structure(structure: Aggregate<Element<'symbol>, Symbol<'symbol>>) -> Self {
         Self::Structure(Box::new(structure))
@@ -102,6 +194,21 @@ impl<'symbol> SymbolKind<'symbol> {
         Self::Union(Box::new(union))
     }
 
+    #[inline]
+    pub fn enumeration(enumeration: Aggregate<Element<'symbol>, Symbol<'symbol>>) -> Self {
+        Self::Enumeration(Box::new(enumeration))
+    }
+
+    #[inline]
+    pub fn implementation(implementation: Aggregate<Element<'symbol>, Symbol<'symbol>>) -> Self {
+        Self::Implementation(Box::new(implementation))
+    }
+
+    #[inline]
+    pub fn interface(interface: Aggregate<Element<'symbol>, Symbol<'symbol>>) -> Self {
+        Self::Interface(Box::new(interface))
+    }
+
     #[inline]
     pub fn function(
         function: Function<
@@ -119,11 +226,21 @@ impl<'symbol> SymbolKind<'symbol> {
         Self::Module(Box::new(module))
     }
 
+    #[inline]
+    pub fn import(import: Aggregate<Element<'symbol>, Element<'symbol>>) -> Self {
+        Self::Import(Box::new(import))
+    }
+
     #[inline(always)]
     pub fn is_binding(&self) -> bool {
         matches!(self, Self::Binding(_))
     }
 
+    #[inline(always)]
+    pub fn is_alias(&self) -> bool {
+        matches!(self, Self::Alias(_))
+    }
+
     #[inline(always)]
     pub fn is_structure(&self) -> bool {
         matches!(self, Self::Structure(_))
@@ -134,6 +251,21 @@ impl<'symbol> SymbolKind<'symbol> {
         matches!(self, Self::Union(_))
     }
 
+    #[inline(always)]
+    pub fn is_enumeration(&self) -> bool {
+        matches!(self, Self::Enumeration(_))
+    }
+
+    #[inline(always)]
+    pub fn is_implementation(&self) -> bool {
+        matches!(self, Self::Implementation(_))
+    }
+
+    #[inline(always)]
+    pub fn is_interface(&self) -> bool {
+        matches!(self, Self::Interface(_))
+    }
+
     #[inline(always)]
     pub fn is_function(&self) -> bool {
         matches!(self, Self::Function(_))
@@ -144,6 +276,11 @@ impl<'symbol> SymbolKind<'symbol> {
         matches!(self, Self::Module(_))
     }
 
+    #[inline(always)]
+    pub fn is_import(&self) -> bool {
+        matches!(self, Self::Import(_))
+    }
+
     #[inline]
     #[track_caller]
     pub fn unwrap_binding(
@@ -155,6 +292,15 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_alias(self) -> Alias<Element<'symbol>, Element<'symbol>> {
+        match self {
+            Self::Alias(alias) => *alias,
+            _ => panic!("expected alias"),
+        }
+    }
+
     #[inline]
     #[track_caller]
     pub fn unwrap_structure(self) -> Aggregate<Element<'symbol>, Symbol<'symbol>> {
@@ -173,6 +319,33 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_enumeration(self) -> Aggregate<Element<'symbol>, Symbol<'symbol>> {
+        match self {
+            Self::Enumeration(enumeration) => *enumeration,
+            _ => panic!("expected enumeration"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_implementation(self) -> Aggregate<Element<'symbol>, Symbol<'symbol>> {
+        match self {
+            Self::Implementation(implementation) => *implementation,
+            _ => panic!("expected implementation"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_interface(self) -> Aggregate<Element<'symbol>, Symbol<'symbol>> {
+        match self {
+            Self::Interface(interface) => *interface,
+            _ => panic!("expected interface"),
+        }
+    }
+
     #[inline]
     #[track_caller]
     pub fn unwrap_function(
@@ -198,6 +371,15 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_import(self) -> Aggregate<Element<'symbol>, Element<'symbol>> {
+        match self {
+            Self::Import(import) => *import,
+            _ => panic!("expected import"),
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_binding(
         &self,
@@ -208,6 +390,14 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_alias(&self) -> Option<&Alias<Element<'symbol>, Element<'symbol>>> {
+        match self {
+            Self::Alias(alias) => Some(alias),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_structure(&self) -> Option<&Aggregate<Element<'symbol>, Symbol<'symbol>>> {
         match self {
@@ -224,6 +414,32 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_enumeration(&self) -> Option<&Aggregate<Element<'symbol>, Symbol<'symbol>>> {
+        match self {
+            Self::Enumeration(enumeration) => Some(enumeration),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_implementation(
+        &self,
+    ) -> Option<&Aggregate<Element<'symbol>, Symbol<'symbol>>> {
+        match self {
+            Self::Implementation(implementation) => Some(implementation),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_interface(&self) -> Option<&Aggregate<Element<'symbol>, Symbol<'symbol>>> {
+        match self {
+            Self::Interface(interface) => Some(interface),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_function(
         &self,
@@ -249,6 +465,14 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_import(&self) -> Option<&Aggregate<Element<'symbol>, Element<'symbol>>> {
+        match self {
+            Self::Import(import) => Some(import),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_binding_mut(
         &mut self,
@@ -259,6 +483,16 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_alias_mut(
+        &mut self,
+    ) -> Option<&mut Alias<Element<'symbol>, Element<'symbol>>> {
+        match self {
+            Self::Alias(alias) => Some(alias),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_structure_mut(
         &mut self,
@@ -279,6 +513,36 @@ impl<'symbol> SymbolKind<'symbol> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_enumeration_mut(
+        &mut self,
+    ) -> Option<&mut Aggregate<Element<'symbol>, Symbol<'symbol>>> {
+        match self {
+            Self::Enumeration(enumeration) => Some(enumeration),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_implementation_mut(
+        &mut self,
+    ) -> Option<&mut Aggregate<Element<'symbol>, Symbol<'symbol>>> {
+        match self {
+            Self::Implementation(implementation) => Some(implementation),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_interface_mut(
+        &mut self,
+    ) -> Option<&mut Aggregate<Element<'symbol>, Symbol<'symbol>>> {
+        match self {
+            Self::Interface(interface) => Some(interface),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_function_mut(
         &mut self,
@@ -303,6 +567,16 @@ impl<'symbol> SymbolKind<'symbol> {
             _ => None,
         }
     }
+
+    #[inline(always)]
+    pub fn try_unwrap_import_mut(
+        &mut self,
+    ) -> Option<&mut Aggregate<Element<'symbol>, Element<'symbol>>> {
+        match self {
+            Self::Import(import) => Some(import),
+            _ => None,
+        }
+    }
 }
 
 impl<'symbol> Element<'symbol> {
@@ -333,4 +607,112 @@ impl<'symbol> Element<'symbol> {
             _ => None,
         }
     }
+
+    pub fn text(&self) -> Option<Str<'symbol>> {
+        match &self.kind {
+            ElementKind::Literal(literal) => literal.kind.try_unwrap_string().copied(),
+            _ => None,
+        }
+    }
+
+    pub fn attribute(&self) -> Option<Attribute<Str<'symbol>>> {
+        match &self.kind {
+            ElementKind::Literal(_) => match self.target()?.as_str()? {
+                "inline" => Some(Attribute::Inline),
+                "cold" => Some(Attribute::Cold),
+                "export" => Some(Attribute::Export),
+                _ => None,
+            },
+            ElementKind::Invoke(invoke) => {
+                let [value] = invoke.members.as_slice() else {
+                    return None;
+                };
+
+                let value = value.text()?;
+
+                match invoke.target.target()?.as_str()? {
+                    "link_name" => Some(Attribute::Link(value)),
+                    "section" => Some(Attribute::Section(value)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    pub fn pattern(&self) -> Option<(Str<'symbol>, Vec<Str<'symbol>>)> {
+        match &self.kind {
+            ElementKind::Literal(_) => Some((self.target()?, Vec::new())),
+            ElementKind::Invoke(invoke) => Some((
+                invoke.target.target()?,
+                invoke
+                    .members
+                    .iter()
+                    .map(|member| member.target().unwrap_or_default())
+                    .collect(),
+            )),
+            ElementKind::Binary(binary) => match &binary.operator.kind {
+                TokenKind::Operator(operator) if **operator == OperatorKind::Dot => {
+                    binary.right.pattern()
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn arm(&self) -> Option<(Str<'symbol>, Vec<Str<'symbol>>)> {
+        match &self.kind {
+            ElementKind::Binary(binary)
+                if matches!(
+                    &binary.operator.kind,
+                    TokenKind::Operator(operator)
+                        if operator.as_slice() == [OperatorKind::Equal, OperatorKind::RightAngle]
+                ) =>
+            {
+                binary.left.pattern()
+            }
+            _ => None,
+        }
+    }
+
+    pub fn destructure(&self) -> Option<Vec<(Option<Str<'symbol>>, Str<'symbol>)>> {
+        match &self.kind {
+            ElementKind::Delimited(delimited)
+                if matches!(
+                    delimited.start.kind,
+                    TokenKind::Punctuation(PunctuationKind::LeftParenthesis)
+                ) && (delimited.separator.is_some() || delimited.members.len() != 1) =>
+            {
+                delimited
+                    .members
+                    .iter()
+                    .map(|member| match &member.kind {
+                        ElementKind::Literal(_) => Some((None, member.target()?)),
+                        _ => None,
+                    })
+                    .collect()
+            }
+            ElementKind::Construct(construct) => construct
+                .members
+                .iter()
+                .map(|member| match &member.kind {
+                    ElementKind::Binary(binary)
+                        if matches!(
+                            &binary.operator.kind,
+                            TokenKind::Operator(operator) if **operator == OperatorKind::Equal
+                        ) =>
+                    {
+                        Some((Some(binary.left.target()?), binary.right.target()?))
+                    }
+                    ElementKind::Literal(_) => {
+                        let name = member.target()?;
+                        Some((Some(name), name))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
 }
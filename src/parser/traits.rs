@@ -142,6 +142,8 @@ impl<'symbol> Clone for Symbol<'symbol> {
             span: self.span.clone(),
             scope: self.scope.clone(),
             typing: self.typing.clone(),
+            visibility: self.visibility,
+            attributes: self.attributes.clone(),
         }
     }
 }
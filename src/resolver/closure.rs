@@ -0,0 +1,96 @@
+use crate::{
+    data::Identity,
+    parser::{Element, ElementKind, Symbol, SymbolKind},
+    resolver::Resolver,
+    scanner::TokenKind,
+};
+
+impl<'a> Element<'a> {
+    fn references(&self, identities: &mut Vec<Identity>) {
+        match &self.kind {
+            ElementKind::Literal(token) => {
+                if let (TokenKind::Identifier(_), Some(reference)) = (&token.kind, self.reference) {
+                    if !identities.contains(&reference) {
+                        identities.push(reference);
+                    }
+                }
+            }
+            ElementKind::Delimited(delimited) => delimited
+                .members
+                .iter()
+                .for_each(|member| member.references(identities)),
+            ElementKind::Unary(unary) => unary.operand.references(identities),
+            ElementKind::Binary(binary) => {
+                binary.left.references(identities);
+                binary.right.references(identities);
+            }
+            ElementKind::Index(index) => {
+                index.target.references(identities);
+                index
+                    .members
+                    .iter()
+                    .for_each(|member| member.references(identities));
+            }
+            ElementKind::Invoke(invoke) => {
+                invoke.target.references(identities);
+                invoke
+                    .members
+                    .iter()
+                    .for_each(|member| member.references(identities));
+            }
+            ElementKind::Construct(construct) => {
+                construct.target.references(identities);
+                construct
+                    .members
+                    .iter()
+                    .for_each(|member| member.references(identities));
+            }
+            ElementKind::Symbolize(symbol) => symbol.references(identities),
+        }
+    }
+}
+
+impl<'a> Symbol<'a> {
+    fn references(&self, identities: &mut Vec<Identity>) {
+        match &self.kind {
+            SymbolKind::Binding(binding) => binding
+                .value
+                .iter()
+                .for_each(|value| value.references(identities)),
+            SymbolKind::Function(function) => function
+                .body
+                .iter()
+                .for_each(|body| body.references(identities)),
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Resolver<'a> {
+    pub fn capture(&mut self, closure: &Symbol<'a>) {
+        let mut references = Vec::new();
+        closure.references(&mut references);
+
+        let captures = references
+            .into_iter()
+            .filter(|identity| {
+                self.owner(*identity).is_some()
+                    && self
+                        .get_symbol(*identity)
+                        .is_some_and(|symbol| symbol.kind.is_binding())
+            })
+            .collect();
+
+        self.captures.insert(closure.identity, captures);
+    }
+
+    pub fn captured(&self, closure: &Symbol<'a>) -> Vec<Symbol<'a>> {
+        self.captures
+            .get(&closure.identity)
+            .into_iter()
+            .flatten()
+            .filter_map(|identity| self.get_symbol(*identity))
+            .cloned()
+            .collect()
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    data::{Binding, BindingKind, Delimited, Function, Interface, Scale, Str},
-    parser::{Element, ElementKind, SymbolKind},
+    data::{Binding, BindingKind, Delimited, Function, Interface, Scale, Str, Unary},
+    parser::{Element, ElementKind, Symbol, SymbolKind},
     resolver::{Error, ErrorKind, Resolvable, Resolver, Type, TypeKind},
     scanner::{OperatorKind, PunctuationKind, Token, TokenKind},
     tracker::Spanned,
@@ -256,6 +256,7 @@ impl<'a> Element<'a> {
                         )
                     }
                 }
+                [OperatorKind::QuestionMark] => Self::propagate(resolver, unary),
                 [OperatorKind::Star] => {
                     let target = resolver.fresh();
                     let pointer = Type::new(
@@ -282,6 +283,83 @@ impl<'a> Element<'a> {
         }
     }
 
+    fn propagate(
+        resolver: &mut Resolver<'a>,
+        unary: &mut crate::data::Unary<Token<'a>, Element<'a>>,
+    ) -> Type<'a> {
+        if Self::nominal(resolver, &unary.operand) {
+            return Type::from(TypeKind::Type);
+        }
+
+        let member = resolver.fresh();
+
+        let (wrapper, output) = if resolver.reify(&unary.operand.typing).kind.is_result() {
+            let error = resolver.fresh();
+
+            (
+                Type::from(TypeKind::Result {
+                    value: Box::new(member.clone()),
+                    error: Box::new(error.clone()),
+                }),
+                Type::from(TypeKind::Result {
+                    value: Box::new(resolver.fresh()),
+                    error: Box::new(error),
+                }),
+            )
+        } else {
+            (
+                Type::from(TypeKind::Optional {
+                    member: Box::new(member.clone()),
+                }),
+                Type::from(TypeKind::Optional {
+                    member: Box::new(resolver.fresh()),
+                }),
+            )
+        };
+
+        resolver.unify(unary.operand.span, &wrapper, &unary.operand.typing);
+
+        match resolver.returns.last().cloned() {
+            Some(expect) => {
+                resolver.unify(unary.operand.span, &output, &expect);
+                member
+            }
+            None => Self::invalid_unary(
+                resolver,
+                unary.operator.clone(),
+                unary.operand.typing.clone(),
+            ),
+        }
+    }
+
+    fn nominal(resolver: &Resolver<'a>, element: &Element<'a>) -> bool {
+        match &element.kind {
+            ElementKind::Literal(_) => {
+                let primitive = Resolver::builtin(element).map_or(false, |symbol| {
+                    symbol
+                        .kind
+                        .try_unwrap_function()
+                        .map_or(false, |function| function.output.is_some())
+                });
+
+                primitive
+                    || element
+                        .reference
+                        .and_then(|reference| resolver.get_symbol(reference))
+                        .map_or(false, |symbol| {
+                            matches!(
+                                symbol.kind,
+                                SymbolKind::Structure(_)
+                                    | SymbolKind::Union(_)
+                                    | SymbolKind::Enumeration(_)
+                                    | SymbolKind::Alias(_)
+                            )
+                        })
+            }
+            _ => element.typing.kind.is_type(),
+        }
+    }
+
     fn access(
         resolver: &mut Resolver<'a>,
         reference: &mut Option<crate::data::Identity>,
@@ -295,11 +373,32 @@ impl<'a> Element<'a> {
             typing = resolver.reify(&typing.kind.unwrap_pointer());
         }
 
-        let scope = left
+        let owner = left
             .reference
             .and_then(|reference| resolver.get_symbol(reference).cloned())
-            .filter(|symbol| !symbol.is_instance())
-            .map(|symbol| symbol.scope)
+            .filter(|symbol| !symbol.is_instance());
+
+        if let Some(method) = owner
+            .as_ref()
+            .and_then(|symbol| Self::method(resolver, symbol, right))
+        {
+            return Self::call(resolver, reference, None, right, method);
+        }
+
+        if typing.kind.is_structure() || typing.kind.is_union() || typing.kind.is_enumeration() {
+            if let Some(method) = resolver
+                .get_symbol(typing.identity)
+                .and_then(|symbol| Self::method(resolver, symbol, right))
+                .filter(Self::receiver)
+            {
+                let receiver = Self::borrow(resolver, left, &method);
+                return Self::call(resolver, reference, Some(receiver), right, method);
+            }
+        }
+
+        let scope = owner
+            .as_ref()
+            .map(|symbol| symbol.scope.clone())
             .or_else(|| {
                 typing
                     .kind
@@ -318,6 +417,11 @@ impl<'a> Element<'a> {
                 right.resolve(resolver);
             });
             *reference = right.reference;
+
+            if let Some(symbol) = owner.filter(|symbol| symbol.kind.is_enumeration()) {
+                return symbol.typing;
+            }
+
             return right.typing.clone();
         }
 
@@ -405,6 +509,268 @@ impl<'a> Element<'a> {
         }
     }
 
+    fn range(
+        resolver: &mut Resolver<'a>,
+        operator: Token<'a>,
+        left: Type<'a>,
+        right: Type<'a>,
+        span: crate::tracker::Span,
+    ) -> Type<'a> {
+        let valid = |kind: &TypeKind| kind.is_integer() || kind.is_variable() || kind.is_unknown();
+
+        if !valid(&left.kind) || !valid(&right.kind) {
+            return Self::invalid_binary(resolver, operator, left, right, span);
+        }
+
+        let bound = resolver.unify(span, &left, &right);
+
+        Type::from(TypeKind::Tuple {
+            members: Box::new(vec![bound.clone(), bound]),
+        })
+    }
+
+    pub fn iterated(typing: &Type<'a>) -> Option<Type<'a>> {
+        match &typing.kind {
+            TypeKind::Array { member, .. } => Some(*member.clone()),
+            TypeKind::Tuple { members }
+                if members.len() == 2
+                    && members
+                        .iter()
+                        .all(|member| member.kind.is_integer() || member.kind.is_variable()) =>
+            {
+                Some(members[0].clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn iteration(
+        element: &mut Element<'a>,
+    ) -> Option<&mut crate::data::Binary<Element<'a>, Token<'a>, Element<'a>>> {
+        match &mut element.kind {
+            ElementKind::Binary(binary)
+                if binary
+                    .operator
+                    .kind
+                    .try_unwrap_identifier()
+                    .is_some_and(|identifier| identifier.as_str() == Some("in")) =>
+            {
+                Some(binary)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn label(
+        binary: &crate::data::Binary<Element<'a>, Token<'a>, Element<'a>>,
+    ) -> Option<Str<'a>> {
+        let colon = matches!(
+            binary.operator.kind.try_unwrap_operator(),
+            Some(OperatorKind::Colon)
+        );
+
+        let repeats = binary
+            .right
+            .kind
+            .try_unwrap_invoke()
+            .and_then(|invoke| invoke.target.target())
+            .is_some_and(|name| matches!(name.as_str(), Some("while" | "for")));
+
+        if colon && repeats && binary.left.kind.is_literal() {
+            binary.left.target()
+        } else {
+            None
+        }
+    }
+
+    fn r#for(
+        resolver: &mut Resolver<'a>,
+        invoke: &mut crate::data::Invoke<Element<'a>, Element<'a>>,
+    ) -> Type<'a> {
+        let label = resolver.label.take();
+
+        if invoke.members.len() < 2 {
+            return Type::from(TypeKind::Void);
+        }
+
+        let (head, body) = invoke.members.split_at_mut(1);
+        let (head, body) = (&mut head[0], &mut body[0]);
+
+        let pattern = head.clone();
+        let Some(binary) = Self::iteration(head) else {
+            resolver
+                .errors
+                .push(Error::new(ErrorKind::InvalidPattern(pattern), head.span));
+            return Type::from(TypeKind::Void);
+        };
+
+        binary.right.resolve(resolver);
+        let iterable = resolver.reify(&binary.right.typing);
+
+        let item = Self::iterated(&iterable).unwrap_or_else(|| {
+            resolver.errors.push(Error::new(
+                ErrorKind::Uniterable(iterable),
+                binary.right.span,
+            ));
+            resolver.fresh()
+        });
+
+        let (yielded, _) = resolver.nest(|resolver| {
+            if let Some(name) = binary
+                .left
+                .target()
+                .filter(|_| binary.left.kind.is_literal())
+            {
+                let mut symbol = Symbol::new(
+                    SymbolKind::binding(Binding::new(
+                        binary.left.clone(),
+                        None,
+                        None,
+                        BindingKind::Let,
+                    )),
+                    binary.left.span,
+                );
+                symbol.typing = Self::binding(item.clone(), name);
+
+                binary.left.reference = Some(symbol.identity);
+                binary.left.typing = item;
+
+                resolver.insert(symbol);
+            } else {
+                resolver.errors.push(Error::new(
+                    ErrorKind::InvalidPattern(*binary.left.clone()),
+                    binary.left.span,
+                ));
+            }
+
+            resolver.label = label;
+            let (_, _, yielded) = resolver.looping(|resolver| body.resolve(resolver));
+            yielded
+        });
+
+        yielded.unwrap_or_else(|| Type::from(TypeKind::Void))
+    }
+
+    fn printable(
+        resolver: &mut Resolver<'a>,
+        invoke: &mut crate::data::Invoke<Element<'a>, Element<'a>>,
+    ) {
+        for member in invoke.members.iter_mut() {
+            member.resolve(resolver);
+            let typing = resolver.reify(&member.typing);
+
+            let valid = matches!(
+                typing.kind,
+                TypeKind::Integer { .. }
+                    | TypeKind::Float { .. }
+                    | TypeKind::Boolean
+                    | TypeKind::String
+                    | TypeKind::Character
+                    | TypeKind::Variable(_)
+            );
+
+            if !valid {
+                resolver
+                    .errors
+                    .push(Error::new(ErrorKind::Unformattable(typing), member.span));
+            }
+        }
+    }
+
+    fn assembly(
+        resolver: &mut Resolver<'a>,
+        span: crate::tracker::Span,
+        invoke: &mut crate::data::Invoke<Element<'a>, Element<'a>>,
+    ) -> Type<'a> {
+        for member in invoke.members.iter_mut() {
+            member.resolve(resolver);
+        }
+
+        let [template, constraints, operands @ ..] = invoke.members.as_slice() else {
+            resolver
+                .errors
+                .push(Error::new(ErrorKind::IncompleteAssembly, span));
+            return Type::from(TypeKind::Unknown);
+        };
+
+        for element in [template, constraints] {
+            if element.text().is_none() {
+                resolver.errors.push(Error::new(
+                    ErrorKind::InvalidAssembly(element.clone()),
+                    element.span,
+                ));
+            }
+        }
+
+        let codes = constraints
+            .text()
+            .and_then(|constraints| constraints.as_str())
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            .collect::<Vec<_>>();
+
+        let written = |code: &str| code.starts_with('=') && !code.starts_with("=*");
+
+        let inputs = codes
+            .iter()
+            .filter(|&&code| !code.starts_with('~') && !written(code))
+            .count();
+
+        if constraints.text().is_some() && inputs != operands.len() {
+            resolver.errors.push(Error::new(
+                ErrorKind::AssemblyOperands {
+                    expected: inputs,
+                    found: operands.len(),
+                },
+                span,
+            ));
+        }
+
+        for operand in operands {
+            let typing = resolver.reify(&operand.typing);
+
+            let valid = matches!(
+                typing.kind,
+                TypeKind::Integer { .. }
+                    | TypeKind::Float { .. }
+                    | TypeKind::Boolean
+                    | TypeKind::Character
+                    | TypeKind::String
+                    | TypeKind::Pointer { .. }
+                    | TypeKind::Variable(_)
+            );
+
+            if !valid {
+                resolver
+                    .errors
+                    .push(Error::new(ErrorKind::Unassemblable(typing), operand.span));
+            }
+        }
+
+        let mut operands = operands.iter();
+        let mut outputs = Vec::new();
+
+        for &code in codes.iter().filter(|code| !code.starts_with('~')) {
+            if written(code) {
+                outputs.push(resolver.fresh());
+            } else if let Some(operand) = operands.next() {
+                if code.starts_with('+') {
+                    outputs.push(operand.typing.clone());
+                }
+            }
+        }
+
+        match outputs.len() {
+            0 => Type::from(TypeKind::Void),
+            1 => outputs.remove(0),
+            _ => Type::from(TypeKind::Tuple {
+                members: Box::new(outputs),
+            }),
+        }
+    }
+
     fn compare(
         resolver: &mut Resolver<'a>,
         operator: Token<'a>,
@@ -434,11 +800,91 @@ impl<'a> Element<'a> {
         Type::from(TypeKind::Boolean)
     }
 
+    fn operation(operator: &Token<'a>) -> Option<&'static str> {
+        let TokenKind::Operator(operator) = &operator.kind else {
+            return None;
+        };
+
+        match operator.as_slice() {
+            [OperatorKind::Plus] => Some("add"),
+            [OperatorKind::Minus] => Some("subtract"),
+            [OperatorKind::Star] => Some("multiply"),
+            [OperatorKind::Slash] => Some("divide"),
+            [OperatorKind::Percent] => Some("modulus"),
+            [OperatorKind::Ampersand] => Some("bitwise_and"),
+            [OperatorKind::Pipe] => Some("bitwise_or"),
+            [OperatorKind::Caret] => Some("bitwise_xor"),
+            [OperatorKind::LeftAngle, OperatorKind::LeftAngle] => Some("shift_left"),
+            [OperatorKind::RightAngle, OperatorKind::RightAngle] => Some("shift_right"),
+            [OperatorKind::Equal, OperatorKind::Equal] => Some("equal"),
+            [OperatorKind::Exclamation, OperatorKind::Equal] => Some("not_equal"),
+            [OperatorKind::LeftAngle] => Some("less"),
+            [OperatorKind::LeftAngle, OperatorKind::Equal] => Some("less_or_equal"),
+            [OperatorKind::RightAngle] => Some("greater"),
+            [OperatorKind::RightAngle, OperatorKind::Equal] => Some("greater_or_equal"),
+            _ => None,
+        }
+    }
+
+    fn overload(
+        resolver: &mut Resolver<'a>,
+        reference: &mut Option<crate::data::Identity>,
+        operator: &Token<'a>,
+        left: &mut Element<'a>,
+        right: &Element<'a>,
+    ) -> Option<Type<'a>> {
+        let name = Self::operation(operator)?;
+        let mut typing = resolver.reify(&left.typing);
+
+        while typing.kind.is_pointer() {
+            typing = resolver.reify(&typing.kind.unwrap_pointer());
+        }
+
+        if !typing.kind.is_structure() && !typing.kind.is_union() && !typing.kind.is_enumeration() {
+            return None;
+        }
+
+        let owner = resolver.get_symbol(typing.identity).cloned()?;
+        let method = resolver.provided(&owner, Str::from(name)).or_else(|| {
+            (name == "not_equal")
+                .then(|| resolver.provided(&owner, Str::from("equal")))
+                .flatten()
+        })?;
+
+        *left = Self::borrow(resolver, left, &method);
+        *reference = Some(method.identity);
+
+        let output = resolver.fresh();
+        let expected = Type::from(TypeKind::Function(Box::new(Function::new(
+            Str::default(),
+            vec![left.typing.clone(), right.typing.clone()],
+            resolver.fresh(),
+            Some(Box::new(output.clone())),
+            Interface::Axo,
+            false,
+            false,
+        ))));
+
+        Some(Self::apply(
+            resolver,
+            right.span,
+            &method.typing,
+            expected,
+            output,
+        ))
+    }
+
     fn binary(
         resolver: &mut Resolver<'a>,
         reference: &mut Option<crate::data::Identity>,
         binary: &mut crate::data::Binary<Element<'a>, Token<'a>, Element<'a>>,
     ) -> Type<'a> {
+        if let Some(label) = Self::label(binary) {
+            resolver.label = Some(label);
+            binary.right.resolve(resolver);
+            return binary.right.typing.clone();
+        }
+
         binary.left.resolve(resolver);
 
         match &binary.operator.kind {
@@ -460,6 +906,17 @@ impl<'a> Element<'a> {
                 | [OperatorKind::Slash]
                 | [OperatorKind::Percent] => {
                     binary.right.resolve(resolver);
+
+                    if let Some(output) = Self::overload(
+                        resolver,
+                        reference,
+                        &binary.operator,
+                        &mut binary.left,
+                        &binary.right,
+                    ) {
+                        return output;
+                    }
+
                     let left = resolver.reify(&binary.left.typing);
                     let right = resolver.reify(&binary.right.typing);
                     Self::math(
@@ -473,6 +930,17 @@ impl<'a> Element<'a> {
                 [OperatorKind::LeftAngle, OperatorKind::LeftAngle]
                 | [OperatorKind::RightAngle, OperatorKind::RightAngle] => {
                     binary.right.resolve(resolver);
+
+                    if let Some(output) = Self::overload(
+                        resolver,
+                        reference,
+                        &binary.operator,
+                        &mut binary.left,
+                        &binary.right,
+                    ) {
+                        return output;
+                    }
+
                     let left = resolver.reify(&binary.left.typing);
                     let right = resolver.reify(&binary.right.typing);
                     Self::shifts(
@@ -485,6 +953,17 @@ impl<'a> Element<'a> {
                 }
                 [OperatorKind::Ampersand] | [OperatorKind::Pipe] | [OperatorKind::Caret] => {
                     binary.right.resolve(resolver);
+
+                    if let Some(output) = Self::overload(
+                        resolver,
+                        reference,
+                        &binary.operator,
+                        &mut binary.left,
+                        &binary.right,
+                    ) {
+                        return output;
+                    }
+
                     let left = resolver.reify(&binary.left.typing);
                     let right = resolver.reify(&binary.right.typing);
                     Self::bits(
@@ -495,6 +974,19 @@ impl<'a> Element<'a> {
                         binary.right.span,
                     )
                 }
+                [OperatorKind::Dot, OperatorKind::Dot]
+                | [OperatorKind::Dot, OperatorKind::Dot, OperatorKind::Equal] => {
+                    binary.right.resolve(resolver);
+                    let left = resolver.reify(&binary.left.typing);
+                    let right = resolver.reify(&binary.right.typing);
+                    Self::range(
+                        resolver,
+                        binary.operator.clone(),
+                        left,
+                        right,
+                        binary.right.span,
+                    )
+                }
                 [OperatorKind::Ampersand, OperatorKind::Ampersand]
                 | [OperatorKind::Pipe, OperatorKind::Pipe] => {
                     binary.right.resolve(resolver);
@@ -510,6 +1002,17 @@ impl<'a> Element<'a> {
                 | [OperatorKind::RightAngle]
                 | [OperatorKind::RightAngle, OperatorKind::Equal] => {
                     binary.right.resolve(resolver);
+
+                    if let Some(output) = Self::overload(
+                        resolver,
+                        reference,
+                        &binary.operator,
+                        &mut binary.left,
+                        &binary.right,
+                    ) {
+                        return output;
+                    }
+
                     Self::compare(
                         resolver,
                         binary.operator.clone(),
@@ -529,6 +1032,9 @@ impl<'a> Element<'a> {
                     )
                 }
             },
+            TokenKind::Identifier(identifier) if identifier.as_str() == Some("as") => {
+                Self::cast(resolver, &binary.left, &binary.right)
+            }
             _ => {
                 binary.right.resolve(resolver);
                 Self::invalid_binary(
@@ -542,6 +1048,50 @@ impl<'a> Element<'a> {
         }
     }
 
+    fn cast(resolver: &mut Resolver<'a>, value: &Element<'a>, target: &Element<'a>) -> Type<'a> {
+        let typing = match resolver.annotation(target) {
+            Ok(typing) => typing,
+            Err(error) => {
+                resolver.errors.push(error);
+                return resolver.fresh();
+            }
+        };
+
+        let source = resolver.reify(&value.typing);
+
+        if !Self::castable(&source, &typing) {
+            resolver.errors.push(Error::new(
+                ErrorKind::Uncastable(source, typing.clone()),
+                value.span.merge(&target.span),
+            ));
+        }
+
+        typing
+    }
+
+    fn castable(source: &Type<'a>, target: &Type<'a>) -> bool {
+        match (&source.kind, &target.kind) {
+            (TypeKind::Newtype { member, .. }, _) => Self::castable(member, target),
+            (_, TypeKind::Newtype { member, .. }) => Self::castable(source, member),
+            (TypeKind::Variable(_) | TypeKind::Unknown, _) => true,
+            (
+                TypeKind::Integer { .. }
+                | TypeKind::Float { .. }
+                | TypeKind::Boolean
+                | TypeKind::Character
+                | TypeKind::Pointer { .. },
+                TypeKind::Integer { .. },
+            )
+            | (TypeKind::Integer { .. } | TypeKind::Float { .. }, TypeKind::Float { .. })
+            | (TypeKind::Integer { .. } | TypeKind::Boolean, TypeKind::Boolean)
+            | (TypeKind::Integer { .. } | TypeKind::Character, TypeKind::Character)
+            | (TypeKind::Integer { .. } | TypeKind::Pointer { .. }, TypeKind::Pointer { .. }) => {
+                true
+            }
+            _ => source == target,
+        }
+    }
+
     fn index(
         resolver: &mut Resolver<'a>,
         span: crate::tracker::Span,
@@ -554,6 +1104,20 @@ impl<'a> Element<'a> {
             return resolver.fresh();
         }
 
+        let primitive = matches!(
+            index.target.target().and_then(|name| name.as_str()),
+            Some("Optional" | "Result")
+        );
+
+        if primitive
+            || resolver
+                .candidates(&index.target)
+                .iter()
+                .any(Symbol::is_generic)
+        {
+            return Type::from(TypeKind::Type);
+        }
+
         index.target.resolve(resolver);
         index.members[0].resolve(resolver);
 
@@ -614,6 +1178,23 @@ impl<'a> Element<'a> {
         }
     }
 
+    fn specialize(
+        resolver: &mut Resolver<'a>,
+        span: crate::tracker::Span,
+        target: &Element<'a>,
+        kind: fn(&SymbolKind<'a>) -> bool,
+        pairs: impl FnOnce(&Symbol<'a>) -> Vec<(Element<'a>, Type<'a>)>,
+    ) -> Option<Symbol<'a>> {
+        let template = resolver
+            .candidates(target)
+            .into_iter()
+            .find(|symbol| kind(&symbol.kind) && symbol.is_generic())?;
+
+        resolver.access(&template, target.span);
+        let pairs = pairs(&template);
+        resolver.instantiate(&template, target, pairs, span)
+    }
+
     fn invoke(
         resolver: &mut Resolver<'a>,
         span: crate::tracker::Span,
@@ -647,17 +1228,19 @@ impl<'a> Element<'a> {
                 typing
             }
             Some("while") => {
-                if !invoke.members.is_empty() {
-                    invoke.members[0].resolve(resolver);
-                    let boolean = Type::from(TypeKind::Boolean);
-                    resolver.unify(invoke.members[0].span, &invoke.members[0].typing, &boolean);
-                }
+                let (_, _, yielded) = resolver.looping(|resolver| {
+                    if !invoke.members.is_empty() {
+                        invoke.members[0].resolve(resolver);
+                        let boolean = Type::from(TypeKind::Boolean);
+                        resolver.unify(invoke.members[0].span, &invoke.members[0].typing, &boolean);
+                    }
 
-                if invoke.members.len() > 1 {
-                    invoke.members[1].resolve(resolver);
-                }
+                    if invoke.members.len() > 1 {
+                        invoke.members[1].resolve(resolver);
+                    }
+                });
 
-                Type::from(TypeKind::Void)
+                yielded.unwrap_or_else(|| Type::from(TypeKind::Void))
             }
             Some("return") => {
                 if !invoke.members.is_empty() {
@@ -675,62 +1258,577 @@ impl<'a> Element<'a> {
 
                 Type::from(TypeKind::Unknown)
             }
-            Some("continue") | Some("break") => Type::from(TypeKind::Unknown),
+            Some("defer") => {
+                for member in &mut invoke.members {
+                    member.resolve(resolver);
+                }
+
+                Type::from(TypeKind::Void)
+            }
+            Some("for") => Self::r#for(resolver, invoke),
+            Some("format") => {
+                Self::printable(resolver, invoke);
+                Type::from(TypeKind::String)
+            }
+            Some("println") => {
+                Self::printable(resolver, invoke);
+                Type::from(TypeKind::Void)
+            }
+            Some("some") => {
+                let member = match invoke.members.first_mut() {
+                    Some(member) => {
+                        member.resolve(resolver);
+                        member.typing.clone()
+                    }
+                    None => resolver.fresh(),
+                };
+
+                Type::from(TypeKind::Optional {
+                    member: Box::new(member),
+                })
+            }
+            Some("none") => Type::from(TypeKind::Optional {
+                member: Box::new(resolver.fresh()),
+            }),
+            Some("ok") | Some("err") => {
+                let member = match invoke.members.first_mut() {
+                    Some(member) => {
+                        member.resolve(resolver);
+                        member.typing.clone()
+                    }
+                    None => Type::from(TypeKind::Void),
+                };
+
+                let other = resolver.fresh();
+
+                let (value, error) = if invoke.target.target() == Some(Str::from("ok")) {
+                    (member, other)
+                } else {
+                    (other, member)
+                };
+
+                Type::from(TypeKind::Result {
+                    value: Box::new(value),
+                    error: Box::new(error),
+                })
+            }
+            Some("continue") | Some("break") => {
+                if let Some(query) = resolver.stray(&invoke.members) {
+                    resolver.errors.push(Error::new(
+                        ErrorKind::UndefinedLabel { query },
+                        invoke.members[0].span,
+                    ));
+
+                    return Type::from(TypeKind::Unknown);
+                }
+
+                let (label, value) = resolver.escape(&invoke.members);
+
+                if let Some(index) = value {
+                    invoke.members[index].resolve(resolver);
+                }
+
+                let breaking = invoke.target.target() == Some(Str::from("break"));
+
+                if let (true, Some(index), Some(target)) =
+                    (breaking, value, resolver.enclosing(label))
+                {
+                    let typing = invoke.members[index].typing.clone();
+
+                    match resolver.loops[target].1.clone() {
+                        Some(expect) => {
+                            resolver.unify(span, &expect, &typing);
+                        }
+                        None => resolver.loops[target].1 = Some(typing),
+                    }
+                }
+
+                Type::from(TypeKind::Unknown)
+            }
+            Some("match") => Self::r#match(resolver, span, invoke),
+            Some("asm") => Self::assembly(resolver, span, invoke),
             _ => {
                 for member in &mut invoke.members {
                     member.resolve(resolver);
                 }
 
-                let output = resolver.fresh();
-                let expected = Type::from(TypeKind::Function(Box::new(Function::new(
-                    Str::default(),
-                    invoke
-                        .members
-                        .iter()
-                        .map(|member| member.typing.clone())
-                        .collect(),
-                    resolver.fresh(),
-                    Some(Box::new(output.clone())),
-                    Interface::Axo,
-                    false,
-                    false,
-                ))));
+                if let Some(symbol) = Self::spread(resolver, span, invoke) {
+                    invoke.target = Resolver::named(&symbol, invoke.target.span);
+                    *reference = Some(symbol.identity);
+
+                    let (expected, output) = Self::signature(resolver, invoke);
+                    return Self::apply(resolver, span, &invoke.target.typing, expected, output);
+                }
+
+                let (expected, output) = Self::signature(resolver, invoke);
+
+                if let Some(symbol) = resolver
+                    .candidates(&invoke.target)
+                    .into_iter()
+                    .find(|symbol| symbol.kind.is_alias())
+                {
+                    let member = symbol
+                        .typing
+                        .kind
+                        .try_unwrap_newtype()
+                        .map_or_else(|| symbol.typing.clone(), |(_, member)| *member.clone());
+
+                    let constructor = Type::from(TypeKind::Function(Box::new(Function::new(
+                        symbol.target().unwrap_or_default(),
+                        vec![member],
+                        resolver.fresh(),
+                        Some(Box::new(symbol.typing.clone())),
+                        Interface::Axo,
+                        false,
+                        false,
+                    ))));
+
+                    resolver.access(&symbol, invoke.target.span);
+                    invoke.target.reference = Some(symbol.identity);
+                    invoke.target.typing = constructor.clone();
+                    *reference = Some(symbol.identity);
+
+                    return Self::apply(resolver, span, &constructor, expected, output);
+                }
 
                 let selected = resolver
                     .candidates(&invoke.target)
                     .into_iter()
                     .find(|symbol| {
-                        matches!(symbol.kind, SymbolKind::Function(_)) && {
-                            let mut trial = resolver.clone();
-                            let before = trial.errors.len();
-                            let _ = trial.unify(span, &symbol.typing, &expected);
-                            trial.errors.len() == before
-                        }
+                        matches!(symbol.kind, SymbolKind::Function(_))
+                            && !symbol.is_generic()
+                            && !symbol.is_variadic()
+                            && {
+                                let mut trial = resolver.clone();
+                                let before = trial.errors.len();
+                                let _ = trial.unify(span, &symbol.typing, &expected);
+                                trial.errors.len() == before
+                            }
                     });
 
+                let generic = selected.is_none().then(|| {
+                    Self::specialize(
+                        resolver,
+                        span,
+                        &invoke.target,
+                        SymbolKind::is_function,
+                        |template| {
+                            template
+                                .kind
+                                .try_unwrap_function()
+                                .map(|function| {
+                                    function
+                                        .members
+                                        .iter()
+                                        .zip(invoke.members.iter())
+                                        .filter_map(|(parameter, argument)| {
+                                            Some((
+                                                parameter
+                                                    .kind
+                                                    .try_unwrap_binding()?
+                                                    .annotation
+                                                    .clone()?,
+                                                argument.typing.clone(),
+                                            ))
+                                        })
+                                        .collect()
+                                })
+                                .unwrap_or_default()
+                        },
+                    )
+                });
+
                 if let Some(symbol) = selected {
+                    resolver.access(&symbol, invoke.target.span);
                     invoke.target.reference = Some(symbol.identity);
                     invoke.target.typing = symbol.typing.clone();
                     *reference = Some(symbol.identity);
+                } else if let Some(Some(symbol)) = generic {
+                    invoke.target = Resolver::named(&symbol, invoke.target.span);
+                    *reference = Some(symbol.identity);
                 } else {
                     invoke.target.resolve(resolver);
                     *reference = invoke.target.reference;
                 }
 
-                let function = resolver.unify(span, &invoke.target.typing, &expected);
+                Self::apply(resolver, span, &invoke.target.typing, expected, output)
+            }
+        }
+    }
 
-                if function.kind.is_function() {
-                    function
-                        .kind
-                        .unwrap_function()
-                        .output
-                        .map(|kind| *kind)
-                        .unwrap_or_else(|| Type::from(TypeKind::Void))
-                } else {
-                    output
+    fn spread(
+        resolver: &mut Resolver<'a>,
+        span: crate::tracker::Span,
+        invoke: &mut crate::data::Invoke<Element<'a>, Element<'a>>,
+    ) -> Option<Symbol<'a>> {
+        let candidates = resolver.candidates(&invoke.target);
+
+        let exact = candidates.iter().any(|symbol| {
+            !symbol.is_generic()
+                && !symbol.is_variadic()
+                && symbol
+                    .kind
+                    .try_unwrap_function()
+                    .is_some_and(|function| function.members.len() == invoke.members.len())
+        });
+
+        if exact {
+            return None;
+        }
+
+        let template = candidates
+            .into_iter()
+            .find(|symbol| symbol.is_variadic() && !symbol.is_generic())?;
+
+        let fixed = template.kind.try_unwrap_function()?.members.len() - 1;
+
+        if invoke.members.len() < fixed {
+            return None;
+        }
+
+        resolver.access(&template, invoke.target.span);
+
+        let rest = invoke.members.split_off(fixed);
+        let count = rest.len();
+
+        let extent = match (rest.first(), rest.last()) {
+            (Some(first), Some(last)) => crate::tracker::Span::merge(&first.span, &last.span),
+            _ => span,
+        };
+
+        let mut member = resolver.fresh();
+
+        for item in &rest {
+            member = resolver.unify(item.span, &member, &item.typing);
+        }
+
+        let mut array = Element::new(
+            ElementKind::delimited(Delimited::new(
+                Token::new(TokenKind::punctuation(PunctuationKind::LeftBracket), extent),
+                rest,
+                Some(Token::new(
+                    TokenKind::punctuation(PunctuationKind::Comma),
+                    extent,
+                )),
+                Token::new(
+                    TokenKind::punctuation(PunctuationKind::RightBracket),
+                    extent,
+                ),
+            )),
+            extent,
+        );
+        array.typing = Type::from(TypeKind::Array {
+            member: Box::new(member),
+            size: count as Scale,
+        });
+
+        invoke.members.push(array);
+
+        Some(resolver.spread(&template, count, span))
+    }
+
+    fn signature(
+        resolver: &mut Resolver<'a>,
+        invoke: &crate::data::Invoke<Element<'a>, Element<'a>>,
+    ) -> (Type<'a>, Type<'a>) {
+        let output = resolver.fresh();
+        let expected = Type::from(TypeKind::Function(Box::new(Function::new(
+            Str::default(),
+            invoke
+                .members
+                .iter()
+                .map(|member| member.typing.clone())
+                .collect(),
+            resolver.fresh(),
+            Some(Box::new(output.clone())),
+            Interface::Axo,
+            false,
+            false,
+        ))));
+
+        (expected, output)
+    }
+
+    fn apply(
+        resolver: &mut Resolver<'a>,
+        span: crate::tracker::Span,
+        target: &Type<'a>,
+        expected: Type<'a>,
+        output: Type<'a>,
+    ) -> Type<'a> {
+        let function = resolver.unify(span, target, &expected);
+
+        if function.kind.is_function() {
+            function
+                .kind
+                .unwrap_function()
+                .output
+                .map(|kind| *kind)
+                .unwrap_or_else(|| Type::from(TypeKind::Void))
+        } else {
+            output
+        }
+    }
+
+    fn method(
+        resolver: &Resolver<'a>,
+        owner: &Symbol<'a>,
+        right: &Element<'a>,
+    ) -> Option<Symbol<'a>> {
+        let ElementKind::Invoke(invoke) = &right.kind else {
+            return None;
+        };
+
+        resolver.provided(owner, invoke.target.target()?)
+    }
+
+    fn receiver(method: &Symbol<'a>) -> bool {
+        method
+            .kind
+            .try_unwrap_function()
+            .and_then(|function| function.members.first())
+            .and_then(|member| member.target())
+            .is_some_and(|name| name == "self")
+    }
+
+    fn call(
+        resolver: &mut Resolver<'a>,
+        reference: &mut Option<crate::data::Identity>,
+        receiver: Option<Element<'a>>,
+        right: &mut Element<'a>,
+        method: Symbol<'a>,
+    ) -> Type<'a> {
+        let span = right.span;
+        let ElementKind::Invoke(invoke) = &mut right.kind else {
+            return resolver.fresh();
+        };
+
+        for member in &mut invoke.members {
+            member.resolve(resolver);
+        }
+
+        if let Some(receiver) = receiver {
+            invoke.members.insert(0, receiver);
+        }
+
+        invoke.target = Resolver::named(&method, invoke.target.span);
+        right.reference = Some(method.identity);
+        *reference = right.reference;
+
+        let (expected, output) = Self::signature(resolver, invoke);
+        right.typing = Self::apply(resolver, span, &method.typing, expected, output);
+        right.typing.clone()
+    }
+
+    fn borrow(resolver: &mut Resolver<'a>, left: &Element<'a>, method: &Symbol<'a>) -> Element<'a> {
+        let wanted = method
+            .typing
+            .kind
+            .try_unwrap_function()
+            .and_then(|function| function.members.first().cloned())
+            .map(|member| resolver.reify(&member))
+            .unwrap_or_else(|| Type::from(TypeKind::Unknown));
+        let actual = resolver.reify(&left.typing);
+
+        let address = match (
+            wanted.kind.try_unwrap_pointer(),
+            actual.kind.try_unwrap_pointer(),
+        ) {
+            (Some(_), None) => true,
+            (None, Some(_)) if !wanted.kind.is_variable() && !wanted.kind.is_unknown() => false,
+            _ => return left.clone(),
+        };
+
+        let operator = Token::new(
+            TokenKind::operator(if address {
+                OperatorKind::Ampersand
+            } else {
+                OperatorKind::Star
+            }),
+            left.span,
+        );
+
+        if address && !left.assignable() {
+            Self::invalid_unary(resolver, operator, actual);
+            return left.clone();
+        }
+
+        let mut element = Element::new(
+            ElementKind::unary(Unary::new(operator, left.clone())),
+            left.span,
+        );
+        element.reference = left.reference;
+        element.typing = if address {
+            Type::new(
+                actual.identity,
+                TypeKind::Pointer {
+                    target: Box::new(actual),
+                },
+            )
+        } else {
+            *actual.kind.unwrap_pointer()
+        };
+
+        element
+    }
+
+    fn r#match(
+        resolver: &mut Resolver<'a>,
+        span: crate::tracker::Span,
+        invoke: &mut crate::data::Invoke<Element<'a>, Element<'a>>,
+    ) -> Type<'a> {
+        if invoke.members.len() < 2 {
+            return Type::from(TypeKind::Void);
+        }
+
+        invoke.members[0].resolve(resolver);
+        let scrutinee = resolver.reify(&invoke.members[0].typing);
+
+        let Some(owner) = resolver.enumeration(&scrutinee) else {
+            resolver.errors.push(Error::new(
+                ErrorKind::Unmatchable(scrutinee),
+                invoke.members[0].span,
+            ));
+            return resolver.fresh();
+        };
+
+        let target = owner.target().unwrap_or_default();
+        let variants = owner
+            .kind
+            .try_unwrap_enumeration()
+            .map(|enumeration| enumeration.members.clone())
+            .unwrap_or_default();
+
+        let mut covered = vec![false; variants.len()];
+        let mut fallback = false;
+        let mut output = resolver.fresh();
+
+        let arms = match &mut invoke.members[1].kind {
+            ElementKind::Delimited(delimited) => &mut delimited.members,
+            _ => {
+                resolver.errors.push(Error::new(
+                    ErrorKind::InvalidPattern(invoke.members[1].clone()),
+                    invoke.members[1].span,
+                ));
+                return output;
+            }
+        };
+
+        for arm in arms.iter_mut() {
+            let span = arm.span;
+            let pattern = arm.arm();
+
+            let ElementKind::Binary(binary) = &mut arm.kind else {
+                resolver
+                    .errors
+                    .push(Error::new(ErrorKind::InvalidPattern(arm.clone()), span));
+                continue;
+            };
+
+            let Some((name, binders)) = pattern else {
+                resolver.errors.push(Error::new(
+                    ErrorKind::InvalidPattern(binary.left.clone()),
+                    binary.left.span,
+                ));
+                continue;
+            };
+
+            let fields = if name.as_str() == Some("_") {
+                fallback = true;
+                Vec::new()
+            } else if let Some(index) = variants
+                .iter()
+                .position(|variant| variant.target() == Some(name))
+            {
+                covered[index] = true;
+
+                let typing = resolver
+                    .get_symbol(variants[index].identity)
+                    .map(|variant| variant.typing.clone())
+                    .unwrap_or_else(|| variants[index].typing.clone());
+
+                match resolver.reify(&typing).kind {
+                    TypeKind::Structure(structure) => structure.members,
+                    _ => Vec::new(),
+                }
+            } else {
+                resolver.errors.push(Error::new(
+                    ErrorKind::UndefinedMember {
+                        target,
+                        member: name,
+                    },
+                    binary.left.span,
+                ));
+                Vec::new()
+            };
+
+            if binders.len() > fields.len() {
+                for binder in &binders[fields.len()..] {
+                    resolver.errors.push(Error::new(
+                        ErrorKind::UndefinedMember {
+                            target: name,
+                            member: *binder,
+                        },
+                        binary.left.span,
+                    ));
                 }
             }
+
+            let (typing, _) = resolver.nest(|resolver| {
+                for (binder, field) in binders.iter().zip(fields.iter()) {
+                    if binder.is_empty() || binder.as_str() == Some("_") {
+                        continue;
+                    }
+
+                    let value = match resolver.reify(field).kind {
+                        TypeKind::Binding(binding) => {
+                            binding.value.or(binding.annotation).map(|value| *value)
+                        }
+                        _ => None,
+                    }
+                    .unwrap_or_else(|| resolver.fresh());
+
+                    let head = Element::new(
+                        ElementKind::literal(Token::new(
+                            TokenKind::identifier(*binder),
+                            binary.left.span,
+                        )),
+                        binary.left.span,
+                    );
+
+                    let mut symbol = Symbol::new(
+                        SymbolKind::binding(Binding::new(head, None, None, BindingKind::Let)),
+                        binary.left.span,
+                    );
+                    symbol.typing = Self::binding(value, *binder);
+
+                    resolver.insert(symbol);
+                }
+
+                binary.right.resolve(resolver);
+                binary.right.typing.clone()
+            });
+
+            output = resolver.unify(binary.right.span, &output, &typing);
         }
+
+        if !fallback {
+            let members = variants
+                .iter()
+                .zip(covered.iter())
+                .filter(|(_, covered)| !**covered)
+                .filter_map(|(variant, _)| variant.target())
+                .collect::<Vec<_>>();
+
+            if !members.is_empty() {
+                resolver.errors.push(Error::new(
+                    ErrorKind::NonExhaustive { target, members },
+                    span,
+                ));
+            }
+        }
+
+        output
     }
 
     fn construct(
@@ -783,6 +1881,7 @@ impl<'a> Element<'a> {
         let selected = resolver
             .candidates(&construct.target)
             .into_iter()
+            .filter(|symbol| !symbol.is_generic())
             .find(|symbol| {
                 let layout = match &symbol.typing.kind {
                     TypeKind::Structure(aggregate) | TypeKind::Union(aggregate) => {
@@ -801,10 +1900,55 @@ impl<'a> Element<'a> {
                 trial.errors.len() == before
             });
 
+        let generic = selected.is_none().then(|| {
+            Self::specialize(
+                resolver,
+                span,
+                &construct.target,
+                SymbolKind::is_structure,
+                |template| {
+                    let fields = template
+                        .kind
+                        .try_unwrap_structure()
+                        .map(|structure| {
+                            structure
+                                .members
+                                .iter()
+                                .filter(|member| member.is_instance())
+                                .collect::<Vec<_>>()
+                        })
+                        .unwrap_or_default();
+
+                    members
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, (name, typing))| {
+                            let field = match name {
+                                Some(name) => fields
+                                    .iter()
+                                    .find(|field| field.target() == Some(*name))
+                                    .copied(),
+                                None => fields.get(index).copied(),
+                            }?;
+
+                            Some((
+                                field.kind.try_unwrap_binding()?.annotation.clone()?,
+                                typing.clone(),
+                            ))
+                        })
+                        .collect()
+                },
+            )
+        });
+
         if let Some(symbol) = selected {
+            resolver.access(&symbol, construct.target.span);
             construct.target.reference = Some(symbol.identity);
             construct.target.typing = symbol.typing.clone();
             *reference = Some(symbol.identity);
+        } else if let Some(Some(symbol)) = generic {
+            construct.target = Resolver::named(&symbol, construct.target.span);
+            *reference = Some(symbol.identity);
         } else {
             construct.target.resolve(resolver);
             *reference = construct.target.reference;
@@ -887,6 +2031,8 @@ impl<'a> Resolvable<'a> for Element<'a> {
     }
 
     fn is_instance(&self) -> bool {
-        self.typing.kind.is_structure() || self.typing.kind.is_union()
+        self.typing.kind.is_structure()
+            || self.typing.kind.is_union()
+            || self.typing.kind.is_enumeration()
     }
 }
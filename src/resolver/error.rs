@@ -4,6 +4,7 @@ use crate::{
     parser::Element,
     resolver::Type,
     scanner::Token,
+    tracker::Span,
 };
 
 #[derive(Clone)]
@@ -12,12 +13,21 @@ pub enum ErrorKind<'error> {
     EmptyIndex,
     IndexBounds(usize, usize),
     Unindexable,
+    Unmatchable(Type<'error>),
+    Uniterable(Type<'error>),
+    Unformattable(Type<'error>),
+    Uncastable(Type<'error>, Type<'error>),
+    Unwrapped(Type<'error>),
+    InvalidPattern(Element<'error>),
     InvalidUnary(Token<'error>, Type<'error>),
     InvalidBinary(Token<'error>, Type<'error>, Type<'error>),
     InvalidAnnotation(Element<'error>),
     UndefinedSymbol {
         query: Str<'error>,
     },
+    UndefinedLabel {
+        query: Str<'error>,
+    },
     MissingMember {
         target: Str<'error>,
         member: Str<'error>,
@@ -34,6 +44,48 @@ pub enum ErrorKind<'error> {
         target: Str<'error>,
         members: Vec<Str<'error>>,
     },
+    NonExhaustive {
+        target: Str<'error>,
+        members: Vec<Str<'error>>,
+    },
+    UninferredParameter {
+        target: Str<'error>,
+        parameter: Str<'error>,
+    },
+    ParameterCount {
+        target: Str<'error>,
+        expected: usize,
+        found: usize,
+    },
+    MissingRequirement {
+        target: Str<'error>,
+        interface: Str<'error>,
+        member: Str<'error>,
+    },
+    CyclicImport {
+        modules: Vec<Str<'error>>,
+    },
+    AmbiguousImport {
+        query: Str<'error>,
+        candidates: Vec<Str<'error>>,
+    },
+    PrivateSymbol {
+        query: Str<'error>,
+        declaration: Span,
+    },
+    IncompleteAssembly,
+    InvalidAssembly(Element<'error>),
+    AssemblyOperands {
+        expected: usize,
+        found: usize,
+    },
+    Unassemblable(Type<'error>),
+    UnknownAttribute(Element<'error>),
+    DuplicateAttribute(Element<'error>),
+    MisplacedAttribute {
+        attribute: Element<'error>,
+        target: &'static str,
+    },
 }
 
 impl<'error> Display for ErrorKind<'error> {
@@ -51,6 +103,40 @@ impl<'error> Display for ErrorKind<'error> {
                 write!(f, "index `{}` out of bounds for length `{}`.", index, len).into()
             }
             ErrorKind::Unindexable => write!(f, "target is not indexable.").into(),
+            ErrorKind::Unmatchable(typing) => write!(
+                f,
+                "cannot match on `{}`.",
+                typing.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::Uniterable(typing) => write!(
+                f,
+                "cannot iterate over `{}`.",
+                typing.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::Unformattable(typing) => {
+                write!(f, "cannot format `{}`.", typing.format(Stencil::default())).into()
+            }
+            ErrorKind::Uncastable(from, to) => write!(
+                f,
+                "cannot cast `{}` to `{}`.",
+                from.format(Stencil::default()),
+                to.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::Unwrapped(typing) => write!(
+                f,
+                "`{}` must be unwrapped with `?` before use.",
+                typing.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::InvalidPattern(element) => write!(
+                f,
+                "invalid pattern `{}`.",
+                element.format(Stencil::default())
+            )
+            .into(),
             ErrorKind::InvalidUnary(operator, operand) => write!(
                 f,
                 "cannot apply `{}` to `{}`.",
@@ -78,6 +164,9 @@ impl<'error> Display for ErrorKind<'error> {
                 query.format(Stencil::default())
             )
             .into(),
+            ErrorKind::UndefinedLabel { query } => {
+                write!(f, "undefined label `{}`.", query.format(Stencil::default())).into()
+            }
             ErrorKind::MissingMember { target, member } => write!(
                 f,
                 "member `{}` missing from `{}`.",
@@ -106,6 +195,132 @@ impl<'error> Display for ErrorKind<'error> {
                 members.len()
             )
             .into(),
+            ErrorKind::NonExhaustive { target, members } => write!(
+                f,
+                "match on `{}` does not cover `{}`.",
+                target.format(Stencil::default()),
+                members
+                    .iter()
+                    .map(|member| member.format(Stencil::default()).to_string())
+                    .collect::<Vec<_>>()
+                    .join("`, `")
+            )
+            .into(),
+            ErrorKind::UninferredParameter { target, parameter } => write!(
+                f,
+                "cannot infer type parameter `{}` of `{}`.",
+                parameter.format(Stencil::default()),
+                target.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::ParameterCount {
+                target,
+                expected,
+                found,
+            } => write!(
+                f,
+                "`{}` takes {} type parameters but {} were provided.",
+                target.format(Stencil::default()),
+                expected,
+                found
+            )
+            .into(),
+            ErrorKind::MissingRequirement {
+                target,
+                interface,
+                member,
+            } => write!(
+                f,
+                "`{}` does not implement `{}` required by `{}`.",
+                target.format(Stencil::default()),
+                member.format(Stencil::default()),
+                interface.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::CyclicImport { modules } => write!(
+                f,
+                "cyclic import through `{}`.",
+                modules
+                    .iter()
+                    .map(|module| module.format(Stencil::default()).to_string())
+                    .collect::<Vec<_>>()
+                    .join("` -> `")
+            )
+            .into(),
+            ErrorKind::AmbiguousImport { query, candidates } => write!(
+                f,
+                "`{}` matches more than one module: `{}`.",
+                query.format(Stencil::default()),
+                candidates
+                    .iter()
+                    .map(|candidate| candidate.format(Stencil::default()).to_string())
+                    .collect::<Vec<_>>()
+                    .join("`, `")
+            )
+            .into(),
+            ErrorKind::PrivateSymbol { query, .. } => write!(
+                f,
+                "`{}` is private to its module.",
+                query.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::IncompleteAssembly => write!(
+                f,
+                "inline assembly requires a template and a constraint string."
+            )
+            .into(),
+            ErrorKind::InvalidAssembly(element) => write!(
+                f,
+                "expected a string literal for inline assembly but got `{}`.",
+                element.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::AssemblyOperands { expected, found } => write!(
+                f,
+                "inline assembly constraints take {} operands but {} were provided.",
+                expected, found
+            )
+            .into(),
+            ErrorKind::Unassemblable(typing) => write!(
+                f,
+                "cannot pass `{}` to inline assembly.",
+                typing.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::UnknownAttribute(attribute) => write!(
+                f,
+                "unknown attribute `@{}`.",
+                attribute.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::DuplicateAttribute(attribute) => write!(
+                f,
+                "attribute `@{}` is specified more than once.",
+                attribute.format(Stencil::default())
+            )
+            .into(),
+            ErrorKind::MisplacedAttribute { attribute, target } => write!(
+                f,
+                "attribute `@{}` cannot be applied to a {}.",
+                attribute.format(Stencil::default()),
+                target
+            )
+            .into(),
+        }
+    }
+}
+
+impl<'error> ErrorKind<'error> {
+    pub fn note(&self) -> Option<(String, Span)> {
+        match self {
+            ErrorKind::PrivateSymbol { query, declaration } => Some((
+                format!(
+                    "`{}` is declared here without `pub`.",
+                    query.format(Stencil::default())
+                ),
+                *declaration,
+            )),
+            _ => None,
         }
     }
 }
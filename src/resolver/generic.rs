@@ -0,0 +1,382 @@
+use crate::{
+    data::{memory::replace, Binding, BindingKind, Delimited, Identity, Integer, Str},
+    parser::{Element, ElementKind, Symbol, SymbolKind},
+    resolver::{
+        next_identity, scope::Scope, Error, ErrorKind, Resolvable, Resolver, Type, TypeKind,
+    },
+    scanner::{PunctuationKind, Token, TokenKind},
+    tracker::Span,
+};
+
+impl<'a> Element<'a> {
+    fn refresh(&mut self) {
+        self.reference = None;
+
+        match &mut self.kind {
+            ElementKind::Literal(_) => {}
+            ElementKind::Delimited(delimited) => {
+                delimited.members.iter_mut().for_each(Element::refresh)
+            }
+            ElementKind::Unary(unary) => unary.operand.refresh(),
+            ElementKind::Binary(binary) => {
+                binary.left.refresh();
+                binary.right.refresh();
+            }
+            ElementKind::Index(index) => {
+                index.target.refresh();
+                index.members.iter_mut().for_each(Element::refresh);
+            }
+            ElementKind::Invoke(invoke) => {
+                invoke.target.refresh();
+                invoke.members.iter_mut().for_each(Element::refresh);
+            }
+            ElementKind::Construct(construct) => {
+                construct.target.refresh();
+                construct.members.iter_mut().for_each(Element::refresh);
+            }
+            ElementKind::Symbolize(symbol) => symbol.refresh(),
+        }
+    }
+}
+
+impl<'a> Symbol<'a> {
+    fn refresh(&mut self) {
+        self.identity = next_identity();
+        self.scope = Box::new(Scope::new(None));
+        self.typing = Type::from(TypeKind::Unknown);
+
+        match &mut self.kind {
+            SymbolKind::Binding(binding) => {
+                binding.target.refresh();
+                binding.value.iter_mut().for_each(Element::refresh);
+                binding.annotation.iter_mut().for_each(Element::refresh);
+            }
+            SymbolKind::Alias(alias) => alias.value.refresh(),
+            SymbolKind::Structure(aggregate)
+            | SymbolKind::Union(aggregate)
+            | SymbolKind::Enumeration(aggregate)
+            | SymbolKind::Implementation(aggregate)
+            | SymbolKind::Interface(aggregate) => {
+                aggregate.members.iter_mut().for_each(Symbol::refresh)
+            }
+            SymbolKind::Function(function) => {
+                function.members.iter_mut().for_each(Symbol::refresh);
+                function.body.iter_mut().for_each(Element::refresh);
+                function.output.iter_mut().for_each(Element::refresh);
+            }
+            SymbolKind::Module(_) | SymbolKind::Import(_) => {}
+        }
+    }
+
+    fn rename(&mut self, name: Str<'a>) {
+        let head = match &mut self.kind {
+            SymbolKind::Structure(structure) => &mut structure.target,
+            SymbolKind::Function(function) => &mut function.target,
+            _ => return,
+        };
+
+        *head = Element::new(
+            ElementKind::literal(Token::new(TokenKind::identifier(name), head.span)),
+            head.span,
+        );
+    }
+}
+
+impl<'a> Resolver<'a> {
+    pub fn parameter(name: Str<'a>, typing: Type<'a>, span: Span) -> Symbol<'a> {
+        let head = Element::new(
+            ElementKind::literal(Token::new(TokenKind::identifier(name), span)),
+            span,
+        );
+
+        let mut symbol = Symbol::new(
+            SymbolKind::binding(Binding::new(head, None, None, BindingKind::Static)),
+            span,
+        );
+        symbol.typing = typing;
+        symbol
+    }
+
+    pub fn mangle(typing: &Type<'a>) -> String {
+        match &typing.kind {
+            TypeKind::Integer { size, signed } => {
+                format!("{}Int{}", if *signed { "" } else { "U" }, size)
+            }
+            TypeKind::Float { size } => format!("Float{}", size),
+            TypeKind::Boolean => "Boolean".to_string(),
+            TypeKind::String => "String".to_string(),
+            TypeKind::Character => "Character".to_string(),
+            TypeKind::Void => "Void".to_string(),
+            TypeKind::Pointer { target } => format!("*{}", Self::mangle(target)),
+            TypeKind::Optional { member } => format!("{}?", Self::mangle(member)),
+            TypeKind::Result { value, error } => {
+                format!("Result[{}, {}]", Self::mangle(value), Self::mangle(error))
+            }
+            TypeKind::Array { member, size } => format!("[{}; {}]", Self::mangle(member), size),
+            TypeKind::Tuple { members } => format!(
+                "({})",
+                members
+                    .iter()
+                    .map(Self::mangle)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TypeKind::Newtype { target, .. } => target.to_string(),
+            TypeKind::Structure(aggregate)
+            | TypeKind::Union(aggregate)
+            | TypeKind::Enumeration(aggregate) => aggregate.target.to_string(),
+            _ => "_".to_string(),
+        }
+    }
+
+    pub fn owner(&self, target: Identity) -> Option<Identity> {
+        let mut current = self.active;
+
+        loop {
+            let scope = self.scopes.get(&current)?;
+
+            if scope.has(target) {
+                return Some(current);
+            }
+
+            current = scope.parent?;
+        }
+    }
+
+    pub fn named(symbol: &Symbol<'a>, span: Span) -> Element<'a> {
+        let mut element = Element::new(
+            ElementKind::literal(Token::new(
+                TokenKind::identifier(symbol.target().unwrap_or_default()),
+                span,
+            )),
+            span,
+        );
+
+        element.reference = Some(symbol.identity);
+        element.typing = symbol.typing.clone();
+        element
+    }
+
+    pub fn infer(
+        &mut self,
+        template: &Symbol<'a>,
+        pairs: Vec<(Element<'a>, Type<'a>)>,
+        span: Span,
+    ) -> Option<Vec<Type<'a>>> {
+        let parameters = template.generics();
+
+        let (arguments, _) = self.nest(|resolver| {
+            let variables = parameters
+                .iter()
+                .map(|parameter| {
+                    let variable = resolver.fresh();
+                    resolver.insert(Self::parameter(*parameter, variable.clone(), span));
+                    variable
+                })
+                .collect::<Vec<_>>();
+
+            for (annotation, typing) in &pairs {
+                if let Ok(expect) = resolver.annotation(annotation) {
+                    resolver.unify(span, &expect, typing);
+                }
+            }
+
+            variables
+                .iter()
+                .map(|variable| resolver.reify(variable))
+                .collect::<Vec<_>>()
+        });
+
+        let mut complete = true;
+
+        for (parameter, typing) in parameters.iter().zip(arguments.iter()) {
+            if typing.kind.is_variable() || typing.kind.is_unknown() {
+                self.errors.push(Error::new(
+                    ErrorKind::UninferredParameter {
+                        target: template.target().unwrap_or_default(),
+                        parameter: *parameter,
+                    },
+                    span,
+                ));
+                complete = false;
+            }
+        }
+
+        complete.then_some(arguments)
+    }
+
+    pub fn instantiate(
+        &mut self,
+        template: &Symbol<'a>,
+        head: &Element<'a>,
+        pairs: Vec<(Element<'a>, Type<'a>)>,
+        span: Span,
+    ) -> Option<Symbol<'a>> {
+        let arguments = match &head.kind {
+            ElementKind::Index(index) => {
+                let mut arguments = Vec::with_capacity(index.members.len());
+
+                for member in &index.members {
+                    match self.annotation(member) {
+                        Ok(typing) => arguments.push(typing),
+                        Err(error) => {
+                            self.errors.push(error);
+                            return None;
+                        }
+                    }
+                }
+
+                arguments
+            }
+            _ => self.infer(template, pairs, span)?,
+        };
+
+        let expected = template.generics().len();
+
+        if arguments.len() != expected {
+            self.errors.push(Error::new(
+                ErrorKind::ParameterCount {
+                    target: template.target().unwrap_or_default(),
+                    expected,
+                    found: arguments.len(),
+                },
+                span,
+            ));
+            return None;
+        }
+
+        let mut satisfied = true;
+
+        for ((_, bound), argument) in template.parameters().into_iter().zip(arguments.iter()) {
+            let Some(bound) = bound else {
+                continue;
+            };
+
+            match self.annotation(&bound) {
+                Ok(bound) => satisfied &= self.satisfies(argument, &bound, span),
+                Err(error) => {
+                    self.errors.push(error);
+                    satisfied = false;
+                }
+            }
+        }
+
+        if !satisfied {
+            return None;
+        }
+
+        Some(self.specialize(template, arguments, span))
+    }
+
+    pub fn specialize(
+        &mut self,
+        template: &Symbol<'a>,
+        arguments: Vec<Type<'a>>,
+        span: Span,
+    ) -> Symbol<'a> {
+        let name = Str::from(format!(
+            "{}[{}]",
+            template.target().unwrap_or_default(),
+            arguments
+                .iter()
+                .map(Self::mangle)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        self.emplace(template, name, arguments, span, |_| {})
+    }
+
+    pub fn spread(&mut self, template: &Symbol<'a>, count: usize, span: Span) -> Symbol<'a> {
+        let name = Str::from(format!(
+            "{}[...{}]",
+            template.target().unwrap_or_default(),
+            count
+        ));
+
+        self.emplace(template, name, Vec::new(), span, |instance| {
+            let Some(function) = instance.kind.try_unwrap_function_mut() else {
+                return;
+            };
+
+            function.variadic = false;
+
+            let Some(annotation) = function
+                .members
+                .last_mut()
+                .and_then(|member| member.kind.try_unwrap_binding_mut())
+                .and_then(|binding| binding.annotation.as_mut())
+            else {
+                return;
+            };
+
+            let span = annotation.span;
+            let size = Element::new(
+                ElementKind::literal(Token::new(TokenKind::integer(count as Integer), span)),
+                span,
+            );
+
+            *annotation = Element::new(
+                ElementKind::delimited(Delimited::new(
+                    Token::new(TokenKind::punctuation(PunctuationKind::LeftBracket), span),
+                    vec![annotation.clone(), size],
+                    Some(Token::new(
+                        TokenKind::punctuation(PunctuationKind::Semicolon),
+                        span,
+                    )),
+                    Token::new(TokenKind::punctuation(PunctuationKind::RightBracket), span),
+                )),
+                span,
+            );
+        })
+    }
+
+    fn emplace(
+        &mut self,
+        template: &Symbol<'a>,
+        name: Str<'a>,
+        arguments: Vec<Type<'a>>,
+        span: Span,
+        prepare: impl FnOnce(&mut Symbol<'a>),
+    ) -> Symbol<'a> {
+        if let Some(instance) = self
+            .instances
+            .get(&(template.identity, name))
+            .and_then(|identity| self.get_symbol(*identity))
+        {
+            return instance.clone();
+        }
+
+        let parameters = template.generics();
+
+        let mut instance = template.clone();
+        instance.refresh();
+        instance.rename(name);
+        prepare(&mut instance);
+
+        self.instances
+            .insert((template.identity, name), instance.identity);
+
+        let owner = self.owner(template.identity).unwrap_or(self.active);
+        let active = replace(&mut self.active, owner);
+        let module = self.owners.get(&template.identity).copied().or(self.module);
+        let module = replace(&mut self.module, module);
+
+        let (instance, _) = self.nest(|resolver| {
+            for (parameter, typing) in parameters.iter().zip(arguments) {
+                resolver.insert(Self::parameter(*parameter, typing, span));
+            }
+
+            instance.declare(resolver);
+            instance.resolve(resolver);
+            instance
+        });
+
+        self.active = active;
+        self.module = module;
+        self.specializations
+            .push((template.identity, instance.clone()));
+
+        instance
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{
+    data::{memory::replace, Identity, Str},
+    internal::{hash::Set, platform::Path, Artifact, Session},
+    parser::{Element, ElementKind, Symbol, SymbolKind},
+    resolver::{next_identity, Error, ErrorKind, Resolver},
+    scanner::{Token, TokenKind},
+};
+
+impl<'a> Symbol<'a> {
+    fn alias(&self, name: Str<'a>) -> Symbol<'a> {
+        let mut alias = self.clone();
+        alias.identity = next_identity();
+
+        let head = match &mut alias.kind {
+            SymbolKind::Binding(binding) => &mut binding.target,
+            SymbolKind::Alias(aliased) => &mut aliased.target,
+            SymbolKind::Structure(aggregate)
+            | SymbolKind::Union(aggregate)
+            | SymbolKind::Enumeration(aggregate)
+            | SymbolKind::Interface(aggregate) => &mut aggregate.target,
+            SymbolKind::Function(function) => &mut function.target,
+            SymbolKind::Module(module) => &mut module.target,
+            SymbolKind::Implementation(_) | SymbolKind::Import(_) => return alias,
+        };
+
+        let head = if head.kind.is_index() {
+            &mut head.kind.try_unwrap_index_mut().unwrap().target
+        } else {
+            head
+        };
+
+        *head = Element::new(
+            ElementKind::literal(Token::new(TokenKind::identifier(name), head.span)),
+            head.span,
+        );
+
+        alias
+    }
+}
+
+impl<'a> Resolver<'a> {
+    pub(super) fn link(session: &mut Session<'a>, source: &[Identity]) {
+        for &key in source {
+            let Some(module) = Self::module_target(session, key) else {
+                continue;
+            };
+
+            if let Some(Artifact::Elements(elements)) =
+                session.records.get(&key).and_then(|record| record.fetch(2))
+            {
+                for symbol in elements
+                    .iter()
+                    .filter_map(|element| element.kind.try_unwrap_symbolize())
+                {
+                    session.resolver.owners.insert(symbol.identity, module);
+                }
+            }
+        }
+
+        let mut done = Set::new();
+
+        for &key in source {
+            Self::follow(session, source, key, &mut Vec::new(), &mut done);
+        }
+    }
+
+    fn follow(
+        session: &mut Session<'a>,
+        source: &[Identity],
+        key: Identity,
+        trail: &mut Vec<Identity>,
+        done: &mut Set<Identity>,
+    ) {
+        if done.contains(&key) {
+            return;
+        }
+
+        let Some(module) = Self::module_target(session, key) else {
+            done.insert(key);
+            return;
+        };
+
+        let imports = match session.records.get(&key).and_then(|record| record.fetch(2)) {
+            Some(Artifact::Elements(elements)) => elements
+                .iter()
+                .filter_map(|element| {
+                    let symbol = element.kind.try_unwrap_symbolize()?;
+                    Some((symbol.kind.try_unwrap_import()?.clone(), symbol.span))
+                })
+                .collect::<Vec<_>>(),
+            _ => Vec::new(),
+        };
+
+        trail.push(key);
+
+        for (import, span) in imports {
+            let segments = Self::segments(&import.target);
+
+            let query = Str::from(
+                segments
+                    .iter()
+                    .map(|segment| segment.to_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+            );
+
+            let target = match Self::locate(session, source, &segments).as_slice() {
+                [target] => *target,
+                [] => {
+                    session.resolver.errors.push(Error::new(
+                        ErrorKind::UndefinedSymbol { query },
+                        import.target.span,
+                    ));
+                    continue;
+                }
+                candidates => {
+                    let candidates = candidates
+                        .iter()
+                        .map(|candidate| session.records[candidate].location)
+                        .collect();
+
+                    session.resolver.errors.push(Error::new(
+                        ErrorKind::AmbiguousImport { query, candidates },
+                        import.target.span,
+                    ));
+                    continue;
+                }
+            };
+
+            if let Some(position) = trail.iter().position(|entry| *entry == target) {
+                let modules = trail[position..]
+                    .iter()
+                    .chain([&target])
+                    .map(|entry| Self::module_name(&session.records[entry]))
+                    .collect();
+
+                session
+                    .resolver
+                    .errors
+                    .push(Error::new(ErrorKind::CyclicImport { modules }, span));
+                continue;
+            }
+
+            Self::follow(session, source, target, trail, done);
+
+            if let Some(origin) = Self::module_target(session, target) {
+                let active = replace(&mut session.resolver.module, Some(module));
+                session.resolver.bring(module, origin, &import.members);
+                session.resolver.module = active;
+            }
+        }
+
+        trail.pop();
+        done.insert(key);
+    }
+
+    fn segments(path: &Element<'a>) -> Vec<Str<'a>> {
+        match &path.kind {
+            ElementKind::Binary(binary) => {
+                let mut segments = Self::segments(&binary.left);
+                segments.extend(binary.right.target());
+                segments
+            }
+            _ => path.target().into_iter().collect(),
+        }
+    }
+
+    fn locate(session: &Session<'a>, source: &[Identity], segments: &[Str<'a>]) -> Vec<Identity> {
+        let query = segments
+            .iter()
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        source
+            .iter()
+            .copied()
+            .filter(|key| {
+                session.records.get(key).is_some_and(|record| {
+                    Path::new(&record.location)
+                        .with_extension("")
+                        .ends_with(&query)
+                })
+            })
+            .collect()
+    }
+
+    fn bring(&mut self, module: Identity, origin: Identity, items: &[Element<'a>]) {
+        let Some(exports) = self
+            .get_symbol(origin)
+            .map(|symbol| symbol.scope.symbols.clone())
+        else {
+            return;
+        };
+
+        let mut imported = Vec::new();
+
+        if items.is_empty() {
+            imported.push(origin);
+        }
+
+        for item in items {
+            let (name, alias) = match &item.kind {
+                ElementKind::Binary(binary) => (binary.left.target(), binary.right.target()),
+                _ => (item.target(), item.target()),
+            };
+
+            let (Some(name), Some(alias)) = (name, alias) else {
+                continue;
+            };
+
+            let found = exports
+                .iter()
+                .filter_map(|identity| self.get_symbol(*identity))
+                .filter(|symbol| symbol.target() == Some(name))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if found.is_empty() {
+                self.errors.push(Error::new(
+                    ErrorKind::UndefinedSymbol { query: name },
+                    item.span,
+                ));
+                continue;
+            }
+
+            for symbol in found {
+                if !self.accessible(&symbol) {
+                    let error = self.private(&symbol, item.span);
+                    self.errors.push(error);
+                    continue;
+                }
+
+                if alias == name {
+                    imported.push(symbol.identity);
+                    continue;
+                }
+
+                let renamed = symbol.alias(alias);
+                let original = self
+                    .aliases
+                    .get(&symbol.identity)
+                    .copied()
+                    .unwrap_or(symbol.identity);
+
+                self.aliases.insert(renamed.identity, original);
+                imported.push(renamed.identity);
+                self.registry.insert(renamed.identity, renamed);
+            }
+        }
+
+        if let Some(module) = self.registry.get_mut(&module) {
+            for identity in imported {
+                module.scope.insert(identity);
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    data::Str,
+    parser::Symbol,
+    resolver::{Error, ErrorKind, Resolver, Type, TypeKind},
+    tracker::Span,
+};
+
+impl<'a> Resolver<'a> {
+    pub fn provided(&self, owner: &Symbol<'a>, member: Str<'a>) -> Option<Symbol<'a>> {
+        let name = format!("{}.{}", owner.target()?, member);
+
+        owner
+            .scope
+            .symbols
+            .iter()
+            .filter_map(|identity| self.get_symbol(*identity))
+            .find(|symbol| {
+                symbol.kind.is_function()
+                    && symbol
+                        .target()
+                        .is_some_and(|target| target == name.as_str())
+            })
+            .cloned()
+    }
+
+    pub fn satisfies(&mut self, typing: &Type<'a>, bound: &Type<'a>, span: Span) -> bool {
+        let typing = self.reify(typing);
+
+        let owner =
+            (typing.kind.is_structure() || typing.kind.is_union() || typing.kind.is_enumeration())
+                .then(|| self.get_symbol(typing.identity).cloned())
+                .flatten();
+
+        let Some((interface, member)) = self.unsatisfied(owner.as_ref(), bound, span) else {
+            return true;
+        };
+
+        self.errors.push(Error::new(
+            ErrorKind::MissingRequirement {
+                target: owner
+                    .as_ref()
+                    .and_then(Symbol::target)
+                    .unwrap_or_else(|| Str::from(Self::mangle(&typing))),
+                interface,
+                member,
+            },
+            span,
+        ));
+
+        false
+    }
+
+    fn unsatisfied(
+        &mut self,
+        owner: Option<&Symbol<'a>>,
+        bound: &Type<'a>,
+        span: Span,
+    ) -> Option<(Str<'a>, Str<'a>)> {
+        match &bound.kind {
+            TypeKind::Has(required) => {
+                let name = required.kind.try_unwrap_function()?.target;
+                let (interface, member) = name.as_str()?.rsplit_once('.')?;
+                let (interface, member) = (Str::from(interface), Str::from(member));
+
+                let Some(method) = owner.and_then(|owner| self.provided(owner, member)) else {
+                    return Some((interface, member));
+                };
+
+                let mut trial = self.clone();
+                let before = trial.errors.len();
+                trial.unify(span, required, &method.typing);
+
+                if trial.errors.len() != before {
+                    self.errors.push(Error::new(
+                        ErrorKind::Mismatch(*required.clone(), method.typing),
+                        method.span,
+                    ));
+                }
+
+                None
+            }
+            TypeKind::And(left, right) => self
+                .unsatisfied(owner, left, span)
+                .or_else(|| self.unsatisfied(owner, right, span)),
+            TypeKind::Or(left, right) => {
+                let missing = self.unsatisfied(owner, left, span)?;
+                self.unsatisfied(owner, right, span).map(|_| missing)
+            }
+            _ => None,
+        }
+    }
+}
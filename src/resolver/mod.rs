@@ -1,5 +1,9 @@
+mod closure;
 mod element;
 mod error;
+mod generic;
+mod import;
+mod interface;
 mod primitives;
 mod resolver;
 pub mod scope;
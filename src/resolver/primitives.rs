@@ -32,9 +32,19 @@ impl<'resolver> Resolver<'resolver> {
             "Void" => Some(Resolver::function("Void", "Void")),
             "if" => Some(Resolver::statement("if")),
             "while" => Some(Resolver::statement("while")),
+            "for" => Some(Resolver::statement("for")),
             "break" => Some(Resolver::statement("break")),
             "continue" => Some(Resolver::statement("continue")),
             "return" => Some(Resolver::statement("return")),
+            "defer" => Some(Resolver::statement("defer")),
+            "match" => Some(Resolver::statement("match")),
+            "format" => Some(Resolver::statement("format")),
+            "println" => Some(Resolver::statement("println")),
+            "some" => Some(Resolver::statement("some")),
+            "none" => Some(Resolver::statement("none")),
+            "ok" => Some(Resolver::statement("ok")),
+            "err" => Some(Resolver::statement("err")),
+            "asm" => Some(Resolver::statement("asm")),
             _ => None,
         }
     }
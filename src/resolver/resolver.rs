@@ -1,14 +1,20 @@
 use crate::{
-    data::{memory::replace, Identity, Module, Str},
+    data::{
+        memory::{replace, take},
+        Identity, Module, Str, Visibility,
+    },
     format::Show,
     internal::{hash::Map, Artifact, RecordKind, Session, SessionError},
     parser::{Element, ElementKind, Symbol, SymbolKind},
-    resolver::{next_identity, scope::Scope, ErrorKind, ResolveError, Type},
+    resolver::{next_identity, scope::Scope, Error, ErrorKind, ResolveError, Type},
     scanner::{Token, TokenKind},
     tracker::Span,
 };
 use broccli::Color;
 
+#[cfg(feature = "interpreter")]
+use crate::analyzer::Analysis;
+
 pub struct Resolver<'a> {
     pub active: Identity,
     pub scopes: Map<Identity, Scope>,
@@ -17,6 +23,16 @@ pub struct Resolver<'a> {
     pub errors: Vec<ResolveError<'a>>,
     pub variables: Vec<Option<Type<'a>>>,
     pub returns: Vec<Type<'a>>,
+    pub loops: Vec<(Option<Str<'a>>, Option<Type<'a>>)>,
+    pub label: Option<Str<'a>>,
+    pub instances: Map<(Identity, Str<'a>), Identity>,
+    pub specializations: Vec<(Identity, Symbol<'a>)>,
+    pub captures: Map<Identity, Vec<Identity>>,
+    pub aliases: Map<Identity, Identity>,
+    pub owners: Map<Identity, Identity>,
+    pub module: Option<Identity>,
+    #[cfg(feature = "interpreter")]
+    pub constants: Map<Identity, Option<Analysis<'a>>>,
 }
 
 impl Clone for Resolver<'_> {
@@ -29,6 +45,16 @@ impl Clone for Resolver<'_> {
             errors: self.errors.clone(),
             variables: self.variables.clone(),
             returns: self.returns.clone(),
+            loops: self.loops.clone(),
+            label: self.label,
+            instances: self.instances.clone(),
+            specializations: self.specializations.clone(),
+            captures: self.captures.clone(),
+            aliases: self.aliases.clone(),
+            owners: self.owners.clone(),
+            module: self.module,
+            #[cfg(feature = "interpreter")]
+            constants: self.constants.clone(),
         }
     }
 }
@@ -55,6 +81,16 @@ impl<'a> Resolver<'a> {
             errors: Vec::new(),
             variables: Vec::new(),
             returns: Vec::new(),
+            loops: Vec::new(),
+            label: None,
+            instances: Map::new(),
+            specializations: Vec::new(),
+            captures: Map::new(),
+            aliases: Map::new(),
+            owners: Map::new(),
+            module: None,
+            #[cfg(feature = "interpreter")]
+            constants: Map::new(),
         }
     }
 
@@ -105,6 +141,51 @@ impl<'a> Resolver<'a> {
         (value, self.scopes.remove(&active).unwrap())
     }
 
+    pub fn looping<T>(
+        &mut self,
+        combinator: impl FnOnce(&mut Self) -> T,
+    ) -> (T, Option<Str<'a>>, Option<Type<'a>>) {
+        let label = self.label.take();
+        self.loops.push((label, None));
+        let value = combinator(self);
+        let (label, yielded) = self.loops.pop().unwrap_or_default();
+        (value, label, yielded)
+    }
+
+    pub fn enclosing(&self, label: Option<Str<'a>>) -> Option<usize> {
+        match label {
+            Some(name) => self
+                .loops
+                .iter()
+                .rposition(|(label, _)| *label == Some(name)),
+            None => self.loops.len().checked_sub(1),
+        }
+    }
+
+    pub fn escape(&self, members: &[Element<'a>]) -> (Option<Str<'a>>, Option<usize>) {
+        let label = members
+            .first()
+            .filter(|member| member.kind.is_literal())
+            .and_then(Element::target)
+            .filter(|name| self.loops.iter().any(|(label, _)| *label == Some(*name)));
+
+        match label {
+            Some(_) => (label, (members.len() > 1).then_some(1)),
+            None => (None, (!members.is_empty()).then_some(0)),
+        }
+    }
+
+    pub fn stray(&self, members: &[Element<'a>]) -> Option<Str<'a>> {
+        let first = members.first().filter(|member| member.kind.is_literal())?;
+        let name = first.target()?;
+
+        if self.loops.iter().any(|(label, _)| *label == Some(name)) {
+            return None;
+        }
+
+        (members.len() > 1 || self.candidates(first).is_empty()).then_some(name)
+    }
+
     pub fn insert(&mut self, symbol: Symbol<'a>) {
         let identity = symbol.identity;
         self.registry.insert(identity, symbol);
@@ -180,9 +261,70 @@ impl<'a> Resolver<'a> {
     }
 
     pub fn lookup(&self, target: &Element<'a>) -> Result<Symbol<'a>, Vec<ResolveError<'a>>> {
-        Self::builtin(target)
+        let symbol = Self::builtin(target)
             .or_else(|| self.exact(target))
-            .ok_or_else(|| self.undefined(target))
+            .ok_or_else(|| self.undefined(target))?;
+
+        if self.accessible(&symbol) {
+            Ok(symbol)
+        } else {
+            Err(vec![self.private(&symbol, target.span)])
+        }
+    }
+
+    pub fn provided(&self, module: &str, name: &str) -> Option<Symbol<'a>> {
+        let module = self.registry.values().find(|symbol| {
+            symbol.kind.is_module()
+                && symbol.target().and_then(|target| target.as_str()) == Some(module)
+        })?;
+
+        module
+            .scope
+            .symbols
+            .iter()
+            .filter_map(|identity| self.registry.get(identity))
+            .find(|symbol| {
+                symbol.kind.is_function()
+                    && symbol.visibility == Visibility::Public
+                    && symbol.target().and_then(|target| target.as_str()) == Some(name)
+            })
+            .cloned()
+    }
+
+    pub fn accessible(&self, symbol: &Symbol<'a>) -> bool {
+        if symbol.visibility == Visibility::Public {
+            return true;
+        }
+
+        match self.owners.get(&symbol.identity) {
+            Some(owner) => self.module == Some(*owner),
+            None => true,
+        }
+    }
+
+    pub fn private(&self, symbol: &Symbol<'a>, span: Span) -> ResolveError<'a> {
+        Error::new(
+            ErrorKind::PrivateSymbol {
+                query: symbol.target().unwrap_or_default(),
+                declaration: symbol.span,
+            },
+            span,
+        )
+    }
+
+    pub fn access(&mut self, symbol: &Symbol<'a>, span: Span) {
+        if !self.accessible(symbol) {
+            let error = self.private(symbol, span);
+            self.errors.push(error);
+        }
+    }
+
+    pub fn enumeration(&self, typing: &Type<'a>) -> Option<Symbol<'a>> {
+        typing
+            .kind
+            .is_enumeration()
+            .then(|| self.get_symbol(typing.identity).cloned())
+            .flatten()
     }
 
     pub fn execute(session: &mut Session<'a>, keys: &[Identity]) {
@@ -202,9 +344,11 @@ impl<'a> Resolver<'a> {
         Self::visit(session, &source, |element, resolver| {
             element.declare(resolver)
         });
+        Self::link(session, &source);
         Self::visit(session, &source, |element, resolver| {
             element.resolve(resolver)
         });
+        Self::attach(session, &source);
 
         session
             .errors
@@ -215,7 +359,7 @@ impl<'a> Resolver<'a> {
         Str::from(record.location.stem().unwrap().to_string())
     }
 
-    fn module_target(session: &Session<'a>, key: Identity) -> Option<Identity> {
+    pub(super) fn module_target(session: &Session<'a>, key: Identity) -> Option<Identity> {
         match session.records.get(&key)?.fetch(0)? {
             Artifact::Module(identity) => Some(*identity),
             _ => None,
@@ -278,6 +422,7 @@ impl<'a> Resolver<'a> {
                 continue;
             };
 
+            session.resolver.module = Some(target);
             let mut module = session.resolver.registry.remove(&target).unwrap();
             let scope = replace(&mut module.scope, Box::new(Scope::new(None)));
 
@@ -294,6 +439,42 @@ impl<'a> Resolver<'a> {
             module.set_scope(scope);
             session.resolver.insert(module);
         }
+
+        session.resolver.module = None;
+    }
+
+    fn attach(session: &mut Session<'a>, source: &[Identity]) {
+        for (template, instance) in take(&mut session.resolver.specializations) {
+            let owner = source
+                .iter()
+                .copied()
+                .find(|key| {
+                    matches!(
+                        session.records.get(key).and_then(|record| record.fetch(2)),
+                        Some(Artifact::Elements(elements)) if elements.iter().any(|element| {
+                            element
+                                .kind
+                                .try_unwrap_symbolize()
+                                .is_some_and(|symbol| symbol.identity == template)
+                        })
+                    )
+                })
+                .or_else(|| source.last().copied());
+
+            let Some(key) = owner else {
+                continue;
+            };
+
+            if let Some(Artifact::Elements(elements)) =
+                session.records.get_mut(&key).unwrap().fetch_mut(2)
+            {
+                let mut element =
+                    Element::new(ElementKind::symbolize(instance.clone()), instance.span);
+                element.reference = Some(instance.identity);
+                element.typing = instance.typing;
+                elements.push(element);
+            }
+        }
     }
 
     #[allow(dead_code)]
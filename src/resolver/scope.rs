@@ -1,5 +1,5 @@
 use crate::{
-    data::Identity,
+    data::{Identity, Interface},
     internal::hash::Set,
     parser::{Element, ElementKind, Symbol, SymbolKind},
     resolver::{Resolvable, Resolver},
@@ -77,7 +77,11 @@ impl Scope {
                 )
             }
             (ElementKind::Invoke(invoke), SymbolKind::Function(function)) => {
-                invoke.members.len() == function.members.len()
+                match (function.variadic, function.interface) {
+                    (false, _) => invoke.members.len() == function.members.len(),
+                    (true, Interface::Axo) => invoke.members.len() + 1 >= function.members.len(),
+                    (true, _) => invoke.members.len() >= function.members.len(),
+                }
             }
             (ElementKind::Construct(construct), SymbolKind::Structure(structure)) => {
                 Self::fields(&structure.members) == Self::names(&construct.members)
@@ -109,8 +113,14 @@ impl Scope {
         target: &Element<'a>,
         resolver: &Resolver<'a>,
     ) -> Result<Symbol<'a>, Vec<crate::resolver::ResolveError<'a>>> {
-        Resolver::builtin(target)
+        let symbol = Resolver::builtin(target)
             .or_else(|| self.exact(target, resolver))
-            .ok_or_else(|| resolver.undefined(target))
+            .ok_or_else(|| resolver.undefined(target))?;
+
+        if resolver.accessible(&symbol) {
+            Ok(symbol)
+        } else {
+            Err(vec![resolver.private(&symbol, target.span)])
+        }
     }
 }
@@ -1,7 +1,13 @@
 use crate::{
-    data::{memory::replace, Aggregate, Binding as TypeBinding, Function, Interface},
-    parser::{Symbol, SymbolKind},
-    resolver::{scope::Scope, Resolvable, Resolver, Type, TypeKind},
+    data::{
+        memory::{discriminant, replace},
+        Aggregate, Alias, AliasKind, Attribute, Binding as TypeBinding, BindingKind, Function,
+        Interface, Str,
+    },
+    parser::{Element, ElementKind, Symbol, SymbolKind},
+    resolver::{scope::Scope, Error, ErrorKind, Resolvable, Resolver, Type, TypeKind},
+    scanner::{Token, TokenKind},
+    tracker::Span,
 };
 
 impl<'a> Symbol<'a> {
@@ -33,6 +39,13 @@ impl<'a> Symbol<'a> {
                     Vec::new(),
                 ))),
             )),
+            SymbolKind::Enumeration(enumeration) => Some(Type::new(
+                identity,
+                TypeKind::Enumeration(Box::new(Aggregate::new(
+                    enumeration.target.target().unwrap().into(),
+                    Vec::new(),
+                ))),
+            )),
             SymbolKind::Module(module) => Some(Type::new(
                 identity,
                 TypeKind::Module(module.target.target().unwrap().into()),
@@ -59,6 +72,23 @@ impl<'a> Symbol<'a> {
         )
     }
 
+    fn synonym(
+        identity: crate::data::Identity,
+        alias: &Alias<Element<'a>, Element<'a>>,
+        member: Type<'a>,
+    ) -> Type<'a> {
+        match alias.kind {
+            AliasKind::Transparent => member,
+            AliasKind::Nominal => Type::new(
+                identity,
+                TypeKind::Newtype {
+                    target: alias.target.target().unwrap_or_default(),
+                    member: Box::new(member),
+                },
+            ),
+        }
+    }
+
     fn shape(
         identity: crate::data::Identity,
         target: crate::data::Str<'a>,
@@ -74,6 +104,101 @@ impl<'a> Symbol<'a> {
         Type::new(identity, kind)
     }
 
+    fn destructure(
+        resolver: &mut Resolver<'a>,
+        pattern: &mut Element<'a>,
+        value: &Type<'a>,
+        kind: BindingKind,
+        span: Span,
+    ) {
+        let Some(binders) = pattern.destructure() else {
+            return;
+        };
+
+        let fields = if let ElementKind::Construct(construct) = &mut pattern.kind {
+            let name = construct.target.target().unwrap_or_default();
+            let mut typing = resolver.reify(value);
+
+            if !typing.kind.is_structure() {
+                construct.target.resolve(resolver);
+                typing = resolver.unify(span, &construct.target.typing, value);
+                typing = resolver.reify(&typing);
+            }
+
+            let Some(structure) = typing.kind.try_unwrap_structure().cloned() else {
+                resolver
+                    .errors
+                    .push(Error::new(ErrorKind::InvalidPattern(pattern.clone()), span));
+                return;
+            };
+
+            let target = structure.target.as_str().unwrap_or_default();
+            let expected = name.as_str().unwrap_or_default();
+
+            if target != expected && !target.starts_with(&format!("{}[", expected)) {
+                let left = resolver.reify(&construct.target.typing);
+                resolver
+                    .errors
+                    .push(Error::new(ErrorKind::Mismatch(left, typing.clone()), span));
+                return;
+            }
+
+            binders
+                .iter()
+                .map(|(field, _)| {
+                    let member = field.unwrap_or_default();
+                    let found = structure.members.iter().find(|member| {
+                        member
+                            .kind
+                            .try_unwrap_binding()
+                            .is_some_and(|binding| Some(binding.target) == *field)
+                    });
+
+                    if found.is_none() {
+                        resolver.errors.push(Error::new(
+                            ErrorKind::UndefinedMember {
+                                target: name,
+                                member,
+                            },
+                            span,
+                        ));
+                    }
+
+                    found.map(Self::value)
+                })
+                .collect::<Vec<_>>()
+        } else {
+            let members = binders.iter().map(|_| resolver.fresh()).collect::<Vec<_>>();
+
+            let expect = Type::from(TypeKind::Tuple {
+                members: Box::new(members.clone()),
+            });
+            resolver.unify(span, &expect, value);
+
+            members.into_iter().map(Some).collect()
+        };
+
+        for ((_, binder), field) in binders.into_iter().zip(fields) {
+            if binder.is_empty() || binder.as_str() == Some("_") {
+                continue;
+            }
+
+            let typing = field.unwrap_or_else(|| resolver.fresh());
+            let head = Element::new(
+                ElementKind::literal(Token::new(TokenKind::identifier(binder), pattern.span)),
+                pattern.span,
+            );
+
+            let mut symbol = Symbol::new(
+                SymbolKind::binding(TypeBinding::new(head, None, None, kind)),
+                pattern.span,
+            );
+            symbol.typing = Self::bind(symbol.identity, binder, typing, None, kind);
+
+            resolver.insert(symbol);
+        }
+    }
+
     fn declare_scope(
         resolver: &mut Resolver<'a>,
         members: &mut Vec<crate::parser::Symbol<'a>>,
@@ -105,15 +230,149 @@ impl<'a> Symbol<'a> {
 
         (layout, scope)
     }
+
+    fn preserve(resolver: &Resolver<'a>, identity: crate::data::Identity, scope: &mut Scope) {
+        if let Some(existing) = resolver.get_symbol(identity) {
+            scope.extend(existing.scope.symbols.iter().copied().collect());
+        }
+    }
+
+    fn extend(resolver: &mut Resolver<'a>, target: &crate::parser::Element<'a>, scope: &Scope) {
+        let owner = resolver.candidates(target).into_iter().find(|symbol| {
+            symbol.kind.is_structure() || symbol.kind.is_union() || symbol.kind.is_enumeration()
+        });
+
+        if let Some(symbol) = owner.and_then(|owner| resolver.registry.get_mut(&owner.identity)) {
+            symbol.scope.extend(scope.symbols.iter().copied().collect());
+        }
+    }
+
+    fn contract(members: &[Symbol<'a>]) -> Type<'a> {
+        members
+            .iter()
+            .map(|member| {
+                let mut typing = member.typing.clone();
+
+                if let Some(function) = typing.kind.try_unwrap_function_mut() {
+                    function.body = Type::from(TypeKind::Unknown);
+                }
+
+                Type::from(TypeKind::Has(Box::new(typing)))
+            })
+            .reduce(|left, right| Type::from(TypeKind::And(Box::new(left), Box::new(right))))
+            .unwrap_or_else(|| Type::from(TypeKind::Type))
+    }
+
+    fn conform(resolver: &mut Resolver<'a>, target: &Element<'a>, span: crate::tracker::Span) {
+        let ElementKind::Binary(binary) = &target.kind else {
+            return;
+        };
+
+        let owner = resolver
+            .candidates(&binary.left)
+            .into_iter()
+            .find(|symbol| {
+                symbol.kind.is_structure() || symbol.kind.is_union() || symbol.kind.is_enumeration()
+            });
+
+        let interface = match resolver.lookup(&binary.right) {
+            Ok(symbol) if symbol.kind.is_interface() => symbol,
+            Ok(_) => {
+                resolver.errors.push(Error::new(
+                    ErrorKind::InvalidAnnotation(binary.right.clone()),
+                    binary.right.span,
+                ));
+                return;
+            }
+            Err(errors) => {
+                resolver.errors.extend(errors);
+                return;
+            }
+        };
+
+        if let Some(owner) = owner {
+            resolver.satisfies(&owner.typing, &interface.typing, span);
+        }
+    }
+}
+
+impl<'a> Symbol<'a> {
+    fn noun(&self) -> &'static str {
+        match &self.kind {
+            SymbolKind::Function(_) if self.is_generic() => "generic function",
+            SymbolKind::Function(_) if self.is_variadic() => "variadic function",
+            SymbolKind::Function(_) => "function",
+            SymbolKind::Binding(_) => "binding",
+            SymbolKind::Alias(_) => "type alias",
+            SymbolKind::Structure(_) => "structure",
+            SymbolKind::Union(_) => "union",
+            SymbolKind::Enumeration(_) => "enumeration",
+            SymbolKind::Implementation(_) => "implementation",
+            SymbolKind::Interface(_) => "interface",
+            SymbolKind::Module(_) => "module",
+            SymbolKind::Import(_) => "import",
+        }
+    }
+
+    fn validate(&self, resolver: &mut Resolver<'a>) {
+        let mut seen = Vec::new();
+
+        for element in &self.attributes {
+            let Some(attribute) = element.attribute() else {
+                resolver.errors.push(Error::new(
+                    ErrorKind::UnknownAttribute(element.clone()),
+                    element.span,
+                ));
+                continue;
+            };
+
+            let kind = discriminant(&attribute);
+
+            if seen.contains(&kind) {
+                resolver.errors.push(Error::new(
+                    ErrorKind::DuplicateAttribute(element.clone()),
+                    element.span,
+                ));
+                continue;
+            }
+
+            seen.push(kind);
+
+            let allowed = match (&self.kind, &attribute) {
+                (SymbolKind::Function(_), Attribute::Link(_)) => {
+                    !self.is_generic() && !self.is_variadic()
+                }
+                (SymbolKind::Function(_), _) => true,
+                _ => false,
+            };
+
+            if !allowed {
+                resolver.errors.push(Error::new(
+                    ErrorKind::MisplacedAttribute {
+                        attribute: element.clone(),
+                        target: self.noun(),
+                    },
+                    element.span,
+                ));
+            }
+        }
+    }
 }
 
 impl<'a> Resolvable<'a> for Symbol<'a> {
     fn declare(&mut self, resolver: &mut Resolver<'a>) {
+        self.validate(resolver);
+
         if let Some(typing) = Self::seed(self.identity, &self.kind) {
             self.typing = typing;
             resolver.insert(self.clone());
         }
 
+        if self.is_generic() || self.is_variadic() {
+            resolver.insert(self.clone());
+            return;
+        }
+
         self.typing = match &mut self.kind {
             SymbolKind::Binding(binding) => {
                 binding.target.declare(resolver);
@@ -136,6 +395,14 @@ impl<'a> Resolvable<'a> for Symbol<'a> {
                     binding.kind,
                 )
             }
+            SymbolKind::Alias(alias) => {
+                alias.value.resolve(resolver);
+                let member = resolver
+                    .annotation(&alias.value)
+                    .unwrap_or_else(|_| resolver.fresh());
+
+                Self::synonym(self.identity, alias, member)
+            }
             SymbolKind::Function(function) => {
                 let target = function.target.target().unwrap();
                 let (typing, scope) = resolver.nest(|resolver| {
@@ -196,13 +463,56 @@ impl<'a> Resolvable<'a> for Symbol<'a> {
                 self.scope.parent = None;
                 self.typing.clone()
             }
+            SymbolKind::Enumeration(enumeration) => {
+                self.scope = Box::new(Self::declare_scope(resolver, &mut enumeration.members));
+                self.scope.parent = None;
+                self.typing.clone()
+            }
+            SymbolKind::Implementation(implementation) => {
+                self.scope = Box::new(Self::declare_scope(resolver, &mut implementation.members));
+                self.scope.parent = None;
+                Self::extend(resolver, &implementation.target, &self.scope);
+                Type::from(TypeKind::Void)
+            }
+            SymbolKind::Interface(interface) => {
+                let span = self.span;
+                let (typing, scope) = resolver.nest(|resolver| {
+                    resolver.insert(Resolver::parameter(
+                        Str::from("Self"),
+                        Type::from(TypeKind::Unknown),
+                        span,
+                    ));
+
+                    for member in &mut interface.members {
+                        member.declare(resolver);
+                    }
+
+                    Self::contract(&interface.members)
+                });
+
+                self.scope = Box::new(scope);
+                self.scope.parent = None;
+                typing
+            }
             SymbolKind::Module(_) => self.typing.clone(),
+            SymbolKind::Import(_) => Type::from(TypeKind::Void),
         };
 
-        resolver.insert(self.clone());
+        if !self.kind.is_implementation() && !self.kind.is_import() {
+            resolver.insert(self.clone());
+        }
     }
 
     fn resolve(&mut self, resolver: &mut Resolver<'a>) {
+        if self.is_generic() {
+            return;
+        }
+
+        if self.is_variadic() {
+            resolver.spread(self, 0, self.span);
+            return;
+        }
+
         let expected = self.typing.clone();
 
         let typing = match &mut self.kind {
@@ -232,6 +542,13 @@ impl<'a> Resolvable<'a> for Symbol<'a> {
 
                 resolver.unify(self.span, &binding.target.typing, &value);
                 binding.target.typing = value.clone();
+                Self::destructure(
+                    resolver,
+                    &mut binding.target,
+                    &value,
+                    binding.kind,
+                    self.span,
+                );
 
                 Self::bind(
                     self.identity,
@@ -241,6 +558,17 @@ impl<'a> Resolvable<'a> for Symbol<'a> {
                     binding.kind,
                 )
             }
+            SymbolKind::Alias(alias) => {
+                let member = match resolver.annotation(&alias.value) {
+                    Ok(typing) => typing,
+                    Err(error) => {
+                        resolver.errors.push(error);
+                        resolver.fresh()
+                    }
+                };
+
+                Self::synonym(self.identity, alias, member)
+            }
             SymbolKind::Structure(structure) => {
                 let target = structure.target.target().unwrap();
                 let scope = replace(&mut self.scope, Box::new(Scope::new(None)));
@@ -248,6 +576,7 @@ impl<'a> Resolvable<'a> for Symbol<'a> {
                     Self::resolve_scope(resolver, *scope, &mut structure.members);
                 self.scope = Box::new(scope);
                 self.scope.parent = None;
+                Self::preserve(resolver, self.identity, &mut self.scope);
                 Self::shape(self.identity, target, members, false)
             }
             SymbolKind::Union(union) => {
@@ -256,8 +585,44 @@ impl<'a> Resolvable<'a> for Symbol<'a> {
                 let (members, scope) = Self::resolve_scope(resolver, *scope, &mut union.members);
                 self.scope = Box::new(scope);
                 self.scope.parent = None;
+                Self::preserve(resolver, self.identity, &mut self.scope);
                 Self::shape(self.identity, target, members, true)
             }
+            SymbolKind::Enumeration(enumeration) => {
+                let target = enumeration.target.target().unwrap();
+                let scope = replace(&mut self.scope, Box::new(Scope::new(None)));
+                let (_, scope) = Self::resolve_scope(resolver, *scope, &mut enumeration.members);
+                self.scope = Box::new(scope);
+                self.scope.parent = None;
+                Self::preserve(resolver, self.identity, &mut self.scope);
+
+                let variants = enumeration
+                    .members
+                    .iter()
+                    .map(|variant| variant.typing.clone())
+                    .collect();
+
+                Type::new(
+                    self.identity,
+                    TypeKind::Enumeration(Box::new(Aggregate::new(target, variants))),
+                )
+            }
+            SymbolKind::Implementation(implementation) => {
+                let scope = replace(&mut self.scope, Box::new(Scope::new(None)));
+                let (_, scope) = Self::resolve_scope(resolver, *scope, &mut implementation.members);
+                self.scope = Box::new(scope);
+                self.scope.parent = None;
+                Self::extend(resolver, &implementation.target, &self.scope);
+                Self::conform(resolver, &implementation.target, self.span);
+                Type::from(TypeKind::Void)
+            }
+            SymbolKind::Interface(interface) => {
+                let scope = replace(&mut self.scope, Box::new(Scope::new(None)));
+                let (_, scope) = Self::resolve_scope(resolver, *scope, &mut interface.members);
+                self.scope = Box::new(scope);
+                self.scope.parent = None;
+                Self::contract(&interface.members)
+            }
             SymbolKind::Function(function) => {
                 let target = function.target.target().unwrap();
                 let scope = replace(&mut self.scope, Box::new(Scope::new(None)));
@@ -315,16 +680,25 @@ impl<'a> Resolvable<'a> for Symbol<'a> {
 
                 self.scope = Box::new(scope);
                 self.scope.parent = None;
+
+                if self.is_closure() {
+                    resolver.capture(self);
+                }
+
                 typing
             }
             SymbolKind::Module(module) => Type::new(
                 self.identity,
                 TypeKind::Module(module.target.target().unwrap().into()),
             ),
+            SymbolKind::Import(_) => Type::from(TypeKind::Void),
         };
 
         self.typing = resolver.unify(self.span, &expected, &typing);
-        resolver.insert(self.clone());
+
+        if !self.kind.is_implementation() && !self.kind.is_import() {
+            resolver.insert(self.clone());
+        }
     }
 
     fn is_instance(&self) -> bool {
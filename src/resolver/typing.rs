@@ -1,6 +1,8 @@
+#[cfg(feature = "interpreter")]
+use crate::analyzer::{AnalysisKind, Analyzer};
 use crate::{
-    data::{Aggregate, Binding, Boolean, Function, Identity, Interface, Scale, Str},
-    parser::{Element, ElementKind},
+    data::{Aggregate, Binding, BindingKind, Boolean, Function, Identity, Interface, Scale, Str},
+    parser::{Element, ElementKind, SymbolKind},
     resolver::{ErrorKind, ResolveError, Resolver},
     scanner::{OperatorKind, PunctuationKind, TokenKind},
     tracker::Span,
@@ -46,6 +48,17 @@ pub enum TypeKind<'typing> {
     Tuple {
         members: Box<Vec<Type<'typing>>>,
     },
+    Newtype {
+        target: Str<'typing>,
+        member: Box<Type<'typing>>,
+    },
+    Optional {
+        member: Box<Type<'typing>>,
+    },
+    Result {
+        value: Box<Type<'typing>>,
+        error: Box<Type<'typing>>,
+    },
     Void,
     Variable(Identity),
     Unknown,
@@ -56,6 +69,7 @@ pub enum TypeKind<'typing> {
     Module(Str<'typing>),
     Structure(Box<Aggregate<Str<'typing>, Type<'typing>>>),
     Union(Box<Aggregate<Str<'typing>, Type<'typing>>>),
+    Enumeration(Box<Aggregate<Str<'typing>, Type<'typing>>>),
     Binding(Box<Binding<Str<'typing>, Box<Type<'typing>>, Option<Box<Type<'typing>>>>>),
     Function(Box<Function<Str<'typing>, Type<'typing>, Type<'typing>, Option<Box<Type<'typing>>>>>),
 }
@@ -106,6 +120,21 @@ impl<'typing> TypeKind<'typing> {
         matches!(self, Self::Tuple { .. })
     }
 
+    #[inline(always)]
+    pub fn is_newtype(&self) -> bool {
+        matches!(self, Self::Newtype { .. })
+    }
+
+    #[inline(always)]
+    pub fn is_optional(&self) -> bool {
+        matches!(self, Self::Optional { .. })
+    }
+
+    #[inline(always)]
+    pub fn is_result(&self) -> bool {
+        matches!(self, Self::Result { .. })
+    }
+
     #[inline(always)]
     pub fn is_void(&self) -> bool {
         matches!(self, Self::Void)
@@ -151,6 +180,11 @@ impl<'typing> TypeKind<'typing> {
         matches!(self, Self::Union(_))
     }
 
+    #[inline(always)]
+    pub fn is_enumeration(&self) -> bool {
+        matches!(self, Self::Enumeration(_))
+    }
+
     #[inline(always)]
     pub fn is_function(&self) -> bool {
         matches!(self, Self::Function(_))
@@ -206,6 +240,33 @@ impl<'typing> TypeKind<'typing> {
         }
     }
 
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_newtype(self) -> (Str<'typing>, Box<Type<'typing>>) {
+        match self {
+            Self::Newtype { target, member } => (target, member),
+            _ => panic!("expected newtype"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_optional(self) -> Box<Type<'typing>> {
+        match self {
+            Self::Optional { member } => member,
+            _ => panic!("expected optional"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_result(self) -> (Box<Type<'typing>>, Box<Type<'typing>>) {
+        match self {
+            Self::Result { value, error } => (value, error),
+            _ => panic!("expected result"),
+        }
+    }
+
     #[inline]
     #[track_caller]
     pub fn unwrap_tuple(self) -> Box<Vec<Type<'typing>>> {
@@ -269,6 +330,15 @@ impl<'typing> TypeKind<'typing> {
         }
     }
 
+    #[inline]
+    #[track_caller]
+    pub fn unwrap_enumeration(self) -> Box<Aggregate<Str<'typing>, Type<'typing>>> {
+        match self {
+            Self::Enumeration(enumeration) => enumeration,
+            _ => panic!("expected enumeration"),
+        }
+    }
+
     #[inline]
     #[track_caller]
     pub fn unwrap_binding(
@@ -331,6 +401,30 @@ impl<'typing> TypeKind<'typing> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_newtype(&self) -> Option<(&Str<'typing>, &Box<Type<'typing>>)> {
+        match self {
+            Self::Newtype { target, member } => Some((target, member)),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_optional(&self) -> Option<&Box<Type<'typing>>> {
+        match self {
+            Self::Optional { member } => Some(member),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_result(&self) -> Option<(&Box<Type<'typing>>, &Box<Type<'typing>>)> {
+        match self {
+            Self::Result { value, error } => Some((value, error)),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_tuple(&self) -> Option<&Box<Vec<Type<'typing>>>> {
         match self {
@@ -387,6 +481,14 @@ impl<'typing> TypeKind<'typing> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_enumeration(&self) -> Option<&Box<Aggregate<Str<'typing>, Type<'typing>>>> {
+        match self {
+            Self::Enumeration(enumeration) => Some(enumeration),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_binding(
         &self,
@@ -449,6 +551,34 @@ impl<'typing> TypeKind<'typing> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_newtype_mut(
+        &mut self,
+    ) -> Option<(&mut Str<'typing>, &mut Box<Type<'typing>>)> {
+        match self {
+            Self::Newtype { target, member } => Some((target, member)),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_optional_mut(&mut self) -> Option<&mut Box<Type<'typing>>> {
+        match self {
+            Self::Optional { member } => Some(member),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn try_unwrap_result_mut(
+        &mut self,
+    ) -> Option<(&mut Box<Type<'typing>>, &mut Box<Type<'typing>>)> {
+        match self {
+            Self::Result { value, error } => Some((value, error)),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_tuple_mut(&mut self) -> Option<&mut Box<Vec<Type<'typing>>>> {
         match self {
@@ -513,6 +643,16 @@ impl<'typing> TypeKind<'typing> {
         }
     }
 
+    #[inline(always)]
+    pub fn try_unwrap_enumeration_mut(
+        &mut self,
+    ) -> Option<&mut Box<Aggregate<Str<'typing>, Type<'typing>>>> {
+        match self {
+            Self::Enumeration(enumeration) => Some(enumeration),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub fn try_unwrap_binding_mut(
         &mut self,
@@ -584,6 +724,11 @@ impl<'resolver> Resolver<'resolver> {
             }
             TypeKind::Pointer { target } => self.occurs(identity, target),
             TypeKind::Array { member, .. } => self.occurs(identity, member),
+            TypeKind::Newtype { member, .. } => self.occurs(identity, member),
+            TypeKind::Optional { member } => self.occurs(identity, member),
+            TypeKind::Result { value, error } => {
+                self.occurs(identity, value) || self.occurs(identity, error)
+            }
             TypeKind::Tuple { members } => members.iter().any(|item| self.occurs(identity, item)),
             TypeKind::Has(target) => self.occurs(identity, target),
             TypeKind::Binding(binding) => {
@@ -702,6 +847,31 @@ impl<'resolver> Resolver<'resolver> {
                 target: Box::new(self.unify(span, &left_target, &right_target)),
             }),
 
+            (
+                TypeKind::Optional {
+                    member: left_member,
+                },
+                TypeKind::Optional {
+                    member: right_member,
+                },
+            ) => Type::from(TypeKind::Optional {
+                member: Box::new(self.unify(span, &left_member, &right_member)),
+            }),
+
+            (
+                TypeKind::Result {
+                    value: left_value,
+                    error: left_error,
+                },
+                TypeKind::Result {
+                    value: right_value,
+                    error: right_error,
+                },
+            ) => Type::from(TypeKind::Result {
+                value: Box::new(self.unify(span, &left_value, &right_value)),
+                error: Box::new(self.unify(span, &left_error, &right_error)),
+            }),
+
             (
                 TypeKind::Tuple {
                     members: left_items,
@@ -751,8 +921,30 @@ impl<'resolver> Resolver<'resolver> {
                 )
             }
 
+            (TypeKind::Enumeration(left_aggr), TypeKind::Enumeration(right_aggr))
+                if left.identity == right.identity =>
+            {
+                Type::new(
+                    left.identity,
+                    TypeKind::Enumeration(Box::new(Aggregate::new(
+                        left_aggr.target,
+                        if right_aggr.members.is_empty() {
+                            left_aggr.members
+                        } else {
+                            right_aggr.members
+                        },
+                    ))),
+                )
+            }
+
             (TypeKind::Module(_), TypeKind::Module(_)) if left.identity == right.identity => left,
 
+            (TypeKind::Newtype { .. }, TypeKind::Newtype { .. })
+                if left.identity == right.identity =>
+            {
+                left
+            }
+
             (TypeKind::Binding(left_binding), TypeKind::Binding(right_binding))
                 if left_binding.target == right_binding.target =>
             {
@@ -920,6 +1112,12 @@ impl<'resolver> Resolver<'resolver> {
                 )
             }
 
+            (_, TypeKind::Optional { .. }) | (_, TypeKind::Result { .. }) => {
+                self.errors
+                    .push(ResolveError::new(ErrorKind::Unwrapped(right.clone()), span));
+                left
+            }
+
             _ => {
                 self.errors.push(ResolveError::new(
                     ErrorKind::Mismatch(left.clone(), right.clone()),
@@ -948,6 +1146,20 @@ impl<'resolver> Resolver<'resolver> {
                 member: Box::new(self.reify(member)),
                 size: *size,
             }),
+            TypeKind::Optional { member } => Type::from(TypeKind::Optional {
+                member: Box::new(self.reify(member)),
+            }),
+            TypeKind::Result { value, error } => Type::from(TypeKind::Result {
+                value: Box::new(self.reify(value)),
+                error: Box::new(self.reify(error)),
+            }),
+            TypeKind::Newtype { target, member } => Type::new(
+                typing.identity,
+                TypeKind::Newtype {
+                    target: *target,
+                    member: Box::new(self.reify(member)),
+                },
+            ),
             TypeKind::Tuple { members } => {
                 let items = members.iter().map(|item| self.reify(item)).collect();
                 Type::from(TypeKind::Tuple {
@@ -972,6 +1184,16 @@ impl<'resolver> Resolver<'resolver> {
                 }
                 typing.clone()
             }
+            TypeKind::Enumeration(aggregate)
+                if aggregate.members.is_empty() && typing.identity != 0 =>
+            {
+                if let Some(symbol) = self.registry.get(&typing.identity).cloned() {
+                    if symbol.typing != *typing {
+                        return self.reify(&symbol.typing);
+                    }
+                }
+                typing.clone()
+            }
             TypeKind::Has(target) => Type::from(TypeKind::Has(Box::new(self.reify(target)))),
             TypeKind::Binding(binding) => {
                 let value = binding
@@ -1025,10 +1247,32 @@ impl<'resolver> Resolver<'resolver> {
         }
     }
 
-    pub fn evaluate(&self, element: &Element<'resolver>) -> Result<Scale, ResolveError<'resolver>> {
+    pub fn evaluate(
+        &mut self,
+        element: &Element<'resolver>,
+    ) -> Result<Scale, ResolveError<'resolver>> {
         match &element.kind {
             ElementKind::Literal(token) => match &token.kind {
                 TokenKind::Integer(value) => Ok(*value as Scale),
+                TokenKind::Identifier(_) => {
+                    let value = self
+                        .lookup(element)
+                        .ok()
+                        .and_then(|symbol| match symbol.kind {
+                            SymbolKind::Binding(binding) if binding.kind == BindingKind::Static => {
+                                binding.value
+                            }
+                            _ => None,
+                        });
+
+                    match value {
+                        Some(value) => self.evaluate(&value),
+                        None => Err(ResolveError::new(
+                            ErrorKind::InvalidAnnotation(element.clone()),
+                            element.span,
+                        )),
+                    }
+                }
                 _ => Err(ResolveError::new(
                     ErrorKind::InvalidAnnotation(element.clone()),
                     element.span,
@@ -1056,6 +1300,15 @@ impl<'resolver> Resolver<'resolver> {
                     ))
                 }
             }
+            #[cfg(feature = "interpreter")]
+            _ => match Analyzer::fold(self, element).map(|analysis| analysis.kind) {
+                Ok(AnalysisKind::Integer { value, .. }) if value >= 0 => Ok(value as Scale),
+                _ => Err(ResolveError::new(
+                    ErrorKind::InvalidAnnotation(element.clone()),
+                    element.span,
+                )),
+            },
+            #[cfg(not(feature = "interpreter"))]
             _ => Err(ResolveError::new(
                 ErrorKind::InvalidAnnotation(element.clone()),
                 element.span,
@@ -1208,9 +1461,8 @@ impl<'resolver> Resolver<'resolver> {
                 )),
             },
 
-            ElementKind::Unary(unary) => {
-                if matches!(&unary.operator.kind, TokenKind::Operator(operator) if **operator == OperatorKind::Star)
-                {
+            ElementKind::Unary(unary) => match &unary.operator.kind {
+                TokenKind::Operator(operator) if **operator == OperatorKind::Star => {
                     let item = self.annotation(&unary.operand)?;
                     Ok(Type::new(
                         item.identity,
@@ -1218,12 +1470,95 @@ impl<'resolver> Resolver<'resolver> {
                             target: Box::from(item),
                         },
                     ))
-                } else {
-                    Err(ResolveError::new(
-                        ErrorKind::InvalidAnnotation(element.clone()),
-                        element.span,
-                    ))
                 }
+                TokenKind::Operator(operator) if **operator == OperatorKind::QuestionMark => {
+                    let member = self.annotation(&unary.operand)?;
+                    Ok(Type::from(TypeKind::Optional {
+                        member: Box::new(member),
+                    }))
+                }
+                _ => Err(ResolveError::new(
+                    ErrorKind::InvalidAnnotation(element.clone()),
+                    element.span,
+                )),
+            },
+
+            ElementKind::Symbolize(symbol) => match &symbol.kind {
+                SymbolKind::Function(function) if function.body.is_none() => {
+                    let mut members = Vec::with_capacity(function.members.len());
+
+                    for member in &function.members {
+                        let SymbolKind::Binding(binding) = &member.kind else {
+                            return Err(ResolveError::new(
+                                ErrorKind::InvalidAnnotation(element.clone()),
+                                member.span,
+                            ));
+                        };
+
+                        members.push(match &binding.annotation {
+                            Some(annotation) => self.annotation(annotation)?,
+                            None => self.fresh(),
+                        });
+                    }
+
+                    let output = match &function.output {
+                        Some(output) => self.annotation(output)?,
+                        None => Type::from(TypeKind::Void),
+                    };
+
+                    Ok(Type::from(TypeKind::Function(Box::new(Function::new(
+                        Str::default(),
+                        members,
+                        Type::from(TypeKind::Unknown),
+                        Some(Box::new(output)),
+                        Interface::Axo,
+                        false,
+                        false,
+                    )))))
+                }
+                _ => Err(ResolveError::new(
+                    ErrorKind::InvalidAnnotation(element.clone()),
+                    element.span,
+                )),
+            },
+
+            ElementKind::Index(index) => {
+                let invalid = || {
+                    ResolveError::new(ErrorKind::InvalidAnnotation(element.clone()), element.span)
+                };
+
+                match index.target.target().and_then(|name| name.as_str()) {
+                    Some("Optional") => {
+                        let [member] = index.members.as_slice() else {
+                            return Err(invalid());
+                        };
+
+                        return Ok(Type::from(TypeKind::Optional {
+                            member: Box::new(self.annotation(member)?),
+                        }));
+                    }
+                    Some("Result") => {
+                        let [value, error] = index.members.as_slice() else {
+                            return Err(invalid());
+                        };
+
+                        return Ok(Type::from(TypeKind::Result {
+                            value: Box::new(self.annotation(value)?),
+                            error: Box::new(self.annotation(error)?),
+                        }));
+                    }
+                    _ => {}
+                }
+
+                let template = self.lookup(&index.target).map_err(|_| invalid())?;
+
+                if !template.is_generic() {
+                    return Err(invalid());
+                }
+
+                self.instantiate(&template, element, Vec::new(), element.span)
+                    .map(|instance| instance.typing)
+                    .ok_or_else(invalid)
             }
 
             _ => Err(ResolveError::new(
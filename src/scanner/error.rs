@@ -10,6 +10,7 @@ pub enum ErrorKind<'error> {
     Tracking(TrackError<'error>),
     Expected(&'static str),
     Unterminated(&'static str),
+    Unmatched(&'static str),
     InvalidCharacter(CharacterError),
     InvalidEscape(EscapeError),
     NumberParse(ParseError),
@@ -46,6 +47,7 @@ impl<'error> Display for ErrorKind<'error> {
             ErrorKind::Tracking(tracker) => write!(f, "{}", tracker.handle().0),
             ErrorKind::Expected(label) => write!(f, "expected {}.", label),
             ErrorKind::Unterminated(label) => write!(f, "unterminated {}.", label),
+            ErrorKind::Unmatched(label) => write!(f, "unmatched {}.", label),
             ErrorKind::InvalidCharacter(e) => match e {
                 CharacterError::Unexpected(ch) => write!(f, "unexpected character `{}`.", ch.value),
                 CharacterError::OutOfRange => write!(f, "character code point out of range."),
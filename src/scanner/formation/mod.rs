@@ -3,17 +3,267 @@ mod number;
 
 use {
     crate::{
-        data::Str,
+        data::{memory::take, Str},
         scanner::{
             Character, CharacterError, ErrorKind, Operator, Punctuation, PunctuationKind,
             ScanError, Scanner, Token, TokenKind,
         },
-        tracker::Spanned,
+        tracker::{Position, Span, Spanned},
     },
-    chaint::{Form, Formation},
+    chaint::{Form, Formation, Former, Peekable},
 };
 
 impl<'a> Scanner<'a> {
+    fn embedded(
+        brace: Character,
+        characters: Vec<Character>,
+    ) -> Result<Vec<Token<'a>>, ScanError<'a>> {
+        let Some(first) = characters.first() else {
+            return Err(ScanError::new(
+                ErrorKind::Expected("an expression"),
+                brace.span,
+            ));
+        };
+
+        let mut scanner = Scanner {
+            index: 0,
+            state: Position {
+                identity: first.span.identity,
+                offset: first.span.start,
+            },
+            input: Vec::new(),
+            output: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        scanner.set_input(characters);
+        scanner.scan();
+
+        if let Some(error) = scanner.errors.into_iter().next() {
+            return Err(error);
+        }
+
+        if scanner
+            .output
+            .iter()
+            .all(|token| token.kind == TokenKind::Punctuation(PunctuationKind::Space))
+        {
+            return Err(ScanError::new(
+                ErrorKind::Expected("an expression"),
+                brace.span,
+            ));
+        }
+
+        Ok(scanner.output)
+    }
+
+    fn interpolate(
+        open: Character,
+        characters: Vec<Character>,
+        close: Character,
+    ) -> Result<Vec<Token<'a>>, ScanError<'a>> {
+        let mut segments = Vec::new();
+        let mut text: Vec<Character> = Vec::new();
+        let mut index = 0;
+
+        while index < characters.len() {
+            let character = characters[index];
+            let next = characters.get(index + 1).map(|next| next.value);
+
+            match character.value {
+                '{' | '}' if next == Some(character.value) => {
+                    text.push(character);
+                    index += 2;
+                }
+                '{' => {
+                    let end = characters[index + 1..]
+                        .iter()
+                        .position(|candidate| candidate.value == '}')
+                        .map(|offset| index + 1 + offset)
+                        .ok_or_else(|| {
+                            ScanError::new(ErrorKind::Unterminated("interpolation"), character.span)
+                        })?;
+
+                    if !text.is_empty() {
+                        let span = text.span();
+                        let content: Str = text.drain(..).collect();
+                        segments.push(vec![Token::new(TokenKind::string(content), span)]);
+                    }
+
+                    segments.push(Self::embedded(
+                        character,
+                        characters[index + 1..end].to_vec(),
+                    )?);
+                    index = end + 1;
+                }
+                '}' => {
+                    return Err(ScanError::new(
+                        ErrorKind::Unmatched("`}` in interpolation"),
+                        character.span,
+                    ));
+                }
+                _ => {
+                    text.push(character);
+                    index += 1;
+                }
+            }
+        }
+
+        let span = open.span.merge(&close.span);
+
+        if segments.is_empty() {
+            let content: Str = text.into_iter().collect();
+            return Ok(vec![Token::new(TokenKind::string(content), span)]);
+        }
+
+        if !text.is_empty() {
+            let span = text.span();
+            let content: Str = text.into_iter().collect();
+            segments.push(vec![Token::new(TokenKind::string(content), span)]);
+        }
+
+        let point = |span: Span| Span::range(span.identity, span.start, span.start);
+
+        let mut tokens = vec![
+            Token::new(TokenKind::identifier(Str::from("format")), point(open.span)),
+            Token::new(
+                TokenKind::Punctuation(PunctuationKind::LeftParenthesis),
+                open.span,
+            ),
+        ];
+
+        for (position, segment) in segments.into_iter().enumerate() {
+            if position > 0 {
+                tokens.push(Token::new(
+                    TokenKind::Punctuation(PunctuationKind::Comma),
+                    point(segment[0].span),
+                ));
+            }
+
+            tokens.extend(segment);
+        }
+
+        tokens.push(Token::new(
+            TokenKind::Punctuation(PunctuationKind::RightParenthesis),
+            close.span,
+        ));
+
+        Ok(tokens)
+    }
+
+    fn unescape(raw: Vec<Character>) -> Vec<Character> {
+        let Some(first) = raw.first() else {
+            return Vec::new();
+        };
+
+        let mut scanner = Scanner {
+            index: 0,
+            state: Position {
+                identity: first.span.identity,
+                offset: first.span.start,
+            },
+            input: Vec::new(),
+            output: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        scanner.set_input(raw);
+
+        let formation: Formation<'a, '_, Self, Character, Token<'a>, ScanError<'a>> =
+            Formation::repetition(
+                Formation::alternative([
+                    Formation::predicate(|c: &Character| c.value != '\\'),
+                    Self::escape_sequence(),
+                ]),
+                0,
+                None,
+            );
+
+        Former::new(&mut scanner).form(formation).collect_inputs()
+    }
+
+    fn interpolation(&self, token: &Token<'a>) -> Result<Vec<Token<'a>>, ScanError<'a>> {
+        let mut raw: Vec<Character> = self
+            .input
+            .iter()
+            .filter(|character| {
+                character.span.identity == token.span.identity
+                    && character.span.start >= token.span.start
+                    && character.span.end <= token.span.end
+            })
+            .copied()
+            .collect();
+
+        if raw.len() < 2 || raw[0].value != '"' {
+            return Ok(vec![token.clone()]);
+        }
+
+        let close = raw.pop().unwrap();
+        let open = raw.remove(0);
+        let characters = Self::unescape(raw);
+
+        if !characters.iter().any(|c| matches!(c.value, '{' | '}')) {
+            return Ok(vec![token.clone()]);
+        }
+
+        Self::interpolate(open, characters, close)
+    }
+
+    pub fn expand(&mut self) {
+        let tokens = take(&mut self.output);
+        let mut contexts: Vec<bool> = Vec::new();
+        let mut previous: Option<Str<'a>> = None;
+
+        for token in tokens {
+            match &token.kind {
+                TokenKind::Punctuation(
+                    PunctuationKind::Space
+                    | PunctuationKind::Tab
+                    | PunctuationKind::Newline
+                    | PunctuationKind::Return,
+                )
+                | TokenKind::Comment(_) => {
+                    self.output.push(token);
+                    continue;
+                }
+                TokenKind::Punctuation(PunctuationKind::LeftParenthesis) => {
+                    contexts.push(matches!(
+                        previous.as_ref().and_then(|name| name.as_str()),
+                        Some("format" | "println")
+                    ));
+                }
+                TokenKind::Punctuation(
+                    PunctuationKind::LeftBracket | PunctuationKind::LeftBrace,
+                ) => {
+                    contexts.push(false);
+                }
+                TokenKind::Punctuation(
+                    PunctuationKind::RightParenthesis
+                    | PunctuationKind::RightBracket
+                    | PunctuationKind::RightBrace,
+                ) => {
+                    contexts.pop();
+                }
+                TokenKind::String(_) if contexts.last() == Some(&true) => {
+                    match self.interpolation(&token) {
+                        Ok(tokens) => self.output.extend(tokens),
+                        Err(error) => {
+                            self.errors.push(error);
+                            self.output.push(token);
+                        }
+                    }
+
+                    previous = None;
+                    continue;
+                }
+                _ => {}
+            }
+
+            previous = token.kind.try_unwrap_identifier().copied();
+            self.output.push(token);
+        }
+    }
+
     fn string<'source>() -> Formation<'a, 'source, Self, Character, Token<'a>, ScanError<'a>> {
         Formation::sequence([
             Formation::literal('"'),
@@ -33,6 +283,7 @@ impl<'a> Scanner<'a> {
             let form = former.forms.get_mut(formation.form).unwrap();
             let mut inputs = form.collect_inputs();
             let span = inputs.span().clone();
+
             if inputs.len() >= 2 {
                 inputs.drain(0..1);
                 inputs.pop();
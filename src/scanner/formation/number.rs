@@ -128,12 +128,22 @@ impl<'a> Scanner<'a> {
                 Formation::persistence(
                     Formation::alternative([
                         Formation::predicate(|c: &Character| c.is_numeric()),
-                        Formation::literal('.'),
                         Formation::literal('_').with_ignore(),
                     ]),
                     1,
                     None,
                 ),
+                Formation::optional(Formation::sequence([
+                    Formation::literal('.'),
+                    Formation::persistence(
+                        Formation::alternative([
+                            Formation::predicate(|c: &Character| c.is_numeric()),
+                            Formation::literal('_').with_ignore(),
+                        ]),
+                        1,
+                        None,
+                    ),
+                ])),
                 Formation::optional(Formation::sequence([
                     Formation::predicate(|c: &Character| matches!(c.value, 'e' | 'E')),
                     Formation::optional(Formation::predicate(|c: &Character| {
@@ -56,7 +56,7 @@ impl<'source> Default for Scanner<'source> {
 
 #[cfg(test)]
 mod tests {
-    use super::{OperatorKind, PunctuationKind, Scanner, TokenKind};
+    use super::{ErrorKind, OperatorKind, PunctuationKind, Scanner, TokenKind};
     use crate::{data::Str, tracker::Position};
 
     fn scan(source: &'static str) -> Scanner<'static> {
@@ -90,6 +90,27 @@ mod tests {
         assert!(matches!(kinds[2], TokenKind::Float(_)));
     }
 
+    #[test]
+    fn scans_range_bounds() {
+        let scanner = scan("0..10 1..=5 2.5");
+        assert!(scanner.errors.is_empty());
+        let kinds = compact(&scanner);
+        assert_eq!(kinds.len(), 7);
+        assert!(matches!(kinds[0], TokenKind::Integer(0)));
+        assert!(matches!(
+            kinds[1].try_unwrap_operator(),
+            Some(OperatorKind::Composite(op)) if op.as_slice() == [OperatorKind::Dot, OperatorKind::Dot]
+        ));
+        assert!(matches!(kinds[2], TokenKind::Integer(10)));
+        assert!(matches!(
+            kinds[4].try_unwrap_operator(),
+            Some(OperatorKind::Composite(op))
+                if op.as_slice() == [OperatorKind::Dot, OperatorKind::Dot, OperatorKind::Equal]
+        ));
+        assert!(matches!(kinds[5], TokenKind::Integer(5)));
+        assert!(matches!(kinds[6], TokenKind::Float(_)));
+    }
+
     #[test]
     fn scans_identifier_and_boolean() {
         let scanner = scan("true false name _name2");
@@ -181,6 +202,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scans_interpolated_string() {
+        let scanner = scan("println(\"x = {x}!\", \"{{}}\")");
+        assert!(scanner.errors.is_empty());
+        let kinds = compact(&scanner);
+        assert_eq!(kinds.len(), 13);
+        assert!(
+            matches!(kinds[2].try_unwrap_identifier(), Some(name) if name.as_str() == Some("format"))
+        );
+        assert!(matches!(
+            kinds[3],
+            TokenKind::Punctuation(PunctuationKind::LeftParenthesis)
+        ));
+        assert!(
+            matches!(kinds[4].try_unwrap_string(), Some(value) if value.as_str() == Some("x = "))
+        );
+        assert!(
+            matches!(kinds[6].try_unwrap_identifier(), Some(name) if name.as_str() == Some("x"))
+        );
+        assert!(matches!(kinds[8].try_unwrap_string(), Some(value) if value.as_str() == Some("!")));
+        assert!(matches!(
+            kinds[9],
+            TokenKind::Punctuation(PunctuationKind::RightParenthesis)
+        ));
+        assert!(
+            matches!(kinds[11].try_unwrap_string(), Some(value) if value.as_str() == Some("{}"))
+        );
+    }
+
+    #[test]
+    fn plain_string_keeps_braces() {
+        let scanner = scan("\"{x}\" f(\"}}\")");
+        assert!(scanner.errors.is_empty());
+        let kinds = compact(&scanner);
+        assert_eq!(kinds.len(), 5);
+        assert!(
+            matches!(kinds[0].try_unwrap_string(), Some(value) if value.as_str() == Some("{x}"))
+        );
+        assert!(
+            matches!(kinds[3].try_unwrap_string(), Some(value) if value.as_str() == Some("}}"))
+        );
+    }
+
+    #[test]
+    fn unmatched_interpolation_brace() {
+        let scanner = scan("format(\"a}\")");
+        assert_eq!(scanner.errors.len(), 1);
+        assert!(matches!(scanner.errors[0].kind, ErrorKind::Unmatched(_)));
+    }
+
     #[test]
     fn scans_comments() {
         let scanner = scan("a//line\nb/*ok*/c");
@@ -222,6 +293,8 @@ mod tests {
         let corpus = [
             "let a = 1 + 2 * 3",
             "let s = \"hello\\nworld\"",
+            "let t = \"total: {a + b}\"",
+            "let u = format(\"total: {a + b}\")",
             "let c = '\\u0041'",
             "func f(x): i32 { x + 1 }",
             "struct A { let x: i32; let y: i32 }",
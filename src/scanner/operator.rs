@@ -101,6 +101,14 @@ impl OperatorKind {
         }
     }
 
+    pub fn keyword(name: &str) -> Option<u8> {
+        match name {
+            "as" => Some(8),
+            "in" => Some(0),
+            _ => None,
+        }
+    }
+
     pub fn is_arrow(&self) -> bool {
         matches!(
             self.as_slice(),
@@ -130,7 +138,10 @@ impl OperatorKind {
     }
 
     pub fn is_suffix(&self) -> bool {
-        matches!(self.as_slice(), [Plus, Plus] | [Minus, Minus])
+        matches!(
+            self.as_slice(),
+            [Plus, Plus] | [Minus, Minus] | [QuestionMark]
+        )
     }
 
     pub fn decompound(&self) -> Option<OperatorKind> {
@@ -99,6 +99,8 @@ impl<'scanner> Scanner<'scanner> {
                 _ => {}
             }
         }
+
+        self.expand();
     }
 
     pub fn execute(session: &mut Session<'scanner>, keys: &[Identity]) {